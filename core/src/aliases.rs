@@ -0,0 +1,45 @@
+use crate::parser::{IdlType, IdlTypeDef};
+use std::collections::{HashMap, HashSet};
+
+/// Target of every type alias in `types`, followed through aliases of aliases
+///
+/// `type A = u64; type B = A;` maps both `A` and `B` to `u64`. Generic aliases
+/// are left out, since their targets depend on the arguments, as are aliases
+/// in a cycle, which type generation rejects.
+pub fn alias_targets(types: &[IdlTypeDef]) -> HashMap<&str, &IdlType> {
+    let direct: HashMap<&str, &IdlType> = types
+        .iter()
+        .filter(|typedef| typedef.generics.is_empty())
+        .filter_map(|typedef| Some((typedef.name.as_str(), typedef.ty.alias.as_ref()?)))
+        .collect();
+
+    direct
+        .iter()
+        .filter_map(|(&name, &target)| {
+            let mut seen = HashSet::from([name]);
+            let mut target = target;
+            while let Some(next) = alias_name(target).filter(|next| direct.contains_key(next)) {
+                if !seen.insert(next) {
+                    return None;
+                }
+                target = direct[next];
+            }
+            Some((name, target))
+        })
+        .collect()
+}
+
+/// `ty` with a reference to a type alias replaced by the alias's final target
+pub fn resolve_alias<'a>(ty: &'a IdlType, targets: &HashMap<&str, &'a IdlType>) -> &'a IdlType {
+    alias_name(ty)
+        .and_then(|name| targets.get(name).copied())
+        .unwrap_or(ty)
+}
+
+/// Name of the type `ty` refers to, if it is a defined type without generic arguments
+fn alias_name(ty: &IdlType) -> Option<&str> {
+    match ty {
+        IdlType::Defined { defined } if defined.generics().is_empty() => Some(defined.name()),
+        _ => None,
+    }
+}
@@ -0,0 +1,268 @@
+use crate::parser::{IdlAccount, IdlType, IdlTypeDef, IdlTypeDefType, PRIMITIVE_TYPES};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{HashMap, HashSet};
+
+/// Optional derives that depend on the field types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Derive {
+    Eq,
+    Hash,
+    Ord,
+}
+
+const DERIVES: [Derive; 3] = [Derive::Eq, Derive::Hash, Derive::Ord];
+
+/// Which of `Eq`, `Hash` and `Ord` each IDL type definition can derive
+///
+/// A type supports a derive when all of its fields do, looking through
+/// options, vecs, arrays, tuples, maps and other defined types. Floats support
+/// none of them, `HashMap`/`HashSet` only `Eq`, and types not defined in the
+/// IDL none. Support is computed as a fixed point, so mutually recursive types
+/// are handled without recursing.
+pub struct DeriveAnalysis {
+    supported: HashMap<Derive, HashSet<String>>,
+}
+
+impl DeriveAnalysis {
+    /// Analyse the `types` section together with inline account types
+    ///
+    /// Panics if a type contains itself without indirection, which could never
+    /// compile.
+    pub fn new(types: &[IdlTypeDef], accounts: &[IdlAccount]) -> Self {
+        let mut defs: HashMap<&str, &IdlTypeDefType> = types
+            .iter()
+            .map(|typedef| (typedef.name.as_str(), &typedef.ty))
+            .collect();
+        for acc in accounts {
+            if let Some(ty) = &acc.ty {
+                defs.insert(acc.name.as_str(), ty);
+            }
+        }
+
+        check_cycles(&defs);
+
+        let supported = DERIVES
+            .into_iter()
+            .map(|derive| (derive, supported_types(&defs, derive)))
+            .collect();
+        DeriveAnalysis { supported }
+    }
+
+    /// The `#[derive(...)]` attribute for the type definition `name`
+    ///
+    /// The borsh derives are left out when `borsh` is false, for types
+    /// implementing borsh by hand.
+    pub fn derive_attr(&self, name: &str, borsh: bool) -> TokenStream {
+        let supports = |derive: Derive| self.supported[&derive].contains(name);
+
+        let eq = supports(Derive::Eq).then(|| quote! { Eq, });
+        let hash = (supports(Derive::Eq) && supports(Derive::Hash)).then(|| quote! { Hash, });
+        let ord =
+            (supports(Derive::Eq) && supports(Derive::Ord)).then(|| quote! { PartialOrd, Ord, });
+
+        let borsh = borsh.then(|| quote! { ::borsh::BorshDeserialize, ::borsh::BorshSerialize });
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq, #eq #hash #ord #borsh)]
+        }
+    }
+}
+
+/// Names of the type definitions that support `derive`
+fn supported_types(defs: &HashMap<&str, &IdlTypeDefType>, derive: Derive) -> HashSet<String> {
+    let mut supported: HashSet<String> = defs.keys().map(|name| name.to_string()).collect();
+
+    loop {
+        let unsupported: Vec<String> = supported
+            .iter()
+            .filter(|name| {
+                !defs[name.as_str()]
+                    .field_types()
+                    .into_iter()
+                    .all(|ty| type_supports(ty, derive, &supported))
+            })
+            .cloned()
+            .collect();
+        if unsupported.is_empty() {
+            return supported;
+        }
+        for name in unsupported {
+            supported.remove(&name);
+        }
+    }
+}
+
+/// Whether a field of type `ty` allows `derive`, given the supporting defined types
+fn type_supports(ty: &IdlType, derive: Derive, supported: &HashSet<String>) -> bool {
+    match ty {
+        IdlType::Primitive(s) => match s.as_str() {
+            "f32" | "f64" => false,
+            s if PRIMITIVE_TYPES.contains(&s) => true,
+            other => supported.contains(other),
+        },
+        IdlType::Defined { defined } => {
+            supported.contains(defined.name())
+                && defined
+                    .generic_types()
+                    .all(|ty| type_supports(ty, derive, supported))
+        }
+        // Derives bound type parameters themselves
+        IdlType::Generic { .. } => true,
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::BTreeSet { btree_set: inner } => type_supports(inner, derive, supported),
+        IdlType::Array { array } => type_supports(&array.0, derive, supported),
+        IdlType::Tuple { tuple } => tuple.iter().all(|ty| type_supports(ty, derive, supported)),
+        IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => type_supports(key, derive, supported) && type_supports(value, derive, supported),
+        IdlType::HashMap {
+            hash_map: (key, value),
+        } => {
+            derive == Derive::Eq
+                && type_supports(key, derive, supported)
+                && type_supports(value, derive, supported)
+        }
+        IdlType::HashSet { hash_set } => {
+            derive == Derive::Eq && type_supports(hash_set, derive, supported)
+        }
+        IdlType::Unsupported(_) => false,
+    }
+}
+
+/// Defined types stored inline in `ty`, i.e. not behind a heap allocation
+fn inline_defined_types<'a>(ty: &'a IdlType, out: &mut Vec<&'a str>) {
+    match ty {
+        IdlType::Primitive(name) => out.push(name),
+        IdlType::Defined { defined } => {
+            out.push(defined.name());
+            defined
+                .generic_types()
+                .for_each(|ty| inline_defined_types(ty, out));
+        }
+        IdlType::Option { option } => inline_defined_types(option, out),
+        IdlType::Array { array } => inline_defined_types(&array.0, out),
+        IdlType::Tuple { tuple } => tuple.iter().for_each(|ty| inline_defined_types(ty, out)),
+        IdlType::Vec { .. }
+        | IdlType::HashMap { .. }
+        | IdlType::BTreeMap { .. }
+        | IdlType::HashSet { .. }
+        | IdlType::BTreeSet { .. }
+        | IdlType::Generic { .. }
+        | IdlType::Unsupported(_) => {}
+    }
+}
+
+/// Defined types referenced anywhere in `ty`
+fn referenced_types<'a>(ty: &'a IdlType, out: &mut Vec<&'a str>) {
+    match ty {
+        IdlType::Primitive(name) => out.push(name),
+        IdlType::Defined { defined } => {
+            out.push(defined.name());
+            defined
+                .generic_types()
+                .for_each(|ty| referenced_types(ty, out));
+        }
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => referenced_types(inner, out),
+        IdlType::Array { array } => referenced_types(&array.0, out),
+        IdlType::Tuple { tuple } => tuple.iter().for_each(|ty| referenced_types(ty, out)),
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => {
+            referenced_types(key, out);
+            referenced_types(value, out);
+        }
+        IdlType::Generic { .. } | IdlType::Unsupported(_) => {}
+    }
+}
+
+/// Panic with the offending path if a type contains itself
+///
+/// Structs and enums may only refer to themselves behind a heap allocation,
+/// while type aliases can never refer to themselves, since Rust expands them
+/// eagerly.
+fn check_cycles(defs: &HashMap<&str, &IdlTypeDefType>) {
+    let inline_edges: HashMap<&str, Vec<&str>> = defs
+        .iter()
+        .map(|(name, ty)| {
+            let mut inline = Vec::new();
+            for field in ty.field_types() {
+                inline_defined_types(field, &mut inline);
+            }
+            inline.retain(|dep| defs.contains_key(dep));
+            (*name, inline)
+        })
+        .collect();
+    if let Some(cycle) = find_cycle(&inline_edges) {
+        panic!(
+            "Type {} contains itself without indirection ({}); put the recursive field behind a `vec`",
+            cycle[0],
+            cycle.join(" -> ")
+        );
+    }
+
+    let is_alias = |name: &str| defs.get(name).is_some_and(|ty| ty.alias.is_some());
+    let alias_edges: HashMap<&str, Vec<&str>> = defs
+        .iter()
+        .filter_map(|(name, ty)| Some((*name, ty.alias.as_ref()?)))
+        .map(|(name, target)| {
+            let mut referenced = Vec::new();
+            referenced_types(target, &mut referenced);
+            referenced.retain(|dep| is_alias(dep));
+            (name, referenced)
+        })
+        .collect();
+    if let Some(cycle) = find_cycle(&alias_edges) {
+        panic!(
+            "Type alias {} refers to itself ({})",
+            cycle[0],
+            cycle.join(" -> ")
+        );
+    }
+}
+
+/// The first cycle in the graph of `edges`, starting and ending at the same name
+///
+/// Every name an edge points to must have an entry in `edges`.
+fn find_cycle<'a>(edges: &HashMap<&'a str, Vec<&'a str>>) -> Option<Vec<&'a str>> {
+    let mut done: HashSet<&str> = HashSet::new();
+    let mut names: Vec<&str> = edges.keys().copied().collect();
+    names.sort_unstable();
+
+    for start in names {
+        if done.contains(start) {
+            continue;
+        }
+        // Iterative depth-first search; `path` holds the types being visited
+        let mut path: Vec<(&str, usize)> = vec![(start, 0)];
+        while let Some((name, next)) = path.last_mut() {
+            let Some(dep) = edges[*name].get(*next).copied() else {
+                done.insert(*name);
+                path.pop();
+                continue;
+            };
+            *next += 1;
+
+            if let Some(pos) = path.iter().position(|(visiting, _)| *visiting == dep) {
+                return Some(
+                    path[pos..]
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .chain([dep])
+                        .collect(),
+                );
+            }
+            if !done.contains(dep) {
+                path.push((dep, 0));
+            }
+        }
+    }
+    None
+}
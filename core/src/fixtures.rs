@@ -0,0 +1,41 @@
+use crate::generator::{GenerateOptions, InstructionItems};
+use crate::layout;
+use crate::parser::{IdlInstruction, IdlTypeDef};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `<NAME>_SAMPLE_DATA` constants, empty unless the `test_fixtures` option is set
+///
+/// Each is any zeroed header before the discriminator, the discriminator and
+/// an all-zero args payload, which decodes as zero numbers, `false`, zeroed
+/// keys and the first variant of enums. Instructions with variable-size args
+/// get no sample.
+pub fn generate_sample_data(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    if !options.test_fixtures {
+        return quote! {};
+    }
+
+    let samples = instructions.iter().filter_map(|ix| {
+        let args_size = layout::fixed_size_of(layout::encoded_types(&ix.args), types)?;
+        // Args that begin with the discriminator already count its bytes
+        let zeros = if options.discriminator_in_args && !ix.args.is_empty() {
+            args_size.checked_sub(ix.discriminator.len())?
+        } else {
+            args_size
+        };
+        let data = std::iter::repeat_n(0u8, options.discriminator_offset)
+            .chain(ix.discriminator.iter().copied())
+            .chain(std::iter::repeat_n(0u8, zeros));
+        let const_name = InstructionItems::new(ix, types, options).sample_data;
+        Some(quote! {
+            /// Instruction data with all-zero args, for decoding tests
+            pub const #const_name: &[u8] = &[#(#data),*];
+        })
+    });
+
+    quote! { #(#samples)* }
+}
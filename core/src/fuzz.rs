@@ -0,0 +1,162 @@
+use crate::generator::{idl_type_to_rust, GenerateOptions};
+use crate::parser::{IdlField, IdlType, IdlTypeDef};
+use crate::sdk;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `Arbitrary` derive for generated types, empty unless the `fuzz` option is set
+pub fn arbitrary_derive(options: &GenerateOptions) -> TokenStream {
+    if options.fuzz {
+        quote! { #[derive(::arbitrary::Arbitrary)] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Attribute leaving a field at its default in `Arbitrary` values
+pub fn skip_attr(options: &GenerateOptions) -> TokenStream {
+    if options.fuzz {
+        quote! { #[arbitrary(default)] }
+    } else {
+        quote! {}
+    }
+}
+
+/// `#[arbitrary(with = ...)]` for a field of type `ty`
+///
+/// `Pubkey` does not implement `Arbitrary`, so fields holding one, however
+/// deeply nested or behind an alias, are built by a closure creating keys from
+/// 32 arbitrary bytes. Other fields use their own `Arbitrary` impl.
+pub fn field_attr(ty: &IdlType, types: &[IdlTypeDef], options: &GenerateOptions) -> TokenStream {
+    if !options.fuzz || !contains_pubkey(ty, types) {
+        return quote! {};
+    }
+    let rust_type = idl_type_to_rust(ty, options);
+    let value = arbitrary_value(ty, types, options);
+    quote! {
+        #[arbitrary(with = |u: &mut ::arbitrary::Unstructured| -> ::arbitrary::Result<#rust_type> { #value })]
+    }
+}
+
+/// `#[arbitrary(with = ...)]` for a named field, which keeps `bytes` at their `len` if set
+pub fn named_field_attr(
+    field: &IdlField,
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    match field.len {
+        Some(len) if options.fuzz => {
+            let alloc = sdk::alloc_crate(options.no_std);
+            quote! {
+                #[arbitrary(with = |u: &mut ::arbitrary::Unstructured| -> ::arbitrary::Result<#alloc::vec::Vec<u8>> {
+                    Ok(u.bytes(#len)?.to_vec())
+                })]
+            }
+        }
+        _ => field_attr(&field.ty, types, options),
+    }
+}
+
+/// Expression building an `arbitrary::Result` of type `ty` from `u`
+fn arbitrary_value(ty: &IdlType, types: &[IdlTypeDef], options: &GenerateOptions) -> TokenStream {
+    let rust_type = idl_type_to_rust(ty, options);
+    if !contains_pubkey(ty, types) {
+        return quote! { <#rust_type as ::arbitrary::Arbitrary>::arbitrary(u) };
+    }
+
+    let alloc = sdk::alloc_crate(options.no_std);
+    // Collections holding keys take at least a key's worth of data per element
+    let len = quote! { u.arbitrary_len::<[u8; 32]>()? };
+
+    match ty {
+        IdlType::Primitive(_) => {
+            let pubkey = sdk::pubkey_type();
+            quote! { <[u8; 32] as ::arbitrary::Arbitrary>::arbitrary(u).map(#pubkey::new_from_array) }
+        }
+        IdlType::Defined { defined } => {
+            let target = alias_target(defined.name(), types).expect("only aliases hold keys");
+            arbitrary_value(target, types, options)
+        }
+        IdlType::Option { option } => {
+            let inner = arbitrary_value(option, types, options);
+            quote! {
+                if <bool as ::arbitrary::Arbitrary>::arbitrary(u)? {
+                    (#inner).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+        IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => {
+            let inner = arbitrary_value(inner, types, options);
+            quote! { (0..#len).map(|_| #inner).collect::<::arbitrary::Result<#rust_type>>() }
+        }
+        IdlType::Array { array } => {
+            let (inner, size) = array;
+            let element = idl_type_to_rust(inner, options);
+            let inner = arbitrary_value(inner, types, options);
+            quote! {
+                (0..#size)
+                    .map(|_| #inner)
+                    .collect::<::arbitrary::Result<#alloc::vec::Vec<#element>>>()
+                    .map(|items| match <[#element; #size]>::try_from(items) {
+                        Ok(array) => array,
+                        Err(_) => unreachable!(),
+                    })
+            }
+        }
+        IdlType::Tuple { tuple } => {
+            let values = tuple.iter().map(|ty| arbitrary_value(ty, types, options));
+            quote! { Ok((#((#values)?,)*)) }
+        }
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => {
+            let key = arbitrary_value(key, types, options);
+            let value = arbitrary_value(value, types, options);
+            quote! {
+                (0..#len)
+                    .map(|_| Ok(((#key)?, (#value)?)))
+                    .collect::<::arbitrary::Result<#rust_type>>()
+            }
+        }
+        IdlType::Generic { .. } => unreachable!("type parameters hold no keys"),
+        IdlType::Unsupported(value) => panic!("Unsupported IDL type {}", value),
+    }
+}
+
+/// Whether `ty` holds a `Pubkey`, looking through containers and aliases
+fn contains_pubkey(ty: &IdlType, types: &[IdlTypeDef]) -> bool {
+    match ty {
+        IdlType::Primitive(name) => name == "pubkey" || name == "publicKey",
+        IdlType::Defined { defined } => {
+            alias_target(defined.name(), types).is_some_and(|target| contains_pubkey(target, types))
+        }
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => contains_pubkey(inner, types),
+        IdlType::Array { array } => contains_pubkey(&array.0, types),
+        IdlType::Tuple { tuple } => tuple.iter().any(|ty| contains_pubkey(ty, types)),
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => contains_pubkey(key, types) || contains_pubkey(value, types),
+        IdlType::Generic { .. } | IdlType::Unsupported(_) => false,
+    }
+}
+
+/// Target of the type alias `name`, if it is one
+fn alias_target<'a>(name: &str, types: &'a [IdlTypeDef]) -> Option<&'a IdlType> {
+    types
+        .iter()
+        .find(|typedef| typedef.name == name)
+        .and_then(|typedef| typedef.ty.alias.as_ref())
+}
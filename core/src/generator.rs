@@ -0,0 +1,2611 @@
+use crate::derives::DeriveAnalysis;
+use crate::fixtures;
+use crate::fuzz;
+use crate::json;
+use crate::layout;
+use crate::parser::{
+    Idl, IdlAccount, IdlConst, IdlEnumVariant, IdlEnumVariantFields, IdlEvent, IdlField, IdlFormat,
+    IdlGenericArg, IdlGenericParam, IdlInstruction, IdlSeed, IdlType, IdlTypeDef, IdlTypeDefFields,
+    IdlTypeDefType,
+};
+use crate::pda;
+use crate::repr;
+use crate::sdk;
+use crate::validate;
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Options controlling code generation, set through `parse_idl!` arguments
+#[derive(Debug)]
+pub struct GenerateOptions {
+    /// Reject instruction data with bytes left over after the args
+    pub deny_trailing_bytes: bool,
+    /// IDL format, detected from the IDL when unset
+    pub format: Option<IdlFormat>,
+    /// Derive serde traits on generated types and generate `decode_to_json` and
+    /// `to_anchor_json`, set from the `serde` feature of solana_idl_parser
+    pub serde: bool,
+    /// Rename serialized fields and instruction variants to their IDL names
+    pub serde_idl_names: bool,
+    /// Derive `arbitrary::Arbitrary` on generated types, set from the `fuzz`
+    /// feature of solana_idl_parser
+    pub fuzz: bool,
+    /// Derive `borsh::BorshSchema` on generated types, set from the `schema`
+    /// feature of solana_idl_parser
+    pub schema: bool,
+    /// Generate `<INSTRUCTION>_SAMPLE_DATA` constants, set from the
+    /// `test-fixtures` feature of solana_idl_parser
+    pub test_fixtures: bool,
+    /// Target the runtime crate's minimal `Pubkey`, which cannot derive PDAs,
+    /// set from the `minimal-pubkey` feature of solana_idl_parser
+    pub minimal_pubkey: bool,
+    /// Skip unsupported types and instructions with a warning instead of failing
+    pub lenient: bool,
+    /// The args of each instruction start with its discriminator instead of following it
+    pub discriminator_in_args: bool,
+    /// Generate code using only `core`, `alloc` and `borsh`, without `std` or `anyhow`
+    pub no_std: bool,
+    /// Keep account metas past an instruction's named accounts in `remaining_accounts`
+    pub remaining_accounts: bool,
+    /// Dispatch on a sorted discriminator table searched by bisection instead of a `match`
+    pub dispatch_table: bool,
+    /// Generate only type definitions and program accounts, without instruction code
+    pub types_only: bool,
+    /// Check the IDL for consistency first, generating a `compile_error!` listing every problem
+    pub validate: bool,
+    /// Bytes before the discriminator, e.g. a version byte, skipped when decoding
+    pub discriminator_offset: usize,
+    /// Path of the error type returned by generated functions, converting from `DecodeError`
+    pub error: Option<String>,
+    /// Base name of the instructions enum and other program-wide types,
+    /// instead of the program name in PascalCase
+    pub enum_name: Option<String>,
+    /// Put each instruction's structs, constants and helpers in a module named after it
+    pub instruction_modules: bool,
+    /// Older discriminators decoded as the instruction named by the key, in its IDL or
+    /// snake_case form, e.g. from before a program upgrade
+    pub legacy_discriminators: BTreeMap<String, Vec<Vec<u8>>>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            deny_trailing_bytes: false,
+            format: None,
+            serde: false,
+            serde_idl_names: false,
+            fuzz: false,
+            schema: false,
+            test_fixtures: false,
+            minimal_pubkey: false,
+            lenient: false,
+            discriminator_in_args: false,
+            no_std: false,
+            remaining_accounts: false,
+            dispatch_table: false,
+            types_only: false,
+            validate: true,
+            discriminator_offset: 0,
+            error: None,
+            enum_name: None,
+            instruction_modules: false,
+            legacy_discriminators: BTreeMap::new(),
+        }
+    }
+}
+
+/// Generate all code from an IDL
+pub fn generate_idl_code(idl: &Idl, options: &GenerateOptions) -> TokenStream {
+    if options.validate {
+        let problems = validate::validate(idl, options);
+        if !problems.is_empty() {
+            let message = format!(
+                "IDL `{}` failed validation:\n{}",
+                idl.metadata.name,
+                problems
+                    .iter()
+                    .map(|problem| format!("  - {}", problem))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+            return quote! { compile_error!(#message); };
+        }
+    }
+
+    check_legacy_discriminator_names(idl, options);
+    let enum_name = instructions_enum_name(idl, options);
+    let program_id = generate_program_id(&idl.address);
+    let counts = generate_counts(idl);
+    let constants = generate_constants(&idl.constants, options);
+    let warnings = generate_warnings(&idl.warnings);
+    // Runs first since it rejects recursive types the other generators would loop on
+    let derives = DeriveAnalysis::new(&idl.types, &idl.accounts);
+    let types = generate_types(&idl.types, &idl.accounts, &derives, options);
+    let accounts = generate_accounts(&idl.accounts, &idl.types, &derives, options);
+    let events = generate_events(idl, options);
+    // An IDL published only for its account layouts would get an enum without variants
+    if options.types_only || !has_instruction_code(idl) {
+        return quote! {
+            #warnings
+            #program_id
+            #counts
+            #constants
+            #types
+            #accounts
+            #events
+        };
+    }
+
+    let discriminator_offset = generate_discriminator_offset(options);
+    let discriminator_lookup = generate_discriminator_lookup(
+        &idl.instructions,
+        &idl.skipped_instructions,
+        &idl.types,
+        options,
+    );
+    let pda_seeds = generate_pda_const_seeds(&idl.instructions);
+    let (discriminators, skipped_discriminators, sample_data, instruction_structs, pda_helpers) =
+        if options.instruction_modules {
+            let modules = generate_instruction_modules(idl, options);
+            (quote! {}, quote! {}, quote! {}, modules, quote! {})
+        } else {
+            (
+                generate_discriminators(&idl.instructions, &idl.types, options),
+                generate_discriminators(&idl.skipped_instructions, &idl.types, options),
+                fixtures::generate_sample_data(&idl.instructions, &idl.types, options),
+                generate_instruction_structs(&idl.instructions, &idl.types, options),
+                pda::generate_pda_helpers(&idl.instructions, &idl.types, options),
+            )
+        };
+    let instructions_enum =
+        generate_instructions_enum(&enum_name, &idl.instructions, &idl.types, options);
+    let deserialize_impl = generate_deserialize_impl(
+        &enum_name,
+        &idl.instructions,
+        &idl.skipped_instructions,
+        &idl.types,
+        options,
+    );
+    let instruction_builder_impl =
+        generate_instruction_builder_impl(&enum_name, &idl.instructions, &idl.types, options);
+    let visitor = generate_visitor(idl, &enum_name, &idl.types, options);
+    let instruction_kind = generate_instruction_kind(idl, &enum_name, options);
+
+    quote! {
+        #warnings
+        #program_id
+        #counts
+        #constants
+        #discriminator_offset
+        #discriminators
+        #skipped_discriminators
+        #discriminator_lookup
+        #sample_data
+        #pda_seeds
+        #instruction_structs
+        #pda_helpers
+        #types
+        #accounts
+        #events
+        #instructions_enum
+        #deserialize_impl
+        #instruction_builder_impl
+        #visitor
+        #instruction_kind
+    }
+}
+
+/// Generate one module per IDL plus a `decode_any` dispatcher over all of them
+///
+/// Every IDL is generated with the same `options`.
+pub fn generate_multi_idl_code(
+    programs: &[(syn::Ident, Idl)],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let modules: Vec<TokenStream> = programs
+        .iter()
+        .map(|(module, idl)| {
+            let code = generate_idl_code(idl, options);
+            quote! {
+                pub mod #module {
+                    #code
+                }
+            }
+        })
+        .collect();
+
+    // Programs without instructions have no instructions enum to dispatch to
+    let programs: Vec<&(syn::Ident, Idl)> = programs
+        .iter()
+        .filter(|(_, idl)| has_instruction_code(idl))
+        .collect();
+    if programs.is_empty() {
+        return quote! { #(#modules)* };
+    }
+
+    let variants = programs.iter().map(|(module, idl)| {
+        let variant_name = format_ident!("{}", module.to_string().to_case(Case::Pascal));
+        let enum_name = instructions_enum_name(idl, options);
+        quote! { #variant_name(#module::#enum_name) }
+    });
+
+    let dispatch_branches = programs.iter().map(|(module, idl)| {
+        let variant_name = format_ident!("{}", module.to_string().to_case(Case::Pascal));
+        let enum_name = instructions_enum_name(idl, options);
+        quote! {
+            if *program == #module::PROGRAM_ID {
+                return Some(#module::#enum_name::deserialize(accounts, buf).map(AnyInstruction::#variant_name));
+            }
+        }
+    });
+
+    let pubkey = sdk::pubkey_type();
+    let account_meta = sdk::account_meta_type();
+    let serde_derive = sdk::serde_derive(options);
+    let arbitrary_derive = fuzz::arbitrary_derive(options);
+    let result = sdk::result_type(options, quote! { AnyInstruction });
+
+    quote! {
+        #(#modules)*
+
+        #[derive(Debug, Clone)]
+        #serde_derive
+        #arbitrary_derive
+        pub enum AnyInstruction {
+            #(#variants,)*
+        }
+
+        /// Decode an instruction of any of the parsed programs, or `None` if `program` is unknown
+        pub fn decode_any(
+            program: &#pubkey,
+            accounts: &[#account_meta],
+            buf: impl AsRef<[u8]>,
+        ) -> ::core::option::Option<#result> {
+            let buf = buf.as_ref();
+            #(#dispatch_branches)*
+            None
+        }
+    }
+}
+
+/// Whether the IDL has instructions to generate an instructions enum and its decoding for
+///
+/// Instructions skipped in lenient mode count, since decoding reports them.
+pub fn has_instruction_code(idl: &Idl) -> bool {
+    !idl.instructions.is_empty() || !idl.skipped_instructions.is_empty()
+}
+
+/// Name of the generated instructions enum, e.g. `PumpAmmInstructions`
+fn instructions_enum_name(idl: &Idl, options: &GenerateOptions) -> syn::Ident {
+    format_ident!("{}Instructions", program_type_name(idl, options))
+}
+
+/// Prefix of program-wide type names, the `enum_name` option or the program name in PascalCase
+pub fn program_type_name(idl: &Idl, options: &GenerateOptions) -> String {
+    options
+        .enum_name
+        .clone()
+        .unwrap_or_else(|| idl.metadata.name.to_case(Case::Pascal))
+}
+
+/// Generate the program id constant from the IDL address
+fn generate_program_id(address: &str) -> TokenStream {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .unwrap_or_else(|e| panic!("Invalid program address {:?}: {}", address, e));
+    if bytes.len() != 32 {
+        panic!(
+            "Invalid program address {:?}: expected 32 bytes, got {}",
+            address,
+            bytes.len()
+        );
+    }
+    let byte_literals = bytes.iter().map(|b| quote! { #b });
+    let pubkey = sdk::pubkey_type();
+
+    quote! {
+        pub const PROGRAM_ID: #pubkey = #pubkey::new_from_array([#(#byte_literals),*]);
+    }
+}
+
+/// `INSTRUCTION_COUNT` and `ACCOUNT_COUNT` of the program
+///
+/// Instructions skipped in lenient mode have no variant and are not counted.
+fn generate_counts(idl: &Idl) -> TokenStream {
+    let instruction_count = idl.instructions.len();
+    let account_count = idl.accounts.len();
+
+    quote! {
+        /// Number of instructions, one per variant of the instructions enum
+        pub const INSTRUCTION_COUNT: usize = #instruction_count;
+        /// Number of entries in the IDL `accounts` section
+        pub const ACCOUNT_COUNT: usize = #account_count;
+    }
+}
+
+/// Generate constants from the IDL constants section
+///
+/// Values are emitted as written in the IDL: integers become literals of the
+/// declared type and byte arrays keep their byte order, so nothing is
+/// reinterpreted for endianness.
+fn generate_constants(constants: &[IdlConst], options: &GenerateOptions) -> TokenStream {
+    let consts = constants.iter().map(|constant| {
+        let name = format_ident!("{}", constant.name.to_case(Case::ScreamingSnake));
+        let value = constant.value.trim();
+        let invalid = |e: &dyn std::fmt::Display| -> ! {
+            panic!(
+                "Invalid value {:?} for constant {}: {}",
+                value, constant.name, e
+            )
+        };
+
+        match &constant.ty {
+            IdlType::Primitive(ty) if ty == "bytes" => {
+                let bytes = parse_const_bytes(value).unwrap_or_else(|e| invalid(&e));
+                quote! { pub const #name: &[u8] = &[#(#bytes),*]; }
+            }
+            IdlType::Primitive(ty) if ty == "string" => {
+                let string = syn::parse_str::<syn::LitStr>(value)
+                    .map(|lit| lit.value())
+                    .unwrap_or_else(|_| value.to_string());
+                quote! { pub const #name: &str = #string; }
+            }
+            IdlType::Array { array } if matches!(&*array.0, IdlType::Primitive(ty) if ty == "u8") =>
+            {
+                let bytes = parse_const_bytes(value).unwrap_or_else(|e| invalid(&e));
+                let len = array.1;
+                quote! { pub const #name: [u8; #len] = [#(#bytes),*]; }
+            }
+            ty => {
+                let const_type = idl_type_to_rust(ty, options);
+                let expr: syn::Expr = syn::parse_str(value).unwrap_or_else(|e| invalid(&e));
+                quote! { pub const #name: #const_type = #expr; }
+            }
+        }
+    });
+
+    quote! { #(#consts)* }
+}
+
+/// Parse a byte constant written either as `[1, 2, 3]` or `b"..."`
+fn parse_const_bytes(value: &str) -> Result<Vec<u8>, String> {
+    if let Ok(lit) = syn::parse_str::<syn::LitByteStr>(value) {
+        return Ok(lit.value());
+    }
+    serde_json::from_str::<Vec<u8>>(value).map_err(|e| e.to_string())
+}
+
+/// Surface lenient-mode messages as compile warnings
+///
+/// Proc macros cannot emit warnings on stable, so each message is attached to
+/// a deprecated item that is used right away.
+fn generate_warnings(warnings: &[String]) -> TokenStream {
+    let warnings = warnings.iter().map(|warning| {
+        let note = format!("parse_idl!: {}", warning);
+        quote! {
+            const _: () = {
+                #[deprecated(note = #note)]
+                struct IdlWarning;
+                let _ = IdlWarning;
+            };
+        }
+    });
+
+    quote! { #(#warnings)* }
+}
+
+/// `DISCRIMINATOR_OFFSET`, when instruction data has a header before the discriminator
+fn generate_discriminator_offset(options: &GenerateOptions) -> TokenStream {
+    let offset = options.discriminator_offset;
+    if offset == 0 {
+        return quote! {};
+    }
+    quote! {
+        /// Bytes of instruction data before the discriminator, skipped when decoding
+        pub const DISCRIMINATOR_OFFSET: usize = #offset;
+    }
+}
+
+/// Statement dropping the bytes before the discriminator from `buf`, if any
+fn strip_discriminator_offset(options: &GenerateOptions) -> TokenStream {
+    if options.discriminator_offset == 0 {
+        return quote! {};
+    }
+    let error = sdk::decode_error(
+        options,
+        "UnexpectedEof",
+        quote! { "instruction data is shorter than its discriminator offset" },
+    );
+    quote! {
+        let buf = buf.get(DISCRIMINATOR_OFFSET..).ok_or_else(|| #error)?;
+    }
+}
+
+/// Generate discriminator constants and `matches_{name}_discriminator` functions
+fn generate_discriminators(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let data = if options.discriminator_offset == 0 {
+        quote! { data }
+    } else {
+        quote! { data.get(DISCRIMINATOR_OFFSET..).unwrap_or_default() }
+    };
+    let discriminators = instructions.iter().map(|ix| {
+        let items = InstructionItems::new(ix, types, options);
+        let const_name = &items.discriminator;
+        let matches_fn = &items.matches_discriminator;
+        let bytes: Vec<u8> = ix.discriminator.clone();
+        let len = bytes.len();
+        let byte_literals = bytes.iter().map(|b| quote! { #b });
+        let legacy = legacy_discriminators(ix, options);
+
+        quote! {
+            pub const #const_name: [u8; #len] = [#(#byte_literals),*];
+
+            /// Whether instruction data starts with this instruction's discriminator
+            pub fn #matches_fn(data: &[u8]) -> bool {
+                #data.starts_with(&#const_name) #(|| #data.starts_with(&#legacy))*
+            }
+        }
+    });
+
+    quote! { #(#discriminators)* }
+}
+
+/// Generate `discriminator_of`, looking up an instruction's discriminator by name
+///
+/// Both the IDL name and its snake_case form are matched. The discriminator is
+/// returned as an array when all have the same length, as a slice otherwise.
+fn generate_discriminator_lookup(
+    instructions: &[IdlInstruction],
+    skipped_instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let all_instructions = instructions.iter().chain(skipped_instructions);
+    let uniform_len = discriminator_len(all_instructions.clone());
+    let ret = match uniform_len {
+        Some(len) => quote! { [u8; #len] },
+        None => quote! { &'static [u8] },
+    };
+
+    let mut seen = HashSet::new();
+    let arms = all_instructions.map(|ix| {
+        let items = InstructionItems::new(ix, types, options);
+        let discrim_const = items.path(&items.discriminator);
+        let value = match uniform_len {
+            Some(_) => quote! { #discrim_const },
+            None => quote! { &#discrim_const[..] },
+        };
+        let snake = ix.name.to_case(Case::Snake);
+        // Each name is matched once, by the first instruction it belongs to
+        let names: Vec<&str> = [ix.name.as_str(), snake.as_str()]
+            .into_iter()
+            .filter(|name| seen.insert(name.to_string()))
+            .collect();
+        if names.is_empty() {
+            return quote! {};
+        }
+        quote! { #(#names)|* => Some(#value), }
+    });
+
+    let known = instructions.iter().chain(skipped_instructions).map(|ix| {
+        let name = &ix.name;
+        let discrim_const = discriminator_path(ix, types, options);
+        quote! { (#name, &#discrim_const) }
+    });
+
+    quote! {
+        /// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+        pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[#(#known),*];
+
+        /// Discriminator of the instruction named `name`, in its IDL or snake_case form
+        pub fn discriminator_of(name: &str) -> Option<#ret> {
+            match name {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Discriminators registered for `ix` through the `legacy_discriminators` option, as array literals
+///
+/// Panics if one differs in length from the IDL discriminator, since the
+/// args are read after as many bytes.
+fn legacy_discriminators(ix: &IdlInstruction, options: &GenerateOptions) -> Vec<TokenStream> {
+    legacy_discriminator_bytes(ix, options)
+        .into_iter()
+        .map(|bytes| {
+            if bytes.len() != ix.discriminator.len() {
+                panic!(
+                    "Legacy discriminator {:?} of instruction {} has {} bytes, expected {} like its IDL discriminator",
+                    bytes,
+                    ix.name,
+                    bytes.len(),
+                    ix.discriminator.len()
+                );
+            }
+            quote! { [#(#bytes),*] }
+        })
+        .collect()
+}
+
+/// Discriminators registered for `ix` through the `legacy_discriminators` option
+pub(crate) fn legacy_discriminator_bytes(
+    ix: &IdlInstruction,
+    options: &GenerateOptions,
+) -> Vec<Vec<u8>> {
+    let snake = ix.name.to_case(Case::Snake);
+    let mut names = vec![ix.name.as_str()];
+    if snake != ix.name {
+        names.push(&snake);
+    }
+    names
+        .into_iter()
+        .filter_map(|name| options.legacy_discriminators.get(name))
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// Panic if `legacy_discriminators` names an instruction the IDL does not have
+fn check_legacy_discriminator_names(idl: &Idl, options: &GenerateOptions) {
+    for name in options.legacy_discriminators.keys() {
+        let known = idl
+            .instructions
+            .iter()
+            .chain(&idl.skipped_instructions)
+            .any(|ix| ix.name == *name || ix.name.to_case(Case::Snake) == *name);
+        if !known {
+            panic!(
+                "`legacy_discriminators` names unknown instruction `{}`",
+                name
+            );
+        }
+    }
+}
+
+/// `DecodeError::UnknownDiscriminator` for the bytes `discriminator` evaluates to
+///
+/// The closest known instruction is only looked up with `validate` on.
+fn unknown_discriminator_error(
+    options: &GenerateOptions,
+    discriminator: TokenStream,
+) -> TokenStream {
+    let decode_error = sdk::decode_error_type();
+    let known = if options.validate {
+        quote! { INSTRUCTION_DISCRIMINATORS }
+    } else {
+        quote! { &[] }
+    };
+    quote! { #decode_error::unknown_discriminator(#discriminator, #known) }
+}
+
+/// The first bytes of `buf`, up to the length of the longest discriminator
+fn leading_bytes<'a>(instructions: impl Iterator<Item = &'a IdlInstruction>) -> TokenStream {
+    let max_len = instructions
+        .map(|ix| ix.discriminator.len())
+        .max()
+        .unwrap_or(0);
+    quote! { &buf[..buf.len().min(#max_len)] }
+}
+
+/// Length shared by all instruction discriminators, 8 for Anchor programs
+///
+/// Returns `None` when the lengths differ, as in IDLs mixing Anchor
+/// instructions with ones using a custom prefix.
+fn discriminator_len<'a>(
+    mut instructions: impl Iterator<Item = &'a IdlInstruction>,
+) -> Option<usize> {
+    let Some(first) = instructions.next() else {
+        return Some(8);
+    };
+    let len = first.discriminator.len();
+    instructions
+        .all(|ix| ix.discriminator.len() == len)
+        .then_some(len)
+}
+
+/// Generate constants for the literal seeds of PDA accounts
+///
+/// A PDA with a single const seed gets `{ACCOUNT}_PDA_CONST_SEED`; with several,
+/// each is suffixed with its position in the seed list. Accounts repeated across
+/// instructions are emitted once, unless their seeds differ, in which case the
+/// later ones are prefixed with the instruction name.
+fn generate_pda_const_seeds(instructions: &[IdlInstruction]) -> TokenStream {
+    let mut seen: HashMap<String, Vec<(usize, &Vec<u8>)>> = HashMap::new();
+    let mut tokens = TokenStream::new();
+
+    for ix in instructions {
+        for acc in &ix.accounts {
+            let Some(pda) = &acc.pda else { continue };
+            let const_seeds: Vec<(usize, &Vec<u8>)> = pda
+                .seeds
+                .iter()
+                .enumerate()
+                .filter_map(|(i, seed)| match seed {
+                    IdlSeed::Const { value } => Some((i, value)),
+                    _ => None,
+                })
+                .collect();
+            if const_seeds.is_empty() {
+                continue;
+            }
+
+            let prefix = match seen.get(&acc.name) {
+                Some(existing) if *existing == const_seeds => continue,
+                Some(_) => format!("{}_{}", ix.name, acc.name).to_case(Case::ScreamingSnake),
+                None => {
+                    seen.insert(acc.name.clone(), const_seeds.clone());
+                    acc.name.to_case(Case::ScreamingSnake)
+                }
+            };
+
+            let single = const_seeds.len() == 1;
+            for (i, value) in const_seeds {
+                let const_name = if single {
+                    format_ident!("{}_PDA_CONST_SEED", prefix)
+                } else {
+                    format_ident!("{}_PDA_CONST_SEED_{}", prefix, i)
+                };
+                tokens.extend(quote! {
+                    pub const #const_name: &[u8] = &[#(#value),*];
+                });
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Generate one module per instruction holding its structs, constants and helpers
+///
+/// Instructions skipped in lenient mode only get their discriminator.
+fn generate_instruction_modules(idl: &Idl, options: &GenerateOptions) -> TokenStream {
+    let types = &idl.types;
+    let decodable = idl.instructions.iter().map(|ix| (ix, true));
+    let skipped = idl.skipped_instructions.iter().map(|ix| (ix, false));
+
+    let modules = decodable.chain(skipped).map(|(ix, decodable)| {
+        let module = format_ident!("{}", ix.name.to_case(Case::Snake));
+        let doc = format!("Items generated for the `{}` instruction", ix.name);
+        let ix = std::slice::from_ref(ix);
+        let discriminators = generate_discriminators(ix, types, options);
+        let items = if decodable {
+            let sample_data = fixtures::generate_sample_data(ix, types, options);
+            let structs = generate_instruction_structs(ix, types, options);
+            let pda_helpers = pda::generate_pda_helpers(ix, types, options);
+            quote! {
+                #sample_data
+                #structs
+                #pda_helpers
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #[doc = #doc]
+            pub mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #discriminators
+                #items
+            }
+        }
+    });
+
+    quote! { #(#modules)* }
+}
+
+/// Generate Accounts struct and Args struct for each instruction
+fn generate_instruction_structs(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let pubkey = sdk::pubkey_type();
+    let account_meta = sdk::account_meta_type();
+    let serde_derive = sdk::serde_derive(options);
+    let schema_derive = sdk::schema_derive(options);
+    let arbitrary_derive = fuzz::arbitrary_derive(options);
+    let pubkey_type = IdlType::Primitive("pubkey".to_string());
+    let pubkey_attr = fuzz::field_attr(&pubkey_type, types, options);
+    let optional_pubkey_attr = fuzz::field_attr(
+        &IdlType::Option {
+            option: Box::new(pubkey_type.clone()),
+        },
+        types,
+        options,
+    );
+    let alloc = sdk::alloc_crate(options.no_std);
+    let result = sdk::result_type(options, quote! { Self });
+
+    let structs = instructions.iter().map(|ix| {
+        let items = InstructionItems::new(ix, types, options);
+        let discrim_const = &items.discriminator;
+        let discrim_len = ix.discriminator.len();
+
+        let mut tokens = TokenStream::new();
+
+        if !ix.accounts.is_empty() {
+            let accounts_len = ix.accounts.len();
+            let len_const = &items.accounts_len;
+            let accounts_struct_name = &items.accounts;
+
+            let account_names = unique_field_names(ix.accounts.iter().map(|acc| acc.name.as_str()));
+
+            // Optional accounts after the last required one may be left out
+            let min_accounts_len = ix
+                .accounts
+                .iter()
+                .rposition(|acc| !acc.optional)
+                .map_or(0, |i| i + 1);
+
+            let account_fields = ix
+                .accounts
+                .iter()
+                .zip(&account_names)
+                .map(|(acc, field_name)| {
+                    let rename = serde_rename(options, &acc.name, field_name);
+                    let docs = doc_attrs(&acc.docs);
+                    if acc.optional {
+                        quote! { #docs #rename #optional_pubkey_attr pub #field_name: Option<#pubkey> }
+                    } else {
+                        quote! { #docs #rename #pubkey_attr pub #field_name: #pubkey }
+                    }
+                });
+
+            // Anchor passes the program id in place of an omitted optional account
+            let from_metas_fields = ix.accounts.iter().zip(&account_names).enumerate().map(
+                |(i, (acc, field_name))| {
+                    let idx = syn::Index::from(i);
+                    if acc.optional {
+                        quote! {
+                            #field_name: metas
+                                .get(#idx)
+                                .map(|meta| meta.pubkey)
+                                .filter(|pubkey| *pubkey != PROGRAM_ID)
+                        }
+                    } else {
+                        quote! { #field_name: metas[#idx].pubkey }
+                    }
+                },
+            );
+
+            let to_metas_entries =
+                ix.accounts
+                    .iter()
+                    .zip(&account_names)
+                    .map(|(acc, field_name)| {
+                        let is_signer = acc.signer;
+                        let is_writable = acc.writable;
+                        if acc.optional {
+                            quote! {
+                                match self.#field_name {
+                                    Some(pubkey) => #account_meta {
+                                        pubkey,
+                                        is_signer: #is_signer,
+                                        is_writable: #is_writable,
+                                    },
+                                    None => #account_meta {
+                                        pubkey: PROGRAM_ID,
+                                        is_signer: false,
+                                        is_writable: false,
+                                    },
+                                }
+                            }
+                        } else {
+                            quote! {
+                                #account_meta {
+                                    pubkey: self.#field_name,
+                                    is_signer: #is_signer,
+                                    is_writable: #is_writable,
+                                }
+                            }
+                        }
+                    });
+
+            let named_entries = ix
+                .accounts
+                .iter()
+                .zip(&account_names)
+                .map(|(acc, field_name)| {
+                    let idl_name = &acc.name;
+                    if acc.optional {
+                        quote! { (#idl_name, self.#field_name.unwrap_or(PROGRAM_ID)) }
+                    } else {
+                        quote! { (#idl_name, self.#field_name) }
+                    }
+                });
+
+            // Remaining account metas are not part of any serialized form and
+            // carry no `Copy` or `Hash`
+            let named_metas = quote! {
+                #alloc::vec![
+                    #(#to_metas_entries,)*
+                ]
+            };
+            let (derives, remaining_field, remaining_from_metas, to_metas, length_check) =
+                if options.remaining_accounts {
+                    let serde_skip = sdk::serde_skip_attr(options);
+                    let arbitrary_skip = fuzz::skip_attr(options);
+                    (
+                        quote! { Clone, Debug, PartialEq, Eq },
+                        quote! {
+                            /// Account metas passed after the named accounts
+                            #[borsh(skip)]
+                            #serde_skip
+                            #arbitrary_skip
+                            pub remaining_accounts: #alloc::vec::Vec<#account_meta>,
+                        },
+                        quote! {
+                            remaining_accounts: metas.get(#len_const..).unwrap_or_default().to_vec(),
+                        },
+                        quote! { [#named_metas, self.remaining_accounts.clone()].concat() },
+                        if min_accounts_len == accounts_len {
+                            quote! { metas.len() < #len_const }
+                        } else {
+                            quote! { metas.len() < #min_accounts_len }
+                        },
+                    )
+                } else {
+                    let length_check = if min_accounts_len == accounts_len {
+                        quote! { metas.len() != #len_const }
+                    } else {
+                        quote! { !(#min_accounts_len..=#len_const).contains(&metas.len()) }
+                    };
+                    (
+                        quote! { Copy, Clone, Debug, PartialEq, Eq, Hash },
+                        quote! {},
+                        quote! {},
+                        named_metas,
+                        length_check,
+                    )
+                };
+            let decode_error = sdk::decode_error_type();
+            let expected_len = if min_accounts_len == accounts_len {
+                quote! { #len_const }
+            } else {
+                quote! {
+                    if metas.len() < #min_accounts_len { #min_accounts_len } else { #len_const }
+                }
+            };
+            let length_error = quote! {
+                #decode_error::InvalidAccountLen {
+                    expected: #expected_len,
+                    got: metas.len(),
+                }
+            };
+
+            tokens.extend(quote! {
+                pub const #len_const: usize = #accounts_len;
+
+                #[derive(#derives, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+                #serde_derive
+                #schema_derive
+                #arbitrary_derive
+                pub struct #accounts_struct_name {
+                    #(#account_fields,)*
+                    #remaining_field
+                }
+
+                impl #accounts_struct_name {
+                    /// Discriminator of the instruction taking these accounts
+                    pub const DISCRIMINATOR: [u8; #discrim_len] = #discrim_const;
+
+                    pub fn from_account_metas(metas: &[#account_meta]) -> #result {
+                        if #length_check {
+                            return Err(#length_error.into());
+                        }
+                        Ok(Self {
+                            #(#from_metas_fields,)*
+                            #remaining_from_metas
+                        })
+                    }
+
+                    pub fn to_account_metas(&self) -> #alloc::vec::Vec<#account_meta> {
+                        #to_metas
+                    }
+
+                    /// Each named account paired with its name in the IDL, in instruction order
+                    pub fn named_accounts(&self) -> [(&'static str, #pubkey); #len_const] {
+                        [#(#named_entries,)*]
+                    }
+
+                    /// Iterator over `named_accounts`, for streaming them without holding the array
+                    pub fn iter(&self) -> impl Iterator<Item = (&'static str, #pubkey)> {
+                        self.named_accounts().into_iter()
+                    }
+                }
+            });
+        }
+
+        if !ix.args.is_empty() {
+            let args_struct_name = &items.args;
+            let arg_names = unique_field_names(ix.args.iter().map(|arg| arg.name.as_str()));
+            let arg_fields = ix.args.iter().zip(&arg_names).map(|(arg, field_name)| {
+                let field_type = idl_type_to_rust(&arg.ty, options);
+                let rename = serde_rename(options, &arg.name, field_name);
+                let docs = doc_attrs(&arg.docs);
+                let arbitrary = fuzz::named_field_attr(arg, types, options);
+                let place = format!("arg `{}` of instruction {}", arg.name, ix.name);
+                let borsh = field_borsh_attrs(arg, &place);
+                quote! { #docs #rename #arbitrary #borsh pub #field_name: #field_type }
+            });
+            let to_json = json::args_impl(args_struct_name, &ix.args, &arg_names, options);
+
+            tokens.extend(quote! {
+                #[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+                #serde_derive
+                #schema_derive
+                #arbitrary_derive
+                pub struct #args_struct_name {
+                    #(#arg_fields,)*
+                }
+
+                impl #args_struct_name {
+                    /// Discriminator of the instruction taking these args
+                    pub const DISCRIMINATOR: [u8; #discrim_len] = #discrim_const;
+                }
+
+                #to_json
+            });
+        }
+
+        tokens
+    });
+
+    quote! { #(#structs)* }
+}
+
+/// Name of an instruction's args struct, `{Name}Args` unless the IDL already
+/// defines a type with that name (common in Shank IDLs), then `{Name}InstructionArgs`
+pub fn args_struct_name(ix: &IdlInstruction, types: &[IdlTypeDef]) -> syn::Ident {
+    let name = format!("{}Args", ix.name.to_case(Case::Pascal));
+    if types.iter().any(|typedef| typedef.name == name) {
+        format_ident!("{}InstructionArgs", ix.name.to_case(Case::Pascal))
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
+/// Names of the items generated for an instruction
+///
+/// They are prefixed with the instruction name, e.g. `BuyAccounts` and
+/// `BUY_DISCRIMINATOR`, or with `instruction_modules` live in a module named
+/// after the instruction without the prefix, e.g. `buy::Accounts`.
+pub struct InstructionItems {
+    module: Option<syn::Ident>,
+    pub accounts: syn::Ident,
+    pub args: syn::Ident,
+    pub discriminator: syn::Ident,
+    pub accounts_len: syn::Ident,
+    pub matches_discriminator: syn::Ident,
+    pub sample_data: syn::Ident,
+}
+
+impl InstructionItems {
+    pub fn new(ix: &IdlInstruction, types: &[IdlTypeDef], options: &GenerateOptions) -> Self {
+        if options.instruction_modules {
+            return InstructionItems {
+                module: Some(format_ident!("{}", ix.name.to_case(Case::Snake))),
+                accounts: format_ident!("Accounts"),
+                args: format_ident!("Args"),
+                discriminator: format_ident!("DISCRIMINATOR"),
+                accounts_len: format_ident!("ACCOUNTS_LEN"),
+                matches_discriminator: format_ident!("matches_discriminator"),
+                sample_data: format_ident!("SAMPLE_DATA"),
+            };
+        }
+        let screaming = ix.name.to_case(Case::ScreamingSnake);
+        InstructionItems {
+            module: None,
+            accounts: format_ident!("{}Accounts", ix.name.to_case(Case::Pascal)),
+            args: args_struct_name(ix, types),
+            discriminator: format_ident!("{}_DISCRIMINATOR", screaming),
+            accounts_len: format_ident!("{}_IX_ACCOUNTS_LEN", screaming),
+            matches_discriminator: format_ident!(
+                "matches_{}_discriminator",
+                ix.name.to_case(Case::Snake)
+            ),
+            sample_data: format_ident!("{}_SAMPLE_DATA", screaming),
+        }
+    }
+
+    /// Path to `item`, one of these names, from the top of the generated code
+    pub fn path(&self, item: &syn::Ident) -> TokenStream {
+        match &self.module {
+            Some(module) => quote! { #module::#item },
+            None => quote! { #item },
+        }
+    }
+}
+
+/// Path to an instruction's discriminator constant from the top of the generated code
+fn discriminator_path(
+    ix: &IdlInstruction,
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let items = InstructionItems::new(ix, types, options);
+    items.path(&items.discriminator)
+}
+
+/// `#[serde(rename = "...")]` back to the IDL name, if enabled and the identifier differs
+fn serde_rename(options: &GenerateOptions, idl_name: &str, ident: &syn::Ident) -> TokenStream {
+    if options.serde_idl_names && ident != idl_name {
+        quote! { #[serde(rename = #idl_name)] }
+    } else {
+        quote! {}
+    }
+}
+
+/// `#[doc = "..."]` attributes for the IDL doc lines
+fn doc_attrs(docs: &[String]) -> TokenStream {
+    quote! { #(#[doc = #docs])* }
+}
+
+/// `#[borsh(...)]` attribute from a `borsh` IDL extension, if any
+///
+/// Panics if the attribute contents are not valid tokens.
+fn borsh_attr(attr: &Option<String>, place: &str) -> TokenStream {
+    let Some(attr) = attr else {
+        return quote! {};
+    };
+    let tokens: TokenStream = syn::parse_str(attr)
+        .unwrap_or_else(|e| panic!("Invalid borsh attribute {:?} on {}: {}", attr, place, e));
+    quote! { #[borsh(#tokens)] }
+}
+
+/// `#[borsh(...)]` attributes of a field: its `borsh` extension, then the `len` check if any
+///
+/// Panics if `len` is set on a field that is not `bytes`.
+fn field_borsh_attrs(field: &IdlField, place: &str) -> TokenStream {
+    let attr = borsh_attr(&field.borsh, place);
+    let Some(len) = field.len else {
+        return attr;
+    };
+    if !matches!(&field.ty, IdlType::Primitive(ty) if ty == "bytes") {
+        panic!(
+            "`len` is only supported on `bytes` fields, found it on {}",
+            place
+        );
+    }
+    let with = format!(
+        "::solana_idl_parser::deserialize_bytes_of_len::<_, {}>",
+        len
+    );
+    quote! {
+        #attr
+        #[borsh(deserialize_with = #with)]
+    }
+}
+
+/// Snake-case field identifiers, suffixing repeated names (`authority`, `authority_1`, ...)
+pub fn unique_field_names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<syn::Ident> {
+    let names: Vec<String> = names.map(|name| name.to_case(Case::Snake)).collect();
+    let mut taken: HashSet<String> = HashSet::new();
+
+    names
+        .iter()
+        .map(|name| {
+            let mut unique = name.clone();
+            let mut suffix = 1;
+            while taken.contains(&unique) || (unique != *name && names.contains(&unique)) {
+                unique = format!("{}_{}", name, suffix);
+                suffix += 1;
+            }
+            taken.insert(unique.clone());
+            format_ident!("{}", unique)
+        })
+        .collect()
+}
+
+/// Generate types from the IDL types section
+fn generate_types(
+    types: &[IdlTypeDef],
+    accounts: &[IdlAccount],
+    derives: &DeriveAnalysis,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let type_defs = types
+        .iter()
+        .filter(|typedef| {
+            !accounts
+                .iter()
+                .any(|acc| acc.name == typedef.name && acc.ty.is_some())
+        })
+        .map(|typedef| {
+            generate_type_def(
+                &typedef.name,
+                &typedef.ty,
+                &typedef.generics,
+                types,
+                derives,
+                options,
+            )
+        });
+
+    quote! { #(#type_defs)* }
+}
+
+/// Generate a single struct or enum definition
+///
+/// All types derive `PartialEq`; `Eq`, `Hash` and `Ord` are added when the
+/// analysis allows them, bounded on any type parameters by the derives.
+fn generate_type_def(
+    name: &str,
+    ty: &IdlTypeDefType,
+    generics: &[IdlGenericParam],
+    types: &[IdlTypeDef],
+    derives: &DeriveAnalysis,
+    options: &GenerateOptions,
+) -> TokenStream {
+    // Wide enum tags are written by hand-written borsh impls
+    let wide_tag = repr::has_wide_tag(ty);
+    if wide_tag && !generics.is_empty() {
+        panic!("Generic enum {} cannot have a {} repr", name, ty.tag_repr());
+    }
+    let derive_attr = derives.derive_attr(name, !wide_tag);
+    let serde_derive = sdk::serde_derive(options);
+    let schema_derive = if wide_tag {
+        quote! {}
+    } else {
+        sdk::schema_derive(options)
+    };
+    let arbitrary_derive = fuzz::arbitrary_derive(options);
+    let container_borsh = borsh_attr(&ty.borsh, &format!("type {}", name));
+    let derives =
+        quote! { #derive_attr #serde_derive #schema_derive #arbitrary_derive #container_borsh };
+    let params: Vec<syn::Ident> = generics
+        .iter()
+        .map(|param| {
+            if param.kind != "type" {
+                panic!(
+                    "Generic parameter `{}` of type {} has unsupported kind `{}`",
+                    param.name, name, param.kind
+                );
+            }
+            format_ident!("{}", param.name)
+        })
+        .collect();
+    let generics = if params.is_empty() {
+        quote! {}
+    } else {
+        quote! { <#(#params),*> }
+    };
+    let name = format_ident!("{}", name);
+    let to_json = json::type_impl(&name, &params, ty, options);
+
+    match ty.kind.as_str() {
+        "struct" => match &ty.fields {
+            IdlTypeDefFields::Named(fields) => {
+                let field_defs = fields.iter().map(|f| {
+                    let field_name = format_ident!("{}", f.name.to_case(Case::Snake));
+                    let field_type = idl_type_to_rust(&f.ty, options);
+                    let rename = serde_rename(options, &f.name, &field_name);
+                    let docs = doc_attrs(&f.docs);
+                    let arbitrary = fuzz::named_field_attr(f, types, options);
+                    let borsh = field_borsh_attrs(f, &format!("field `{}` of {}", f.name, name));
+                    quote! { #docs #rename #arbitrary #borsh pub #field_name: #field_type }
+                });
+                let layout = layout::layout_const(&name.to_string(), fields, types);
+
+                quote! {
+                    #derives
+                    pub struct #name #generics {
+                        #(#field_defs,)*
+                    }
+                    #layout
+                    #to_json
+                }
+            }
+            IdlTypeDefFields::Tuple(tuple_types) => {
+                let field_types = tuple_types.iter().map(|ty| {
+                    let field_type = idl_type_to_rust(ty, options);
+                    let arbitrary = fuzz::field_attr(ty, types, options);
+                    quote! { #arbitrary pub #field_type }
+                });
+
+                quote! {
+                    #derives
+                    pub struct #name #generics (#(#field_types),*);
+                    #to_json
+                }
+            }
+            IdlTypeDefFields::None => {
+                quote! {
+                    #derives
+                    pub struct #name #generics;
+                    #to_json
+                }
+            }
+        },
+        "enum" => {
+            let variants = ty
+                .variants
+                .iter()
+                .map(|variant| generate_enum_variant(variant, types, options));
+            let borsh_impls = if wide_tag {
+                repr::wide_tag_impls(&name.to_string(), ty, options)
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                #derives
+                pub enum #name #generics {
+                    #(#variants,)*
+                }
+                #borsh_impls
+                #to_json
+            }
+        }
+        "type" | "alias" => {
+            let target = ty
+                .alias
+                .as_ref()
+                .unwrap_or_else(|| panic!("Type alias {} has no target type", name));
+            if ty.borsh.is_some() {
+                panic!("Type alias {} cannot take a borsh attribute", name);
+            }
+            let target = idl_type_to_rust(target, options);
+            quote! { pub type #name #generics = #target; }
+        }
+        // Loading rejects or skips other kinds, so this is only hit for hand-built IDLs
+        kind => panic!("Type {} has unsupported kind `{}`", name, kind),
+    }
+}
+
+/// Generate discriminator constants and decoders for program accounts
+///
+/// An account's layout comes from its inline `type` when present (pre-0.30
+/// IDLs), otherwise from the entry with the same name in `types`.
+fn generate_accounts(
+    accounts: &[IdlAccount],
+    types: &[IdlTypeDef],
+    derives: &DeriveAnalysis,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let pubkey = sdk::pubkey_type();
+    let result = sdk::result_type(options, quote! { Self });
+    let decode_error = sdk::decode_error_type();
+    let short_error = sdk::decode_error(
+        options,
+        "UnexpectedEof",
+        quote! { "account data is shorter than its discriminator" },
+    );
+    let mismatch_error = sdk::decode_error(
+        options,
+        "Other",
+        quote! { "account discriminator mismatch" },
+    );
+
+    let account_defs = accounts.iter().map(|acc| {
+        let name = format_ident!("{}", acc.name);
+        let discrim_const = format_ident!(
+            "{}_ACCOUNT_DISCRIMINATOR",
+            acc.name.to_case(Case::ScreamingSnake)
+        );
+        let discrim_len = acc.discriminator.len();
+        let byte_literals = acc.discriminator.iter().map(|b| quote! { #b });
+
+        let mut tokens = quote! {
+            pub const #discrim_const: [u8; #discrim_len] = [#(#byte_literals),*];
+        };
+
+        let has_layout = match &acc.ty {
+            Some(ty) => {
+                tokens.extend(generate_type_def(
+                    &acc.name,
+                    ty,
+                    &[],
+                    types,
+                    derives,
+                    options,
+                ));
+                true
+            }
+            None => types.iter().any(|typedef| typedef.name == acc.name),
+        };
+
+        if has_layout {
+            tokens.extend(quote! {
+                impl #name {
+                    /// Program owning accounts of this type
+                    pub const OWNER: #pubkey = PROGRAM_ID;
+
+                    /// Decode account data, ignoring bytes after the struct
+                    ///
+                    /// Accounts are often allocated larger than their data, so trailing
+                    /// padding is accepted even with `deny_trailing_bytes`, which only
+                    /// applies to instructions.
+                    pub fn from_account_data(data: impl AsRef<[u8]>) -> #result {
+                        use ::borsh::BorshDeserialize as _;
+                        let (discriminator, mut reader) = data
+                            .as_ref()
+                            .split_first_chunk::<#discrim_len>()
+                            .ok_or_else(|| #short_error)?;
+
+                        if *discriminator != #discrim_const {
+                            return Err(#mismatch_error.into());
+                        }
+                        Self::deserialize(&mut reader).map_err(|e| #decode_error::from(e).into())
+                    }
+
+                    /// Account data after the discriminator, without decoding it
+                    ///
+                    /// `None` if the data does not start with this account's
+                    /// discriminator. Useful to cast the body with e.g. `bytemuck`
+                    /// or to decode it lazily.
+                    pub fn body(data: &[u8]) -> Option<&[u8]> {
+                        data.strip_prefix(&#discrim_const[..])
+                    }
+                }
+            });
+        }
+
+        tokens
+    });
+
+    quote! { #(#account_defs)* }
+}
+
+/// Generate discriminator constants and decoders for events
+///
+/// An event's layout is the entry with the same name in `types`, where loading
+/// also puts the inline fields of legacy events. Events with a layout are
+/// gathered in a `<Program>Events` enum.
+fn generate_events(idl: &Idl, options: &GenerateOptions) -> TokenStream {
+    let types = &idl.types;
+    let result = sdk::result_type(options, quote! { Self });
+    let decode_error = sdk::decode_error_type();
+    let short_error = sdk::decode_error(
+        options,
+        "UnexpectedEof",
+        quote! { "event data is shorter than its discriminator" },
+    );
+    let mismatch_error =
+        sdk::decode_error(options, "Other", quote! { "event discriminator mismatch" });
+
+    let event_defs = idl.events.iter().map(|event| {
+        let name = format_ident!("{}", event.name);
+        let discrim_const = format_ident!(
+            "{}_EVENT_DISCRIMINATOR",
+            event.name.to_case(Case::ScreamingSnake)
+        );
+        let discrim_len = event.discriminator.len();
+        let byte_literals = event.discriminator.iter().map(|b| quote! { #b });
+
+        let mut tokens = quote! {
+            pub const #discrim_const: [u8; #discrim_len] = [#(#byte_literals),*];
+        };
+
+        if types.iter().any(|typedef| typedef.name == event.name) {
+            tokens.extend(quote! {
+                impl #name {
+                    /// Discriminator the event data starts with
+                    pub const EVENT_DISCRIMINATOR: [u8; #discrim_len] = #discrim_const;
+
+                    /// Decode event data, as logged by `emit!` after base64 decoding
+                    pub fn from_event_data(data: impl AsRef<[u8]>) -> #result {
+                        use ::borsh::BorshDeserialize as _;
+                        let (discriminator, mut reader) = data
+                            .as_ref()
+                            .split_first_chunk::<#discrim_len>()
+                            .ok_or_else(|| #short_error)?;
+
+                        if *discriminator != #discrim_const {
+                            return Err(#mismatch_error.into());
+                        }
+                        Self::deserialize(&mut reader).map_err(|e| #decode_error::from(e).into())
+                    }
+                }
+            });
+        }
+
+        tokens
+    });
+
+    let decodable: Vec<&IdlEvent> = idl
+        .events
+        .iter()
+        .filter(|event| types.iter().any(|typedef| typedef.name == event.name))
+        .collect();
+    let events_enum = if decodable.is_empty() {
+        quote! {}
+    } else {
+        generate_events_enum(idl, &decodable, options)
+    };
+
+    quote! {
+        #(#event_defs)*
+        #events_enum
+    }
+}
+
+/// Generate the `<Program>Events` enum and `decode_event_from_inner_instruction`
+fn generate_events_enum(idl: &Idl, events: &[&IdlEvent], options: &GenerateOptions) -> TokenStream {
+    let enum_name = format_ident!("{}Events", program_type_name(idl, options));
+    let pubkey = sdk::pubkey_type();
+    let result = sdk::result_type(options, quote! { Self });
+    let unknown_error =
+        sdk::decode_error(options, "Other", quote! { "unknown event discriminator" });
+    let option_result = sdk::result_type(options, quote! { Option<#enum_name> });
+    // The runtime trait relies on `anyhow`
+    let trait_impl = if options.no_std {
+        quote! {}
+    } else {
+        let to_anyhow = if options.error.is_some() {
+            quote! { .map_err(::anyhow::Error::from) }
+        } else {
+            quote! {}
+        };
+        quote! {
+            impl ::solana_idl_parser::IdlEvents for #enum_name {
+                const PROGRAM_ID: #pubkey = PROGRAM_ID;
+
+                fn from_event_data(data: &[u8]) -> ::anyhow::Result<Self> {
+                    Self::from_event_data(data)#to_anyhow
+                }
+            }
+        }
+    };
+
+    let variants = events.iter().map(|event| {
+        let name = format_ident!("{}", event.name);
+        quote! { #name(#name) }
+    });
+
+    // Longest first, so a short discriminator never shadows a longer one it prefixes
+    let mut by_len = events.to_vec();
+    by_len.sort_by_key(|event| std::cmp::Reverse(event.discriminator.len()));
+    let branches = by_len.iter().map(|event| {
+        let name = format_ident!("{}", event.name);
+        quote! {
+            if data.starts_with(&#name::EVENT_DISCRIMINATOR) {
+                #name::from_event_data(data).map(Self::#name)
+            }
+        }
+    });
+
+    quote! {
+        /// Events of the program; decode them with `from_event_data`
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            /// Decode event data starting with the discriminator of any of the program's events
+            pub fn from_event_data(data: impl AsRef<[u8]>) -> #result {
+                let data = data.as_ref();
+                #(#branches else)* {
+                    Err(#unknown_error.into())
+                }
+            }
+        }
+
+        #trait_impl
+
+        /// Decode an event emitted with `emit_cpi!`, given an inner instruction's program id and data
+        ///
+        /// Such events are self-invocations of the program whose data is
+        /// `solana_idl_parser::EVENT_CPI_TAG`, then the event discriminator and
+        /// fields. Returns `None` for inner instructions of other programs and
+        /// ones that are not events.
+        pub fn decode_event_from_inner_instruction(
+            program_id: &#pubkey,
+            data: impl AsRef<[u8]>,
+        ) -> #option_result {
+            if *program_id != PROGRAM_ID {
+                return Ok(None);
+            }
+            match data.as_ref().strip_prefix(&::solana_idl_parser::EVENT_CPI_TAG) {
+                Some(event) => #enum_name::from_event_data(event).map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// Generate an enum variant
+fn generate_enum_variant(
+    variant: &IdlEnumVariant,
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let name = format_ident!("{}", variant.name);
+
+    match &variant.fields {
+        Some(IdlEnumVariantFields::Named(fields)) => {
+            let field_defs = fields.iter().map(|f| {
+                let field_name = format_ident!("{}", f.name.to_case(Case::Snake));
+                let field_type = idl_type_to_rust(&f.ty, options);
+                let rename = serde_rename(options, &f.name, &field_name);
+                let docs = doc_attrs(&f.docs);
+                let arbitrary = fuzz::named_field_attr(f, types, options);
+                let place = format!("field `{}` of variant {}", f.name, name);
+                let borsh = field_borsh_attrs(f, &place);
+                quote! { #docs #rename #arbitrary #borsh #field_name: #field_type }
+            });
+            quote! { #name { #(#field_defs,)* } }
+        }
+        Some(IdlEnumVariantFields::Tuple(field_types)) => {
+            let fields = field_types.iter().map(|ty| {
+                let field_type = idl_type_to_rust(ty, options);
+                let arbitrary = fuzz::field_attr(ty, types, options);
+                quote! { #arbitrary #field_type }
+            });
+            quote! { #name(#(#fields,)*) }
+        }
+        None => quote! { #name },
+    }
+}
+
+/// Generate the main instructions enum
+fn generate_instructions_enum(
+    enum_name: &syn::Ident,
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let serde_derive = sdk::serde_derive(options);
+    let arbitrary_derive = fuzz::arbitrary_derive(options);
+    let variants = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let rename = serde_rename(options, &ix.name, &variant_name);
+        let items = InstructionItems::new(ix, types, options);
+        let accounts_type = items.path(&items.accounts);
+        let args_type = items.path(&items.args);
+
+        let docs = doc_attrs(&ix.docs);
+        let variant = instruction_variant(ix, quote! { #variant_name }, accounts_type, args_type);
+        quote! { #docs #rename #variant }
+    });
+
+    // No borsh derives: those would tag variants with their index rather than
+    // the discriminator, and decoding needs the account metas
+    quote! {
+        /// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+        #[derive(Debug, Clone, PartialEq)]
+        #serde_derive
+        #arbitrary_derive
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+    }
+}
+
+/// `deserialize_with_overrides`, for programs deployed with other discriminators than their IDL
+///
+/// Overrides are keyed by a discriminator array, or a byte vector matched as
+/// a prefix (longest first) when the IDL mixes discriminator lengths.
+fn generate_override_decoder(
+    discrim_len: Option<usize>,
+    account_meta: &TokenStream,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let strip_offset = strip_discriminator_offset(options);
+    let result = sdk::result_type(options, quote! { Self });
+    let unknown_name_error = sdk::decode_error(
+        options,
+        "Other",
+        quote! { format!("discriminator override names unknown instruction `{}`", name) },
+    );
+    let (key, lookup) = match discrim_len {
+        Some(len) => (
+            quote! { [u8; #len] },
+            quote! {
+                buf.split_first_chunk::<#len>().and_then(|(discriminator, args)| {
+                    Some((*overrides.get(discriminator)?, args))
+                })
+            },
+        ),
+        None => (
+            quote! { ::std::vec::Vec<u8> },
+            quote! {
+                overrides
+                    .iter()
+                    .filter(|(discriminator, _)| buf.starts_with(discriminator))
+                    .max_by_key(|(discriminator, _)| discriminator.len())
+                    .map(|(discriminator, name)| (*name, &buf[discriminator.len()..]))
+            },
+        ),
+    };
+
+    quote! {
+        /// Decode an instruction, first mapping deployed discriminators to instruction names
+        ///
+        /// Patches around programs whose binary uses other discriminators than
+        /// the IDL, e.g. after an upgrade. Names are matched like in
+        /// `discriminator_of`; data without an overridden discriminator is
+        /// decoded as by `deserialize`.
+        pub fn deserialize_with_overrides(
+            overrides: &::std::collections::HashMap<#key, &str>,
+            accounts: &[#account_meta],
+            buf: impl AsRef<[u8]>,
+        ) -> #result {
+            let data = buf.as_ref();
+            let buf = data;
+            #strip_offset
+            let Some((name, args)) = #lookup else {
+                return Self::deserialize(accounts, data);
+            };
+            let discriminator = discriminator_of(name).ok_or_else(|| #unknown_name_error)?;
+            Self::deserialize_parts(&discriminator, args, accounts)
+        }
+    }
+}
+
+/// `decode_to_json`, empty unless the `serde` option is set
+///
+/// Only the data is decoded, so no account metas are needed. Discriminators
+/// are tried longest first, like `deserialize` does when their lengths differ.
+fn generate_json_decoder(
+    instructions: &[IdlInstruction],
+    skipped_instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+    trailing_bytes_check: &TokenStream,
+) -> TokenStream {
+    if !options.serde {
+        return quote! {};
+    }
+    let json = quote! { ::solana_idl_parser::serde_json };
+    let unknown_error = unknown_discriminator_error(
+        options,
+        leading_bytes(instructions.iter().chain(skipped_instructions)),
+    );
+    let strip_offset = strip_discriminator_offset(options);
+
+    let decoders = instructions.iter().map(|ix| {
+        let name = &ix.name;
+        let args = if ix.args.is_empty() {
+            quote! { #json::Value::Object(#json::Map::new()) }
+        } else {
+            let items = InstructionItems::new(ix, types, options);
+            let args_type = items.path(&items.args);
+            quote! {{
+                let args: #args_type = ::borsh::BorshDeserialize::deserialize(&mut reader)?;
+                ::solana_idl_parser::json::ToJson::to_json(&args)
+            }}
+        };
+        let body = quote! {{
+            let args = #args;
+            #trailing_bytes_check
+            (#name, args)
+        }};
+        (ix, body)
+    });
+    let skipped = skipped_instructions.iter().map(|ix| {
+        let message = format!(
+            "instruction `{}` is not supported by the generated code",
+            ix.name
+        );
+        let error = sdk::decode_error(options, "Other", quote! { #message });
+        let body = quote! {
+            { return Err(#error.into()) }
+        };
+        (ix, body)
+    });
+    let mut branches: Vec<(&IdlInstruction, TokenStream)> = decoders.chain(skipped).collect();
+    branches.sort_by_key(|(ix, _)| std::cmp::Reverse(ix.discriminator.len()));
+    let branches = branches.into_iter().map(|(ix, body)| {
+        let items = InstructionItems::new(ix, types, options);
+        let discrim_const = items.path(&items.discriminator);
+        let legacy = legacy_discriminators(ix, options);
+        // Args that begin with the discriminator are read from the start of the data
+        let start = if options.discriminator_in_args && !ix.args.is_empty() {
+            quote! { 0 }
+        } else {
+            quote! { #discrim_const.len() }
+        };
+        quote! {
+            if buf.starts_with(&#discrim_const) #(|| buf.starts_with(&#legacy))* {
+                let mut reader = &buf[#start..];
+                #body
+            }
+        }
+    });
+
+    quote! {
+        /// Decode instruction data to a JSON object keyed by the instruction's IDL name
+        ///
+        /// Only the args are decoded, so no account metas are needed. Keys are
+        /// written as base58 strings.
+        pub fn decode_to_json(data: impl AsRef<[u8]>) -> ::anyhow::Result<#json::Value> {
+            let buf = data.as_ref();
+            #strip_offset
+            let (name, args) = #(#branches else)* {
+                return Err(#unknown_error.into());
+            };
+            let mut object = #json::Map::new();
+            object.insert(name.into(), args);
+            Ok(#json::Value::Object(object))
+        }
+    }
+}
+
+/// `to_anchor_json`, empty unless the `serde` option is set
+///
+/// Args and accounts are keyed by their IDL names whatever `serde_idl_names`
+/// says, matching what Anchor's TypeScript coder produces.
+fn generate_anchor_json(instructions: &[IdlInstruction], options: &GenerateOptions) -> TokenStream {
+    if !options.serde {
+        return quote! {};
+    }
+    let json = quote! { ::solana_idl_parser::serde_json };
+
+    let arms = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let name = &ix.name;
+
+        let arg_names = unique_field_names(ix.args.iter().map(|arg| arg.name.as_str()));
+        let data_entries = ix.args.iter().zip(&arg_names).map(|(arg, field_name)| {
+            let idl_name = &arg.name;
+            quote! {
+                data.insert(#idl_name.into(), ::solana_idl_parser::json::ToJson::to_json(&args.#field_name));
+            }
+        });
+
+        let account_names =
+            unique_field_names(ix.accounts.iter().map(|acc| acc.name.as_str()));
+        let account_entries = ix.accounts.iter().zip(&account_names).map(|(acc, field_name)| {
+            let idl_name = &acc.name;
+            let value = if acc.optional {
+                quote! {
+                    match accounts.#field_name {
+                        Some(pubkey) => #json::Value::String(pubkey.to_string()),
+                        None => #json::Value::Null,
+                    }
+                }
+            } else {
+                quote! { #json::Value::String(accounts.#field_name.to_string()) }
+            };
+            quote! { account_keys.insert(#idl_name.into(), #value); }
+        });
+        // Only maps something is inserted into need to be mutable
+        let data_decl = if ix.args.is_empty() {
+            quote! { let data = #json::Map::new(); }
+        } else {
+            quote! {
+                let mut data = #json::Map::new();
+                #(#data_entries)*
+            }
+        };
+        let accounts_decl = if ix.accounts.is_empty() {
+            quote! { let account_keys = #json::Map::new(); }
+        } else {
+            quote! {
+                let mut account_keys = #json::Map::new();
+                #(#account_entries)*
+            }
+        };
+
+        let pattern = instruction_variant(
+            ix,
+            quote! { Self::#variant_name },
+            quote! { accounts },
+            quote! { args },
+        );
+        quote! {
+            #pattern => {
+                #data_decl
+                #accounts_decl
+                (#name, data, account_keys)
+            }
+        }
+    });
+
+    quote! {
+        /// JSON in the shape Anchor's TypeScript coder gives decoded instructions
+        ///
+        /// `{ "name": <IDL name>, "data": { <arg>: <value>, ... }, "accounts":
+        /// { <account>: <base58 key>, ... } }`, with args and accounts under
+        /// their IDL names and omitted optional accounts as `null`.
+        pub fn to_anchor_json(&self) -> ::anyhow::Result<#json::Value> {
+            let (name, data, account_keys) = match self {
+                #(#arms)*
+            };
+            let mut object = #json::Map::new();
+            object.insert("name".into(), #json::Value::String(name.into()));
+            object.insert("data".into(), #json::Value::Object(data));
+            object.insert("accounts".into(), #json::Value::Object(account_keys));
+            Ok(#json::Value::Object(object))
+        }
+    }
+}
+
+/// Generate the deserialize implementation
+fn generate_deserialize_impl(
+    enum_name: &syn::Ident,
+    instructions: &[IdlInstruction],
+    skipped_instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let pubkey = sdk::pubkey_type();
+    let account_meta = sdk::account_meta_type();
+    let alloc = sdk::alloc_crate(options.no_std);
+    let result = sdk::result_type(options, quote! { Self });
+    let decode_error = sdk::decode_error_type();
+    let all_instructions = instructions.iter().chain(skipped_instructions);
+    let discrim_len = discriminator_len(all_instructions.clone());
+    // Dispatch table entries are functions outside the impl, reading through `reader: &mut &[u8]`
+    let (this, reader) = if options.dispatch_table {
+        (quote! { #enum_name }, quote! { reader })
+    } else {
+        (quote! { Self }, quote! { &mut reader })
+    };
+    let decoders = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let items = InstructionItems::new(ix, types, options);
+        let accounts_type = items.path(&items.accounts);
+
+        let read_args = if options.discriminator_in_args {
+            quote! { #this::read_args_with_discriminator(discriminator, #reader)? }
+        } else if options.dispatch_table {
+            quote! { ::borsh::BorshDeserialize::deserialize(#reader).map_err(#decode_error::from)? }
+        } else {
+            let args_type = items.path(&items.args);
+            quote! { #args_type::deserialize(#reader).map_err(#decode_error::from)? }
+        };
+
+        let decode = instruction_variant(
+            ix,
+            quote! { #this::#variant_name },
+            quote! { #accounts_type::from_account_metas(accounts)? },
+            read_args,
+        );
+        (ix, decode)
+    });
+
+    let skipped_errors = skipped_instructions.iter().map(|ix| {
+        let message = format!(
+            "instruction `{}` is not supported by the generated code",
+            ix.name
+        );
+        let error = sdk::decode_error(options, "Other", quote! { #message });
+        (ix, quote! { Err(#error.into()) })
+    });
+
+    let unknown_discriminator = unknown_discriminator_error(options, quote! { &discriminator[..] });
+    let unknown_discriminator = quote! { Err(#unknown_discriminator.into()) };
+    let (dispatch_table, dispatch) = if options.dispatch_table {
+        let discrim_len = discrim_len.unwrap_or_else(|| {
+            panic!("`dispatch_table = true` needs every instruction discriminator to have the same length")
+        });
+        // Legacy discriminators get entries of their own, sharing the instruction's decoder
+        let mut entries: Vec<(Vec<u8>, TokenStream, TokenStream)> = decoders
+            .map(|(ix, decode)| (ix, quote! { |discriminator, accounts, reader| Ok(#decode) }))
+            .chain(skipped_errors.map(|(ix, error)| (ix, quote! { |_, _, _| #error })))
+            .flat_map(|(ix, decoder)| {
+                let current = (
+                    ix.discriminator.clone(),
+                    discriminator_path(ix, types, options),
+                    decoder.clone(),
+                );
+                let legacy = legacy_discriminators(ix, options)
+                    .into_iter()
+                    .zip(legacy_discriminator_bytes(ix, options))
+                    .map(move |(tokens, bytes)| (bytes, tokens, decoder.clone()));
+                std::iter::once(current).chain(legacy)
+            })
+            .collect();
+        entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        let entries_len = entries.len();
+        let entries = entries
+            .into_iter()
+            .map(|(_, discriminator, decoder)| quote! { (#discriminator, #decoder) });
+        let decoder_result = sdk::result_type(options, quote! { #enum_name });
+
+        let table = quote! {
+            /// Decoders of every instruction, sorted by discriminator for `deserialize_parts`
+            #[allow(unused_variables)]
+            static INSTRUCTION_DISPATCH_TABLE: [(
+                [u8; #discrim_len],
+                fn(&[u8; #discrim_len], &[#account_meta], &mut &[u8]) -> #decoder_result,
+            ); #entries_len] = [
+                #(#entries,)*
+            ];
+        };
+        let dispatch = quote! {
+            match INSTRUCTION_DISPATCH_TABLE.binary_search_by(|(known, _)| known.cmp(discriminator)) {
+                Ok(i) => (INSTRUCTION_DISPATCH_TABLE[i].1)(discriminator, accounts, &mut reader)?,
+                Err(_) => return #unknown_discriminator,
+            }
+        };
+        (table, dispatch)
+    } else if discrim_len.is_none() {
+        // Discriminators of different lengths cannot share a match on a fixed-size array
+        let branches = decoders.map(|(ix, decode)| {
+            let discrim_const = discriminator_path(ix, types, options);
+            let legacy = legacy_discriminators(ix, options);
+            quote! { if *discriminator == #discrim_const #(|| *discriminator == #legacy)* { #decode } }
+        });
+        let skipped_branches = skipped_errors.map(|(ix, error)| {
+            let discrim_const = discriminator_path(ix, types, options);
+            let legacy = legacy_discriminators(ix, options);
+            quote! { if *discriminator == #discrim_const #(|| *discriminator == #legacy)* { return #error } }
+        });
+        let dispatch = quote! {
+            #(#branches else)*
+            #(#skipped_branches else)*
+            { return #unknown_discriminator }
+        };
+        (quote! {}, dispatch)
+    } else {
+        let match_arms = decoders.map(|(ix, decode)| {
+            let discrim_const = discriminator_path(ix, types, options);
+            let legacy = legacy_discriminators(ix, options);
+            quote! { #discrim_const #(| #legacy)* => #decode }
+        });
+        let skipped_arms = skipped_errors.map(|(ix, error)| {
+            let discrim_const = discriminator_path(ix, types, options);
+            let legacy = legacy_discriminators(ix, options);
+            quote! { #discrim_const #(| #legacy)* => return #error }
+        });
+        let dispatch = quote! {
+            match *discriminator {
+                #(#match_arms,)*
+                #(#skipped_arms,)*
+                _ => return #unknown_discriminator
+            }
+        };
+        (quote! {}, dispatch)
+    };
+
+    let discriminator_arms = instructions.iter().map(|ix| {
+        let pattern = variant_pattern(ix);
+        let discrim_const = discriminator_path(ix, types, options);
+        quote! { #pattern => &#discrim_const }
+    });
+
+    let name_arms = instructions.iter().map(|ix| {
+        let pattern = variant_pattern(ix);
+        let name = &ix.name;
+        quote! { #pattern => #name }
+    });
+
+    // For instructions without args this requires the data to be exactly the discriminator
+    let trailing_bytes_check = if options.deny_trailing_bytes {
+        let error = sdk::decode_error(
+            options,
+            "InvalidData",
+            quote! { "unexpected trailing bytes after instruction data" },
+        );
+        quote! {
+            if !reader.is_empty() {
+                return Err(#error.into());
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let read_args_with_discriminator = if options.discriminator_in_args {
+        let uncovered_error = sdk::decode_error(
+            options,
+            "InvalidData",
+            quote! { "instruction args do not cover the discriminator" },
+        );
+        quote! {
+            /// Decode args that begin with the discriminator, advancing `reader` past them
+            fn read_args_with_discriminator<T: ::borsh::BorshDeserialize>(
+                discriminator: &[u8],
+                reader: &mut &[u8],
+            ) -> ::core::result::Result<T, #decode_error> {
+                let data = #alloc::vec![discriminator, *reader].concat();
+                let mut prefixed = data.as_slice();
+                let args = T::deserialize(&mut prefixed)?;
+                let consumed = data.len() - prefixed.len();
+                if consumed < discriminator.len() {
+                    return Err(#uncovered_error);
+                }
+                *reader = &reader[consumed - discriminator.len()..];
+                Ok(args)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Text decoding and the runtime trait rely on `anyhow`
+    let std_impls = if options.no_std {
+        quote! {}
+    } else {
+        let json_decoder = generate_json_decoder(
+            instructions,
+            skipped_instructions,
+            types,
+            options,
+            &trailing_bytes_check,
+        );
+        let override_decoder = generate_override_decoder(discrim_len, &account_meta, options);
+        let anchor_json = generate_anchor_json(instructions, options);
+        // A custom error type converts into `anyhow::Error` like any `std` error
+        let to_anyhow = if options.error.is_some() {
+            quote! { .map_err(::anyhow::Error::from) }
+        } else {
+            quote! {}
+        };
+        quote! {
+            impl #enum_name {
+                #json_decoder
+                #anchor_json
+                #override_decoder
+
+                /// Decode an instruction whose data is base64 encoded
+                pub fn from_base64(accounts: &[#account_meta], data: &str) -> ::anyhow::Result<Self> {
+                    Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)#to_anyhow
+                }
+
+                /// Decode an instruction whose data is base58 encoded
+                pub fn from_base58(accounts: &[#account_meta], data: &str) -> ::anyhow::Result<Self> {
+                    Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)#to_anyhow
+                }
+            }
+
+            impl ::solana_idl_parser::IdlInstructions for #enum_name {
+                const PROGRAM_ID: #pubkey = PROGRAM_ID;
+
+                fn deserialize(accounts: &[#account_meta], buf: &[u8]) -> ::anyhow::Result<Self> {
+                    Self::deserialize(accounts, buf)#to_anyhow
+                }
+
+                fn discriminator(&self) -> &[u8] {
+                    match self {
+                        #(#discriminator_arms,)*
+                    }
+                }
+
+                fn name(&self) -> &str {
+                    match self {
+                        #(#name_arms,)*
+                    }
+                }
+            }
+        }
+    };
+
+    let strip_offset = strip_discriminator_offset(options);
+    let short_error = sdk::decode_error(
+        options,
+        "UnexpectedEof",
+        quote! { "instruction data is shorter than its discriminator" },
+    );
+    let unknown_error =
+        unknown_discriminator_error(options, leading_bytes(all_instructions.clone()));
+    let (split, discriminator_type) = match discrim_len {
+        Some(discrim_len) => (
+            quote! {
+                let (discriminator, args) = buf
+                    .split_first_chunk::<#discrim_len>()
+                    .ok_or_else(|| #short_error)?;
+            },
+            quote! { [u8; #discrim_len] },
+        ),
+        None => {
+            // Longest first, so a short discriminator never shadows a longer one it prefixes
+            let mut by_len: Vec<&IdlInstruction> = all_instructions.collect();
+            by_len.sort_by_key(|ix| std::cmp::Reverse(ix.discriminator.len()));
+            let discrim_consts = by_len.iter().flat_map(|ix| {
+                std::iter::once(discriminator_path(ix, types, options))
+                    .chain(legacy_discriminators(ix, options))
+            });
+            (
+                quote! {
+                    let discriminator: &[u8] = [#(&#discrim_consts[..]),*]
+                        .into_iter()
+                        .find(|discriminator| buf.starts_with(discriminator))
+                        .ok_or_else(|| #unknown_error)?;
+                    let args = &buf[discriminator.len()..];
+                },
+                quote! { [u8] },
+            )
+        }
+    };
+
+    quote! {
+        impl #enum_name {
+            /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+            pub fn deserialize(accounts: &[#account_meta], buf: impl AsRef<[u8]>) -> #result {
+                let buf = buf.as_ref();
+                #strip_offset
+                #split
+                Self::deserialize_parts(discriminator, args, accounts)
+            }
+
+            /// Decode instruction data with borrowed account metas, like `deserialize`
+            pub fn from_slice(accounts: &[#account_meta], buf: &[u8]) -> #result {
+                Self::deserialize(accounts, buf)
+            }
+
+            /// Decode an instruction whose discriminator and args payload are already split
+            pub fn deserialize_parts(
+                discriminator: &#discriminator_type,
+                args: &[u8],
+                accounts: &[#account_meta],
+            ) -> #result {
+                use ::borsh::BorshDeserialize as _;
+                let mut reader = args;
+
+                let ix = #dispatch;
+                #trailing_bytes_check
+                Ok(ix)
+            }
+
+            #read_args_with_discriminator
+        }
+
+        #dispatch_table
+
+        #std_impls
+    }
+}
+
+/// Pattern matching an instruction's variant regardless of its payload
+fn variant_pattern(ix: &IdlInstruction) -> TokenStream {
+    let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+    if ix.accounts.is_empty() && ix.args.is_empty() {
+        quote! { Self::#variant_name }
+    } else {
+        quote! { Self::#variant_name(..) }
+    }
+}
+
+/// The fields of an instruction's variant: `accounts` if it has accounts, then `args` if it has args
+fn variant_fields<T>(ix: &IdlInstruction, accounts: T, args: T) -> Vec<T> {
+    let mut fields = Vec::with_capacity(2);
+    if !ix.accounts.is_empty() {
+        fields.push(accounts);
+    }
+    if !ix.args.is_empty() {
+        fields.push(args);
+    }
+    fields
+}
+
+/// `variant(accounts, args)` with the fields the instruction has, as a pattern, constructor or declaration
+fn instruction_variant(
+    ix: &IdlInstruction,
+    variant: TokenStream,
+    accounts: TokenStream,
+    args: TokenStream,
+) -> TokenStream {
+    let fields = variant_fields(ix, accounts, args);
+    if fields.is_empty() {
+        variant
+    } else {
+        quote! { #variant(#(#fields),*) }
+    }
+}
+
+/// Generate the `<Program>Visitor` trait and `accept` on the instructions enum
+///
+/// The trait has one empty default method per instruction, so consumers only
+/// implement the instructions they handle instead of matching every variant.
+fn generate_visitor(
+    idl: &Idl,
+    enum_name: &syn::Ident,
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let visitor_name = format_ident!("{}Visitor", program_type_name(idl, options));
+
+    let (methods, arms): (Vec<TokenStream>, Vec<TokenStream>) = idl
+        .instructions
+        .iter()
+        .map(|ix| {
+            let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+            let method = format_ident!("visit_{}", ix.name.to_case(Case::Snake));
+            let items = InstructionItems::new(ix, types, options);
+            let accounts_type = items.path(&items.accounts);
+            let args_type = items.path(&items.args);
+            let doc = format!("Called by `accept` for `{}` instructions", ix.name);
+
+            let params = variant_fields(
+                ix,
+                quote! { _accounts: &#accounts_type },
+                quote! { _args: &#args_type },
+            );
+            let bindings = variant_fields(ix, quote! { accounts }, quote! { args });
+            let pattern = instruction_variant(
+                ix,
+                quote! { Self::#variant_name },
+                quote! { accounts },
+                quote! { args },
+            );
+            let call = quote! { visitor.#method(#(#bindings),*) };
+            let method = quote! {
+                #[doc = #doc]
+                fn #method(&mut self, #(#params),*) {}
+            };
+            (method, quote! { #pattern => #call })
+        })
+        .unzip();
+
+    quote! {
+        /// Callbacks for `accept`, one per instruction, all doing nothing by default
+        pub trait #visitor_name {
+            #(#methods)*
+        }
+
+        impl #enum_name {
+            /// Call the visitor method of this instruction with its accounts and args
+            pub fn accept<V: #visitor_name + ?Sized>(&self, visitor: &mut V) {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate a fieldless `<Program>InstructionKind` enum, classified from the discriminator alone
+///
+/// Like `deserialize`, longer discriminators are tried first so mixed lengths
+/// resolve to the most specific instruction.
+fn generate_instruction_kind(
+    idl: &Idl,
+    enum_name: &syn::Ident,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let kind_name = format_ident!("{}InstructionKind", program_type_name(idl, options));
+    let serde_derive = sdk::serde_derive(options);
+
+    let variants = idl.instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let doc = format!("`{}`", ix.name);
+        let rename = serde_rename(options, &ix.name, &variant_name);
+        quote! {
+            #[doc = #doc]
+            #rename
+            #variant_name
+        }
+    });
+
+    let mut by_len: Vec<&IdlInstruction> = idl.instructions.iter().collect();
+    by_len.sort_by_key(|ix| std::cmp::Reverse(ix.discriminator.len()));
+    let checks = by_len.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let items = InstructionItems::new(ix, &idl.types, options);
+        let matches_fn = items.path(&items.matches_discriminator);
+        quote! {
+            if #matches_fn(data) {
+                return Some(Self::#variant_name);
+            }
+        }
+    });
+
+    let name_arms = idl.instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let name = &ix.name;
+        quote! { Self::#variant_name => #name }
+    });
+
+    let kind_arms = idl.instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let pattern = variant_pattern(ix);
+        quote! { #pattern => #kind_name::#variant_name }
+    });
+
+    quote! {
+        /// Which instruction some data holds, without its accounts or args
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #serde_derive
+        pub enum #kind_name {
+            #(#variants,)*
+        }
+
+        impl #kind_name {
+            /// The instruction whose discriminator starts `data`, without decoding the rest
+            pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+                #(#checks)*
+                None
+            }
+
+            /// Instruction name as written in the IDL
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(#name_arms,)*
+                }
+            }
+        }
+
+        impl #enum_name {
+            /// Which instruction this is, without its accounts or args
+            pub fn kind(&self) -> #kind_name {
+                match self {
+                    #(#kind_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate the serialize and `into_instruction` implementation
+fn generate_instruction_builder_impl(
+    enum_name: &syn::Ident,
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let pubkey = sdk::pubkey_type();
+    let instruction = sdk::instruction_type();
+    let alloc = sdk::alloc_crate(options.no_std);
+    let io = sdk::io_module(options.no_std);
+    let bytes_result = sdk::result_type(options, quote! { #alloc::vec::Vec<u8> });
+    let decode_error = sdk::decode_error_type();
+    let instruction_result = sdk::result_type(options, instruction.clone());
+    // Args that carry the discriminator write it themselves
+    let args_prefix = |discrim_const: &TokenStream| {
+        if options.discriminator_in_args {
+            quote! {}
+        } else {
+            quote! { writer.write_all(&#discrim_const)?; }
+        }
+    };
+    // The header before the discriminator is not decoded, so zeros stand in for it
+    let write_offset = if options.discriminator_offset == 0 {
+        quote! {}
+    } else {
+        quote! { writer.write_all(&[0; DISCRIMINATOR_OFFSET])?; }
+    };
+    let serialize_arms = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let discrim_const = discriminator_path(ix, types, options);
+
+        let prefix = args_prefix(&discrim_const);
+        let pattern = instruction_variant(
+            ix,
+            quote! { Self::#variant_name },
+            quote! { _ },
+            quote! { args },
+        );
+
+        if ix.args.is_empty() {
+            quote! { #pattern => writer.write_all(&#discrim_const)? }
+        } else {
+            quote! {
+                #pattern => {
+                    #prefix
+                    ::borsh::BorshSerialize::serialize(args, writer)?;
+                }
+            }
+        }
+    });
+
+    let named_accounts_arms = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+
+        let pattern = instruction_variant(
+            ix,
+            quote! { Self::#variant_name },
+            quote! { accounts },
+            quote! { _ },
+        );
+
+        if ix.accounts.is_empty() {
+            quote! { #pattern => #alloc::vec::Vec::new() }
+        } else {
+            quote! { #pattern => accounts.named_accounts().to_vec() }
+        }
+    });
+
+    let account_metas_arms = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+
+        let pattern = instruction_variant(
+            ix,
+            quote! { Self::#variant_name },
+            quote! { accounts },
+            quote! { _ },
+        );
+
+        if ix.accounts.is_empty() {
+            quote! { #pattern => #alloc::vec::Vec::new() }
+        } else {
+            quote! { #pattern => accounts.to_account_metas() }
+        }
+    });
+
+    // `TryFrom<(Accounts, Args)> for Instruction` would break the orphan rule,
+    // since tuples are foreign types, so payloads convert through the enum
+    let from_payload_impls = instructions.iter().map(|ix| {
+        let variant_name = format_ident!("{}", ix.name.to_case(Case::Pascal));
+        let items = InstructionItems::new(ix, types, options);
+        let accounts_type = items.path(&items.accounts);
+        let args_type = items.path(&items.args);
+
+        let (payload, accounts, args) = match variant_fields(ix, accounts_type, args_type)[..] {
+            [] => return quote! {},
+            [ref ty] => (quote! { #ty }, quote! { payload }, quote! { payload }),
+            [ref accounts, ref args] => (
+                quote! { (#accounts, #args) },
+                quote! { payload.0 },
+                quote! { payload.1 },
+            ),
+            _ => unreachable!("variants have at most an accounts and an args field"),
+        };
+        let variant = instruction_variant(ix, quote! { Self::#variant_name }, accounts, args);
+        quote! {
+            impl ::core::convert::From<#payload> for #enum_name {
+                fn from(payload: #payload) -> Self {
+                    #variant
+                }
+            }
+        }
+    });
+    let error = sdk::error_type(options);
+    let try_from_doc = format!(
+        " Build the instruction like `into_instruction`, e.g. `{}::from((accounts, args)).try_into()`",
+        enum_name
+    );
+
+    quote! {
+        #(#from_payload_impls)*
+
+        #[doc = #try_from_doc]
+        impl ::core::convert::TryFrom<#enum_name> for #instruction {
+            type Error = #error;
+
+            fn try_from(ix: #enum_name) -> #instruction_result {
+                ix.into_instruction()
+            }
+        }
+
+        /// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+        ///
+        /// There is no `BorshDeserialize` counterpart since decoding needs the
+        /// account metas; use `deserialize` instead.
+        impl ::borsh::BorshSerialize for #enum_name {
+            fn serialize<W: #io::Write>(&self, writer: &mut W) -> #io::Result<()> {
+                #write_offset
+                match self {
+                    #(#serialize_arms,)*
+                }
+                Ok(())
+            }
+        }
+
+        impl #enum_name {
+            /// Serialize the instruction data as `discriminator ++ borsh(args)`
+            pub fn to_bytes(&self) -> #bytes_result {
+                let mut data = #alloc::vec::Vec::new();
+                ::borsh::BorshSerialize::serialize(self, &mut data).map_err(#decode_error::from)?;
+                Ok(data)
+            }
+
+            /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+            pub fn into_instruction(self) -> #instruction_result {
+                let data = self.to_bytes()?;
+                let accounts = match &self {
+                    #(#account_metas_arms,)*
+                };
+                Ok(#instruction {
+                    program_id: PROGRAM_ID,
+                    accounts,
+                    data,
+                })
+            }
+
+            /// The instruction's named accounts paired with their IDL names
+            pub fn named_accounts(&self) -> #alloc::vec::Vec<(&'static str, #pubkey)> {
+                match self {
+                    #(#named_accounts_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Convert IDL type to Rust type tokens
+pub fn idl_type_to_rust(ty: &IdlType, options: &GenerateOptions) -> TokenStream {
+    let alloc = sdk::alloc_crate(options.no_std);
+    // Prelude names keep the default output short; `no_std` has no `alloc` prelude
+    let (string_type, vec_type) = if options.no_std {
+        (
+            quote! { #alloc::string::String },
+            quote! { #alloc::vec::Vec },
+        )
+    } else {
+        (quote! { String }, quote! { Vec })
+    };
+    let no_std_hash = |kind: &str| -> ! {
+        panic!(
+            "{} types need std and are not supported with `no_std = true`",
+            kind
+        )
+    };
+
+    match ty {
+        IdlType::Primitive(s) => match s.as_str() {
+            "bool" => quote! { bool },
+            "u8" => quote! { u8 },
+            "u16" => quote! { u16 },
+            "u32" => quote! { u32 },
+            "u64" => quote! { u64 },
+            "u128" => quote! { u128 },
+            "i8" => quote! { i8 },
+            "i16" => quote! { i16 },
+            "i32" => quote! { i32 },
+            "i64" => quote! { i64 },
+            "i128" => quote! { i128 },
+            "f32" => quote! { f32 },
+            "f64" => quote! { f64 },
+            "string" => string_type,
+            "pubkey" | "publicKey" => sdk::pubkey_type(),
+            "bytes" => quote! { #vec_type<u8> },
+            other => {
+                let ident = format_ident!("{}", other);
+                quote! { #ident }
+            }
+        },
+        IdlType::Defined { defined } => {
+            let ident = format_ident!("{}", defined.name());
+            if defined.generics().is_empty() {
+                return quote! { #ident };
+            }
+            let args = defined.generics().iter().map(|arg| match arg {
+                IdlGenericArg::Type { ty } => idl_type_to_rust(ty, options),
+                IdlGenericArg::Const { value } => panic!(
+                    "Const generic argument `{}` of type {} is not supported",
+                    value,
+                    defined.name()
+                ),
+            });
+            quote! { #ident<#(#args),*> }
+        }
+        IdlType::Generic { generic } => {
+            let ident = format_ident!("{}", generic);
+            quote! { #ident }
+        }
+        IdlType::Option { option } => {
+            let inner = idl_type_to_rust(option, options);
+            quote! { Option<#inner> }
+        }
+        IdlType::Vec { vec } => {
+            let inner = idl_type_to_rust(vec, options);
+            quote! { #vec_type<#inner> }
+        }
+        IdlType::Array { array } => {
+            let (inner, size) = array;
+            let inner_type = idl_type_to_rust(inner, options);
+            quote! { [#inner_type; #size] }
+        }
+        IdlType::Tuple { tuple } => {
+            let types = tuple.iter().map(|ty| idl_type_to_rust(ty, options));
+            quote! { (#(#types,)*) }
+        }
+        IdlType::HashMap { .. } if options.no_std => no_std_hash("hash_map"),
+        IdlType::HashMap { hash_map } => {
+            let key = idl_type_to_rust(&hash_map.0, options);
+            let value = idl_type_to_rust(&hash_map.1, options);
+            quote! { ::std::collections::HashMap<#key, #value> }
+        }
+        IdlType::BTreeMap { btree_map } => {
+            let key = idl_type_to_rust(&btree_map.0, options);
+            let value = idl_type_to_rust(&btree_map.1, options);
+            quote! { #alloc::collections::BTreeMap<#key, #value> }
+        }
+        IdlType::HashSet { .. } if options.no_std => no_std_hash("hash_set"),
+        IdlType::HashSet { hash_set } => {
+            let inner = idl_type_to_rust(hash_set, options);
+            quote! { ::std::collections::HashSet<#inner> }
+        }
+        IdlType::BTreeSet { btree_set } => {
+            let inner = idl_type_to_rust(btree_set, options);
+            quote! { #alloc::collections::BTreeSet<#inner> }
+        }
+        IdlType::Unsupported(value) => panic!("Unsupported IDL type {}", value),
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{parse_idl_str, Idl, LoadOptions};
+use anyhow::{anyhow, bail, Context};
+use flate2::read::ZlibDecoder;
+use sha2::{Digest, Sha256};
+use std::io::Read;
+
+/// Offset of the compressed IDL: discriminator, authority and `u32` length
+const IDL_DATA_OFFSET: usize = 8 + 32 + 4;
+
+/// Load the IDL stored in the data of an Anchor IDL account
+///
+/// The account holds an 8-byte discriminator, the 32-byte authority and the
+/// `u32` length of the zlib-compressed IDL JSON that follows. The JSON is
+/// loaded like [`parse_idl_str`] does.
+pub fn parse_onchain_idl_account(data: &[u8], options: &LoadOptions) -> anyhow::Result<Idl> {
+    parse_idl_str(&inflate_idl_account(data)?, options)
+}
+
+/// Inflate the IDL JSON stored in the data of an Anchor IDL account
+pub(crate) fn inflate_idl_account(data: &[u8]) -> anyhow::Result<String> {
+    let header = data
+        .get(..IDL_DATA_OFFSET)
+        .ok_or_else(|| anyhow!("IDL account data is shorter than its header"))?;
+    if header[..8] != Sha256::digest("account:IdlAccount")[..8] {
+        bail!("account data is not an Anchor IDL account");
+    }
+    let len = u32::from_le_bytes(header[40..].try_into().unwrap()) as usize;
+    let compressed = data[IDL_DATA_OFFSET..]
+        .get(..len)
+        .ok_or_else(|| anyhow!("IDL account data is shorter than its length of {}", len))?;
+
+    let mut json = String::new();
+    ZlibDecoder::new(compressed)
+        .read_to_string(&mut json)
+        .context("failed to inflate the IDL account data")?;
+    Ok(json)
+}
@@ -0,0 +1,179 @@
+use crate::generator::GenerateOptions;
+use crate::parser::{
+    IdlEnumVariant, IdlEnumVariantFields, IdlField, IdlTypeDefFields, IdlTypeDefType,
+};
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// `ToJson` impl for a struct or enum, empty unless the `serde` option is set
+///
+/// The JSON has the shape the serde derive gives the type: objects keyed
+/// like its fields, externally tagged enum variants and newtype structs
+/// written as their inner value. Type parameters must implement `ToJson` too.
+pub fn type_impl(
+    name: &syn::Ident,
+    params: &[syn::Ident],
+    ty: &IdlTypeDefType,
+    options: &GenerateOptions,
+) -> TokenStream {
+    if !options.serde {
+        return quote! {};
+    }
+    let body = match ty.kind.as_str() {
+        "struct" => match &ty.fields {
+            IdlTypeDefFields::Named(fields) => {
+                let names = snake_names(fields);
+                let bindings = bindings(fields.len());
+                let value = object(fields, &names, &bindings, options);
+                quote! {
+                    let Self { #(#names: #bindings),* } = self;
+                    #value
+                }
+            }
+            IdlTypeDefFields::Tuple(types) => {
+                let bindings = bindings(types.len());
+                let value = array(&bindings);
+                quote! {
+                    let Self(#(#bindings),*) = self;
+                    #value
+                }
+            }
+            IdlTypeDefFields::None => quote! { ::solana_idl_parser::serde_json::Value::Null },
+        },
+        // `match self {}` is rejected for a reference to an empty enum
+        _ if ty.variants.is_empty() => quote! { match *self {} },
+        _ => {
+            let arms = ty
+                .variants
+                .iter()
+                .map(|variant| variant_arm(variant, options));
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+    let (impl_generics, type_generics) = if params.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! { <#(#params: ::solana_idl_parser::json::ToJson),*> },
+            quote! { <#(#params),*> },
+        )
+    };
+
+    quote! {
+        impl #impl_generics ::solana_idl_parser::json::ToJson for #name #type_generics {
+            fn to_json(&self) -> ::solana_idl_parser::serde_json::Value {
+                #body
+            }
+        }
+    }
+}
+
+/// `ToJson` impl for an instruction's args struct, empty unless the `serde` option is set
+pub fn args_impl(
+    name: &syn::Ident,
+    args: &[IdlField],
+    field_names: &[syn::Ident],
+    options: &GenerateOptions,
+) -> TokenStream {
+    if !options.serde {
+        return quote! {};
+    }
+    let bindings = bindings(args.len());
+    let value = object(args, field_names, &bindings, options);
+    quote! {
+        impl ::solana_idl_parser::json::ToJson for #name {
+            fn to_json(&self) -> ::solana_idl_parser::serde_json::Value {
+                let Self { #(#field_names: #bindings),* } = self;
+                #value
+            }
+        }
+    }
+}
+
+/// Match arm writing a variant as its name, or an object with its name as the only key
+fn variant_arm(variant: &IdlEnumVariant, options: &GenerateOptions) -> TokenStream {
+    let json = quote! { ::solana_idl_parser::serde_json };
+    let name = format_ident!("{}", variant.name);
+    let tag = &variant.name;
+    let (pattern, value) = match &variant.fields {
+        Some(IdlEnumVariantFields::Named(fields)) => {
+            let names = snake_names(fields);
+            let bindings = bindings(fields.len());
+            let value = object(fields, &names, &bindings, options);
+            (quote! { Self::#name { #(#names: #bindings),* } }, value)
+        }
+        Some(IdlEnumVariantFields::Tuple(types)) => {
+            let bindings = bindings(types.len());
+            let value = array(&bindings);
+            (quote! { Self::#name(#(#bindings),*) }, value)
+        }
+        None => {
+            return quote! {
+                Self::#name => #json::Value::String(#tag.into()),
+            };
+        }
+    };
+    quote! {
+        #pattern => {
+            let mut object = #json::Map::new();
+            object.insert(#tag.into(), #value);
+            #json::Value::Object(object)
+        }
+    }
+}
+
+/// Object of the bound fields, keyed by their IDL names under `serde_idl_names`
+fn object(
+    fields: &[IdlField],
+    names: &[syn::Ident],
+    bindings: &[syn::Ident],
+    options: &GenerateOptions,
+) -> TokenStream {
+    let json = quote! { ::solana_idl_parser::serde_json };
+    if fields.is_empty() {
+        return quote! { #json::Value::Object(#json::Map::new()) };
+    }
+    let entries = fields.iter().zip(names).zip(bindings).map(|((field, name), binding)| {
+        let key = if options.serde_idl_names {
+            field.name.clone()
+        } else {
+            name.to_string()
+        };
+        quote! { object.insert(#key.into(), ::solana_idl_parser::json::ToJson::to_json(#binding)); }
+    });
+    quote! {{
+        let mut object = #json::Map::new();
+        #(#entries)*
+        #json::Value::Object(object)
+    }}
+}
+
+/// Array of the bound tuple fields, or the field itself for newtypes
+fn array(bindings: &[syn::Ident]) -> TokenStream {
+    match bindings {
+        [inner] => quote! { ::solana_idl_parser::json::ToJson::to_json(#inner) },
+        _ => quote! {
+            ::solana_idl_parser::serde_json::Value::Array(vec![
+                #(::solana_idl_parser::json::ToJson::to_json(#bindings)),*
+            ])
+        },
+    }
+}
+
+/// Field names of a generated struct or variant, the snake-case IDL names
+fn snake_names(fields: &[IdlField]) -> Vec<syn::Ident> {
+    fields
+        .iter()
+        .map(|f| format_ident!("{}", f.name.to_case(Case::Snake)))
+        .collect()
+}
+
+/// Names fields are bound to, which cannot shadow the map being built
+fn bindings(len: usize) -> Vec<syn::Ident> {
+    (0..len).map(|i| format_ident!("field_{}", i)).collect()
+}
@@ -0,0 +1,127 @@
+use crate::parser::{IdlEnumVariantFields, IdlField, IdlType, IdlTypeDef, IdlTypeDefFields};
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+
+/// `<NAME>_LAYOUT` constant for a struct whose fields all have a fixed size
+///
+/// Each entry is a field's Rust name with its byte offset and size in the
+/// borsh encoding. Structs with a variable-size field get no constant, and
+/// fields borsh skips are left out: they take no bytes in the encoding, so a
+/// skip in the middle of a struct moves the fields after it up by its size.
+pub fn layout_const(name: &str, fields: &[IdlField], types: &[IdlTypeDef]) -> TokenStream {
+    let mut visiting = HashSet::from([name.to_string()]);
+    let mut offset = 0;
+    let mut entries = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.borsh_skipped()) {
+        let Some(size) = fixed_size(&field.ty, types, &mut visiting) else {
+            return quote! {};
+        };
+        let field_name = field.name.to_case(Case::Snake);
+        entries.push(quote! { (#field_name, #offset, #size) });
+        offset += size;
+    }
+
+    let layout_const = format_ident!("{}_LAYOUT", name.to_case(Case::ScreamingSnake));
+    quote! {
+        /// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+        pub const #layout_const: &[(&str, usize, usize)] = &[#(#entries),*];
+    }
+}
+
+/// Total size of the borsh encodings of `tys`, or `None` when any of them varies
+pub fn fixed_size_of<'a>(
+    tys: impl Iterator<Item = &'a IdlType>,
+    types: &[IdlTypeDef],
+) -> Option<usize> {
+    sum_sizes(tys, types, &mut HashSet::new())
+}
+
+/// Size of the borsh encoding of `ty`, or `None` when it varies
+///
+/// Enums are fixed when every variant's fields add up to the same size.
+/// `visiting` holds the defined types being sized, so recursive types are
+/// treated as variable-size instead of recursing forever.
+fn fixed_size(ty: &IdlType, types: &[IdlTypeDef], visiting: &mut HashSet<String>) -> Option<usize> {
+    match ty {
+        IdlType::Primitive(name) => match name.as_str() {
+            "bool" | "u8" | "i8" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" | "f32" => Some(4),
+            "u64" | "i64" | "f64" => Some(8),
+            "u128" | "i128" => Some(16),
+            "pubkey" | "publicKey" => Some(32),
+            _ => None,
+        },
+        IdlType::Array { array } => {
+            let (inner, len) = array;
+            fixed_size(inner, types, visiting)?.checked_mul(*len)
+        }
+        IdlType::Tuple { tuple } => sum_sizes(tuple.iter(), types, visiting),
+        IdlType::Defined { defined } => {
+            let name = defined.name();
+            let typedef = types.iter().find(|typedef| typedef.name == name)?;
+            if !visiting.insert(name.to_string()) {
+                return None;
+            }
+            let size = typedef_size(typedef, types, visiting);
+            visiting.remove(name);
+            size
+        }
+        IdlType::Option { .. }
+        | IdlType::Vec { .. }
+        | IdlType::HashSet { .. }
+        | IdlType::BTreeSet { .. }
+        | IdlType::HashMap { .. }
+        | IdlType::BTreeMap { .. }
+        | IdlType::Generic { .. }
+        | IdlType::Unsupported(_) => None,
+    }
+}
+
+/// Size of the borsh encoding of a type definition, or `None` when it varies
+fn typedef_size(
+    typedef: &IdlTypeDef,
+    types: &[IdlTypeDef],
+    visiting: &mut HashSet<String>,
+) -> Option<usize> {
+    let ty = &typedef.ty;
+    match ty.kind.as_str() {
+        "struct" => match &ty.fields {
+            IdlTypeDefFields::Named(fields) => sum_sizes(encoded_types(fields), types, visiting),
+            IdlTypeDefFields::Tuple(tys) => sum_sizes(tys.iter(), types, visiting),
+            IdlTypeDefFields::None => Some(0),
+        },
+        "enum" => {
+            let mut sizes = ty.variants.iter().map(|variant| match &variant.fields {
+                Some(IdlEnumVariantFields::Named(fields)) => {
+                    sum_sizes(encoded_types(fields), types, visiting)
+                }
+                Some(IdlEnumVariantFields::Tuple(tys)) => sum_sizes(tys.iter(), types, visiting),
+                None => Some(0),
+            });
+            let first = sizes.next()??;
+            sizes
+                .all(|size| size == Some(first))
+                .then_some(ty.tag_width() + first)
+        }
+        "type" | "alias" => fixed_size(ty.alias.as_ref()?, types, visiting),
+        _ => None,
+    }
+}
+
+/// Types of the fields borsh encodes, leaving out skipped ones
+pub fn encoded_types(fields: &[IdlField]) -> impl Iterator<Item = &IdlType> {
+    fields.iter().filter(|f| !f.borsh_skipped()).map(|f| &f.ty)
+}
+
+/// Total size of `tys`, or `None` when any of them varies
+fn sum_sizes<'a>(
+    tys: impl Iterator<Item = &'a IdlType>,
+    types: &[IdlTypeDef],
+    visiting: &mut HashSet<String>,
+) -> Option<usize> {
+    tys.map(|ty| fixed_size(ty, types, visiting))
+        .try_fold(0usize, |total, size| total.checked_add(size?))
+}
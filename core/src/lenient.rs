@@ -0,0 +1,263 @@
+use crate::parser::{Idl, IdlType, IdlTypeDefType, MAX_TYPE_DEPTH, PRIMITIVE_TYPES};
+use std::collections::{HashMap, HashSet};
+
+/// Check that every type used by the IDL is supported by the generator
+///
+/// In strict mode the first unsupported type is an error. In lenient mode
+/// types and constants using one are dropped, instructions with such args are
+/// moved to `skipped_instructions`, and each is recorded in `warnings`.
+/// Lenient mode also drops uses of types that are not defined in the IDL.
+/// Types nested deeper than [`MAX_TYPE_DEPTH`] are an error in either mode.
+pub fn check_supported(idl: &mut Idl, lenient: bool) -> Result<(), String> {
+    check_depth(idl)?;
+    if !lenient {
+        return check_strict(idl);
+    }
+
+    let mut defined: HashSet<String> = idl.types.iter().map(|t| t.name.clone()).collect();
+    defined.extend(
+        idl.accounts
+            .iter()
+            .filter(|acc| acc.ty.is_some())
+            .map(|acc| acc.name.clone()),
+    );
+
+    // Types are skipped until a fixed point, since skipping one can break others
+    let mut skipped: HashMap<String, String> = HashMap::new();
+    loop {
+        let mut newly_skipped = Vec::new();
+        let defs = idl.types.iter().map(|t| (&t.name, &t.ty)).chain(
+            idl.accounts
+                .iter()
+                .filter_map(|acc| Some((&acc.name, acc.ty.as_ref()?))),
+        );
+        for (name, ty) in defs {
+            if skipped.contains_key(name) {
+                continue;
+            }
+            if let Some(kind) = unsupported_kind(ty) {
+                newly_skipped.push((name.clone(), format!("unsupported kind `{}`", kind)));
+                continue;
+            }
+            let checked = ty
+                .field_types()
+                .into_iter()
+                .try_for_each(|field| check_type(field, &defined, &skipped));
+            if let Err(reason) = checked {
+                newly_skipped.push((name.clone(), reason));
+            }
+        }
+        if newly_skipped.is_empty() {
+            break;
+        }
+        skipped.extend(newly_skipped);
+    }
+
+    let mut skipped_names: Vec<&String> = skipped.keys().collect();
+    skipped_names.sort();
+    for name in skipped_names {
+        idl.warnings
+            .push(format!("skipped type `{}`: {}", name, skipped[name]));
+    }
+    idl.types.retain(|t| !skipped.contains_key(&t.name));
+    for acc in &mut idl.accounts {
+        if skipped.contains_key(&acc.name) {
+            acc.ty = None;
+        }
+    }
+
+    let instructions = std::mem::take(&mut idl.instructions);
+    for ix in instructions {
+        let checked = ix.args.iter().try_for_each(|arg| {
+            check_type(&arg.ty, &defined, &skipped)
+                .map_err(|reason| format!("arg `{}`: {}", arg.name, reason))
+        });
+        match checked {
+            Ok(()) => idl.instructions.push(ix),
+            Err(reason) => {
+                idl.warnings.push(format!(
+                    "instruction `{}` cannot be decoded: {}",
+                    ix.name, reason
+                ));
+                idl.skipped_instructions.push(ix);
+            }
+        }
+    }
+
+    let mut warnings = Vec::new();
+    idl.constants.retain(
+        |constant| match check_type(&constant.ty, &defined, &skipped) {
+            Ok(()) => true,
+            Err(reason) => {
+                warnings.push(format!("skipped constant `{}`: {}", constant.name, reason));
+                false
+            }
+        },
+    );
+    idl.warnings.extend(warnings);
+
+    Ok(())
+}
+
+/// Fail on the first type nested deeper than [`MAX_TYPE_DEPTH`]
+fn check_depth(idl: &Idl) -> Result<(), String> {
+    let too_deep = |ty: &IdlType| ty.depth() > MAX_TYPE_DEPTH;
+    let error = |place: String| {
+        Err(format!(
+            "type in {} is nested deeper than the limit of {} levels",
+            place, MAX_TYPE_DEPTH
+        ))
+    };
+
+    for typedef in &idl.types {
+        if typedef.ty.field_types().into_iter().any(too_deep) {
+            return error(format!("type `{}`", typedef.name));
+        }
+    }
+    for acc in &idl.accounts {
+        let Some(ty) = &acc.ty else { continue };
+        if ty.field_types().into_iter().any(too_deep) {
+            return error(format!("account `{}`", acc.name));
+        }
+    }
+    for ix in &idl.instructions {
+        if let Some(arg) = ix.args.iter().find(|arg| too_deep(&arg.ty)) {
+            return error(format!("arg `{}` of instruction `{}`", arg.name, ix.name));
+        }
+    }
+    if let Some(constant) = idl.constants.iter().find(|constant| too_deep(&constant.ty)) {
+        return error(format!("constant `{}`", constant.name));
+    }
+
+    Ok(())
+}
+
+/// Fail on the first type shape the generator does not understand
+fn check_strict(idl: &Idl) -> Result<(), String> {
+    let hint = "pass `lenient = true` to skip it";
+
+    for typedef in &idl.types {
+        if let Some(kind) = unsupported_kind(&typedef.ty) {
+            return Err(format!(
+                "unsupported kind `{}` of type `{}`; {}",
+                kind, typedef.name, hint
+            ));
+        }
+        if let Some(value) = typedef.ty.field_types().into_iter().find_map(unsupported) {
+            return Err(format!(
+                "unsupported type `{}` in type `{}`; {}",
+                value, typedef.name, hint
+            ));
+        }
+    }
+    for acc in &idl.accounts {
+        let Some(ty) = &acc.ty else { continue };
+        if let Some(kind) = unsupported_kind(ty) {
+            return Err(format!(
+                "unsupported kind `{}` of account `{}`; {}",
+                kind, acc.name, hint
+            ));
+        }
+        if let Some(value) = ty.field_types().into_iter().find_map(unsupported) {
+            return Err(format!(
+                "unsupported type `{}` in account `{}`; {}",
+                value, acc.name, hint
+            ));
+        }
+    }
+    for ix in &idl.instructions {
+        for arg in &ix.args {
+            if let Some(value) = unsupported(&arg.ty) {
+                return Err(format!(
+                    "unsupported type `{}` in arg `{}` of instruction `{}`; {}",
+                    value, arg.name, ix.name, hint
+                ));
+            }
+        }
+    }
+    for constant in &idl.constants {
+        if let Some(value) = unsupported(&constant.ty) {
+            return Err(format!(
+                "unsupported type `{}` in constant `{}`; {}",
+                value, constant.name, hint
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The kind of a type definition if the generator cannot emit it
+fn unsupported_kind(ty: &IdlTypeDefType) -> Option<&str> {
+    match ty.kind.as_str() {
+        "struct" | "enum" | "type" | "alias" => None,
+        kind => Some(kind),
+    }
+}
+
+/// The first unsupported type shape within `ty`, if any
+fn unsupported(ty: &IdlType) -> Option<&serde_json::Value> {
+    match ty {
+        IdlType::Unsupported(value) => Some(value),
+        IdlType::Primitive(_) | IdlType::Generic { .. } => None,
+        IdlType::Defined { defined } => defined.generic_types().find_map(unsupported),
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => unsupported(inner),
+        IdlType::Array { array } => unsupported(&array.0),
+        IdlType::Tuple { tuple } => tuple.iter().find_map(unsupported),
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => unsupported(key).or_else(|| unsupported(value)),
+    }
+}
+
+/// Check a type against the defined and skipped type names
+fn check_type(
+    ty: &IdlType,
+    defined: &HashSet<String>,
+    skipped: &HashMap<String, String>,
+) -> Result<(), String> {
+    let check_name = |name: &str| {
+        if skipped.contains_key(name) {
+            Err(format!("uses skipped type `{}`", name))
+        } else if PRIMITIVE_TYPES.contains(&name) || defined.contains(name) {
+            Ok(())
+        } else {
+            Err(format!("unknown type `{}`", name))
+        }
+    };
+
+    match ty {
+        IdlType::Unsupported(value) => Err(format!("unsupported type `{}`", value)),
+        IdlType::Primitive(name) => check_name(name),
+        IdlType::Defined { defined: reference } => {
+            check_name(reference.name())?;
+            reference
+                .generic_types()
+                .try_for_each(|ty| check_type(ty, defined, skipped))
+        }
+        IdlType::Generic { .. } => Ok(()),
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => check_type(inner, defined, skipped),
+        IdlType::Array { array } => check_type(&array.0, defined, skipped),
+        IdlType::Tuple { tuple } => tuple
+            .iter()
+            .try_for_each(|ty| check_type(ty, defined, skipped)),
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => {
+            check_type(key, defined, skipped)?;
+            check_type(value, defined, skipped)
+        }
+    }
+}
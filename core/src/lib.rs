@@ -0,0 +1,152 @@
+//! IDL model and code generation behind `solana_idl_parser`'s macros.
+//!
+//! [`load_idl`] reads an Anchor or Shank IDL the same way `parse_idl!` does:
+//! Shank IDLs are mapped onto the Anchor layout, missing discriminators are
+//! filled in from the IDL spec, unsupported types are rejected or skipped, and
+//! the program name is resolved. Tooling can build on the resulting [`Idl`]
+//! without reimplementing any of it.
+//!
+//! [`parse_onchain_idl_account`] does the same for the data of an on-chain
+//! Anchor IDL account, and with the `fetch` feature `load_onchain_idl` fetches
+//! that account over RPC.
+//!
+//! [`generate_idl_code`] produces the tokens `parse_idl!` expands to. The
+//! generated code refers to the `solana_idl_parser` runtime crate.
+
+mod aliases;
+mod derives;
+mod fixtures;
+mod fuzz;
+mod generator;
+mod idl_account;
+mod json;
+mod layout;
+mod lenient;
+#[cfg(feature = "fetch")]
+mod onchain;
+pub mod parser;
+mod pda;
+mod repr;
+mod sdk;
+mod shank;
+mod spec;
+mod validate;
+
+pub use generator::{generate_idl_code, generate_multi_idl_code, GenerateOptions};
+pub use idl_account::parse_onchain_idl_account;
+#[cfg(feature = "fetch")]
+pub use onchain::{idl_address, load_onchain_idl};
+pub use parser::{Idl, IdlFormat};
+
+use anyhow::{anyhow, Context};
+use std::path::Path;
+
+/// How an IDL is interpreted while loading
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoadOptions {
+    /// IDL format, detected from `metadata.origin` when `None`
+    pub format: Option<IdlFormat>,
+    /// Skip unsupported types, recording a warning for each, instead of failing
+    pub lenient: bool,
+}
+
+/// Read and load the IDL file at `path`
+///
+/// IDLs without `metadata.name` take their program name from the top-level
+/// `name`, then the file stem, then the program address. The address is the
+/// top-level `address`, or `metadata.address` in IDLs without one.
+pub fn load_idl(path: &Path, options: &LoadOptions) -> anyhow::Result<Idl> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("failed to read IDL file at {:?}", path))?;
+    // Parsed as it is read, so large IDLs are never held in memory as text
+    let idl = serde_json::from_reader(std::io::BufReader::new(file)).map_err(|e| {
+        if e.is_io() {
+            anyhow!("failed to read IDL file at {:?}: {}", path, e)
+        } else {
+            anyhow!("failed to parse IDL file {:?}: {}", path, e)
+        }
+    })?;
+    load(idl, Some(path), options)
+}
+
+/// Load an IDL from its JSON text
+///
+/// Like [`load_idl`], without the file stem to fall back on for the name.
+pub fn parse_idl_str(json: &str, options: &LoadOptions) -> anyhow::Result<Idl> {
+    let idl = serde_json::from_str(json).map_err(|e| anyhow!("failed to parse IDL file: {}", e))?;
+    load(idl, None, options)
+}
+
+fn load(mut idl: Idl, path: Option<&Path>, options: &LoadOptions) -> anyhow::Result<Idl> {
+    // Legacy and Shank IDLs keep the program address in the metadata
+    if idl.address.is_empty() {
+        if let Some(address) = idl.metadata.address.clone() {
+            idl.address = address;
+        }
+    }
+
+    match options.format.unwrap_or_else(|| shank::detect_format(&idl)) {
+        IdlFormat::Shank => shank::normalize(&mut idl),
+        IdlFormat::Anchor => spec::apply_spec_defaults(&mut idl),
+    }
+    spec::inline_event_types(&mut idl);
+    spec::flatten_account_groups(&mut idl);
+
+    let defs = idl.types.iter_mut().map(|t| (&t.name, &mut t.ty)).chain(
+        idl.accounts
+            .iter_mut()
+            .filter_map(|acc| Some((&acc.name, acc.ty.as_mut()?))),
+    );
+    for (name, ty) in defs {
+        ty.apply_field_order().map_err(|e| {
+            let message = format!("`fieldOrder` of type `{}` {}", name, e);
+            match path {
+                Some(path) => anyhow!("{} (in {:?})", message, path),
+                None => anyhow!(message),
+            }
+        })?;
+    }
+
+    lenient::check_supported(&mut idl, options.lenient).map_err(|e| match path {
+        Some(path) => anyhow!("{} (in {:?})", e, path),
+        None => anyhow!(e),
+    })?;
+
+    if idl.metadata.name.is_empty() {
+        let file_stem = path
+            .and_then(|path| path.file_stem())
+            .and_then(|stem| stem.to_str());
+        let address_name = (!idl.address.is_empty()).then(|| {
+            format!(
+                "program_{}",
+                idl.address.chars().take(8).collect::<String>()
+            )
+        });
+
+        idl.metadata.name = idl
+            .name
+            .clone()
+            .or_else(|| file_stem.map(str::to_string))
+            .or(address_name)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| {
+                let of_path = path.map(|path| format!(" of {:?}", path)).unwrap_or_default();
+                anyhow!(
+                    "cannot determine the program name{}: the IDL has no `metadata.name`, `name` or `address`",
+                    of_path
+                )
+            })?;
+    }
+
+    if idl.address.is_empty() {
+        let of_path = path
+            .map(|path| format!(" of {:?}", path))
+            .unwrap_or_default();
+        return Err(anyhow!(
+            "cannot determine the program address{}: the IDL has neither `address` nor `metadata.address`",
+            of_path
+        ));
+    }
+
+    Ok(idl)
+}
@@ -0,0 +1,105 @@
+use crate::idl_account::inflate_idl_account;
+use crate::{load_idl, Idl, LoadOptions};
+use anyhow::{anyhow, bail, Context};
+use base64::Engine;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Seed Anchor derives the IDL account from, relative to the program's base PDA
+const IDL_SEED: &str = "anchor:idl";
+
+/// Load the on-chain IDL of `program_id`, fetching it over RPC unless cached
+///
+/// The IDL JSON is cached as `<program_id>.json` in `cache_dir`, and a cached
+/// file is loaded without touching the network. Delete it to fetch again.
+pub fn load_onchain_idl(
+    program_id: &str,
+    rpc_url: &str,
+    cache_dir: &Path,
+    options: &LoadOptions,
+) -> anyhow::Result<Idl> {
+    let program = decode_address(program_id)?;
+    let cache_path = cache_dir.join(format!("{}.json", program_id));
+
+    if !cache_path.exists() {
+        let json = fetch_idl_json(&program, rpc_url)
+            .with_context(|| format!("failed to fetch the IDL of {}", program_id))?;
+        std::fs::create_dir_all(cache_dir)
+            .with_context(|| format!("failed to create IDL cache directory {:?}", cache_dir))?;
+        std::fs::write(&cache_path, json)
+            .with_context(|| format!("failed to write IDL cache file {:?}", cache_path))?;
+    }
+
+    load_idl(&cache_path, options)
+}
+
+/// Address of the account holding the Anchor IDL of `program`
+///
+/// Anchor creates it with the seed `anchor:idl` from the program's PDA with no seeds.
+pub fn idl_address(program: &[u8; 32]) -> [u8; 32] {
+    let base = find_program_address(program);
+    let mut hasher = Sha256::new();
+    hasher.update(base);
+    hasher.update(IDL_SEED);
+    hasher.update(program);
+    hasher.finalize().into()
+}
+
+/// Fetch the IDL account of `program` and inflate its JSON
+fn fetch_idl_json(program: &[u8; 32], rpc_url: &str) -> anyhow::Result<String> {
+    let address = bs58::encode(idl_address(program)).into_string();
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getAccountInfo",
+        "params": [address, { "encoding": "base64" }],
+    });
+    let response: serde_json::Value = ureq::post(rpc_url)
+        .send_json(request)
+        .with_context(|| format!("RPC request to {} failed", rpc_url))?
+        .into_json()
+        .context("RPC response is not JSON")?;
+
+    if let Some(error) = response.get("error") {
+        bail!("RPC error: {}", error);
+    }
+    let value = &response["result"]["value"];
+    if value.is_null() {
+        bail!("no IDL account at {}", address);
+    }
+    let data = value["data"][0]
+        .as_str()
+        .ok_or_else(|| anyhow!("RPC response has no base64 account data"))?;
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .context("account data is not valid base64")?;
+
+    inflate_idl_account(&data)
+}
+
+/// First off-curve address derived from `program` with no seeds
+fn find_program_address(program: &[u8; 32]) -> [u8; 32] {
+    (0..=u8::MAX)
+        .rev()
+        .find_map(|bump| {
+            let mut hasher = Sha256::new();
+            hasher.update([bump]);
+            hasher.update(program);
+            hasher.update(b"ProgramDerivedAddress");
+            let address: [u8; 32] = hasher.finalize().into();
+            let on_curve = CompressedEdwardsY(address).decompress().is_some();
+            (!on_curve).then_some(address)
+        })
+        .expect("no bump yields an off-curve program address")
+}
+
+/// Decode a base58 program address
+fn decode_address(address: &str) -> anyhow::Result<[u8; 32]> {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .with_context(|| format!("program id {} is not valid base58", address))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow!("program id {} is not 32 bytes long", address))
+}
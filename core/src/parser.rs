@@ -0,0 +1,494 @@
+use serde::Deserialize;
+
+/// IDL primitive type names the generator maps to Rust types
+pub const PRIMITIVE_TYPES: &[&str] = &[
+    "bool",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "f32",
+    "f64",
+    "string",
+    "pubkey",
+    "publicKey",
+    "bytes",
+];
+
+/// Source format of an IDL file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdlFormat {
+    Anchor,
+    Shank,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Idl {
+    /// Program address; Shank IDLs carry it in `metadata.address` instead
+    #[serde(default)]
+    pub address: String,
+    /// Program name of legacy and Shank IDLs, which have no `metadata.name`
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub metadata: IdlMetadata,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccount>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+    #[serde(default)]
+    pub events: Vec<IdlEvent>,
+    #[serde(default)]
+    pub errors: Vec<IdlError>,
+    #[serde(default)]
+    pub constants: Vec<IdlConst>,
+    /// Instructions left undecodable by lenient mode
+    #[serde(skip)]
+    pub skipped_instructions: Vec<IdlInstruction>,
+    /// Items dropped by lenient mode, reported as compile warnings
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct IdlMetadata {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    /// IDL spec version, absent in IDLs generated before Anchor 0.30
+    #[serde(default)]
+    pub spec: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Set to `"shank"` by Shank-generated IDLs
+    #[serde(default)]
+    pub origin: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    /// Shank's single-integer instruction discriminator
+    #[serde(default)]
+    pub discriminant: Option<IdlDiscriminant>,
+    pub accounts: Vec<IdlInstructionAccount>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlDiscriminant {
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub value: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlInstructionAccount {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(default, alias = "isMut")]
+    pub writable: bool,
+    #[serde(default, alias = "isSigner")]
+    pub signer: bool,
+    #[serde(default, alias = "isOptional")]
+    pub optional: bool,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub pda: Option<IdlPda>,
+    #[serde(default)]
+    pub relations: Vec<String>,
+    /// Accounts of a nested account group, flattened while loading
+    #[serde(default)]
+    pub accounts: Vec<IdlInstructionAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlPda {
+    pub seeds: Vec<IdlSeed>,
+    #[serde(default)]
+    pub program: Option<IdlSeed>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind")]
+#[serde(rename_all = "lowercase")]
+pub enum IdlSeed {
+    Const {
+        value: Vec<u8>,
+    },
+    Arg {
+        path: String,
+    },
+    Account {
+        path: String,
+        #[serde(default)]
+        account: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+    /// Extension: contents of a `#[borsh(...)]` attribute for the generated field
+    #[serde(default)]
+    pub borsh: Option<String>,
+    /// Extension: length a `bytes` field must have, checked when decoding
+    #[serde(default)]
+    pub len: Option<usize>,
+}
+
+impl IdlField {
+    /// Whether borsh leaves the field out of the encoding, through a `skip` attribute
+    pub fn borsh_skipped(&self) -> bool {
+        self.borsh
+            .as_deref()
+            .is_some_and(|attr| attr.trim() == "skip")
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum IdlType {
+    Primitive(String),
+    Defined {
+        defined: IdlDefinedType,
+    },
+    Option {
+        option: Box<IdlType>,
+    },
+    Vec {
+        vec: Box<IdlType>,
+    },
+    Array {
+        array: (Box<IdlType>, usize),
+    },
+    Tuple {
+        tuple: Vec<IdlType>,
+    },
+    HashMap {
+        #[serde(rename = "hashMap")]
+        hash_map: (Box<IdlType>, Box<IdlType>),
+    },
+    BTreeMap {
+        #[serde(rename = "bTreeMap")]
+        btree_map: (Box<IdlType>, Box<IdlType>),
+    },
+    HashSet {
+        #[serde(rename = "hashSet")]
+        hash_set: Box<IdlType>,
+    },
+    BTreeSet {
+        #[serde(rename = "bTreeSet")]
+        btree_set: Box<IdlType>,
+    },
+    /// A type parameter of the enclosing type definition
+    Generic {
+        generic: String,
+    },
+    /// Any type shape the generator does not understand, e.g. const generic array lengths
+    Unsupported(serde_json::Value),
+}
+
+/// Deepest nesting of container types the generator accepts
+///
+/// Code generation recurses once per level, so IDLs from untrusted sources
+/// are rejected past this depth instead of overflowing the stack.
+pub const MAX_TYPE_DEPTH: usize = 64;
+
+impl IdlType {
+    /// Nesting depth of the type, 1 for primitives and defined types
+    ///
+    /// Computed without recursion, so it is safe on arbitrarily deep types.
+    pub fn depth(&self) -> usize {
+        let mut max = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((ty, depth)) = stack.pop() {
+            max = max.max(depth);
+            match ty {
+                IdlType::Primitive(_) | IdlType::Generic { .. } | IdlType::Unsupported(_) => {}
+                IdlType::Defined { defined } => {
+                    stack.extend(defined.generic_types().map(|ty| (ty, depth + 1)))
+                }
+                IdlType::Option { option: inner }
+                | IdlType::Vec { vec: inner }
+                | IdlType::HashSet { hash_set: inner }
+                | IdlType::BTreeSet { btree_set: inner } => stack.push((inner, depth + 1)),
+                IdlType::Array { array } => stack.push((&array.0, depth + 1)),
+                IdlType::Tuple { tuple } => stack.extend(tuple.iter().map(|ty| (ty, depth + 1))),
+                IdlType::HashMap {
+                    hash_map: (key, value),
+                }
+                | IdlType::BTreeMap {
+                    btree_map: (key, value),
+                } => stack.extend([(&**key, depth + 1), (&**value, depth + 1)]),
+            }
+        }
+        max
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum IdlDefinedType {
+    Simple(String),
+    Named {
+        name: String,
+        /// Arguments instantiating a generic type definition
+        #[serde(default)]
+        generics: Vec<IdlGenericArg>,
+    },
+}
+
+impl IdlDefinedType {
+    pub fn name(&self) -> &str {
+        match self {
+            IdlDefinedType::Simple(name) => name,
+            IdlDefinedType::Named { name, .. } => name,
+        }
+    }
+
+    pub fn generics(&self) -> &[IdlGenericArg] {
+        match self {
+            IdlDefinedType::Simple(_) => &[],
+            IdlDefinedType::Named { generics, .. } => generics,
+        }
+    }
+
+    /// Types passed as generic arguments
+    pub fn generic_types(&self) -> impl Iterator<Item = &IdlType> {
+        self.generics().iter().filter_map(|arg| match arg {
+            IdlGenericArg::Type { ty } => Some(ty),
+            IdlGenericArg::Const { .. } => None,
+        })
+    }
+}
+
+/// Argument of a generic instantiation, e.g. `{ "kind": "type", "type": "u64" }`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum IdlGenericArg {
+    Type {
+        #[serde(rename = "type")]
+        ty: IdlType,
+    },
+    Const {
+        value: String,
+    },
+}
+
+/// Parameter of a generic type definition, e.g. `{ "kind": "type", "name": "T" }`
+#[derive(Debug, Deserialize, Clone)]
+pub struct IdlGenericParam {
+    /// `type`, or `const` for const generics, which are not supported
+    pub kind: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    /// Empty for Shank accounts, which have no discriminator
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    #[serde(default, rename = "type")]
+    pub ty: Option<IdlTypeDefType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefType,
+    #[serde(default)]
+    pub generics: Vec<IdlGenericParam>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlTypeDefType {
+    pub kind: String,
+    #[serde(default)]
+    pub fields: IdlTypeDefFields,
+    #[serde(default)]
+    pub variants: Vec<IdlEnumVariant>,
+    /// Target of a `type` alias, `value` in legacy `alias` definitions
+    #[serde(default, alias = "value")]
+    pub alias: Option<IdlType>,
+    /// Order the program serializes the named fields in, when it differs from `fields`
+    ///
+    /// An extension for programs with hand-written borsh layouts; applied
+    /// while loading, so `fields` is in wire order afterwards.
+    #[serde(default, rename = "fieldOrder")]
+    pub field_order: Option<Vec<String>>,
+    /// Extension: contents of a `#[borsh(...)]` attribute for the generated struct or enum
+    #[serde(default)]
+    pub borsh: Option<String>,
+    /// Representation of the type, e.g. `{ "kind": "u16" }` for an enum with a two-byte tag
+    #[serde(default)]
+    pub repr: Option<IdlRepr>,
+}
+
+/// Integer representations an enum's variant tag can take
+pub const TAG_REPRS: &[&str] = &["u8", "u16", "u32", "u64"];
+
+/// `repr` of a type definition, written as `"u16"` or `{ "kind": "u16" }`
+///
+/// Anchor writes the `rust`, `c` and `transparent` reprs of zero-copy types,
+/// which leave an enum's tag at borsh's single byte.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum IdlRepr {
+    Name(String),
+    Kind { kind: String },
+}
+
+impl IdlRepr {
+    pub fn kind(&self) -> &str {
+        match self {
+            IdlRepr::Name(kind) | IdlRepr::Kind { kind } => kind,
+        }
+    }
+}
+
+impl IdlTypeDefType {
+    /// Reorder the named fields by `fieldOrder`, which must list each exactly once
+    ///
+    /// Errors describe what is wrong with the `fieldOrder` of this type.
+    pub fn apply_field_order(&mut self) -> Result<(), String> {
+        let Some(order) = self.field_order.take() else {
+            return Ok(());
+        };
+        let IdlTypeDefFields::Named(fields) = &mut self.fields else {
+            return Err("is only supported on structs with named fields".to_string());
+        };
+
+        let mut reordered = Vec::with_capacity(fields.len());
+        for name in &order {
+            let position = fields
+                .iter()
+                .position(|field| &field.name == name)
+                .ok_or_else(|| format!("names unknown or repeated field `{}`", name))?;
+            reordered.push(fields.remove(position));
+        }
+        if let Some(field) = fields.first() {
+            return Err(format!("is missing field `{}`", field.name));
+        }
+        *fields = reordered;
+        Ok(())
+    }
+
+    /// Integer type of the variant tag of an enum, `u8` unless `repr` declares a wider one
+    pub fn tag_repr(&self) -> &str {
+        match self.repr.as_ref().map(IdlRepr::kind) {
+            Some(kind) if TAG_REPRS.contains(&kind) => kind,
+            _ => "u8",
+        }
+    }
+
+    /// Bytes of the variant tag of an enum
+    pub fn tag_width(&self) -> usize {
+        match self.tag_repr() {
+            "u16" => 2,
+            "u32" => 4,
+            "u64" => 8,
+            _ => 1,
+        }
+    }
+
+    /// Number of variants the enum's tag can address
+    pub fn max_variants(&self) -> u128 {
+        1 << (8 * self.tag_width())
+    }
+
+    /// Types of every field of a struct or enum definition, or the target of an alias
+    pub fn field_types(&self) -> Vec<&IdlType> {
+        let mut types: Vec<&IdlType> = match &self.fields {
+            IdlTypeDefFields::Named(fields) => fields.iter().map(|f| &f.ty).collect(),
+            IdlTypeDefFields::Tuple(types) => types.iter().collect(),
+            IdlTypeDefFields::None => Vec::new(),
+        };
+        for variant in &self.variants {
+            match &variant.fields {
+                Some(IdlEnumVariantFields::Named(fields)) => {
+                    types.extend(fields.iter().map(|f| &f.ty))
+                }
+                Some(IdlEnumVariantFields::Tuple(tys)) => types.extend(tys),
+                None => {}
+            }
+        }
+        types.extend(&self.alias);
+        types
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(untagged)]
+pub enum IdlTypeDefFields {
+    #[default]
+    None,
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Option<IdlEnumVariantFields>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlEnumVariantFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlEvent {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    /// Inline fields of legacy events, which newer IDLs declare in `types`
+    ///
+    /// Loading adds a struct type named after the event from them.
+    #[serde(default)]
+    pub fields: Option<Vec<IdlField>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    #[serde(default)]
+    pub msg: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlConst {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+    pub value: String,
+}
@@ -0,0 +1,207 @@
+use crate::aliases::{alias_targets, resolve_alias};
+use crate::generator::{unique_field_names, GenerateOptions, InstructionItems};
+use crate::parser::{IdlField, IdlInstruction, IdlSeed, IdlType, IdlTypeDef, IdlTypeDefFields};
+use crate::sdk;
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+
+/// Generate PDA derivation and verification methods on instruction accounts structs
+///
+/// For every PDA account whose seeds can all be resolved from constants, the
+/// instruction args and the other accounts of the instruction, the accounts
+/// struct gets `find_{account}_address`, which runs `find_program_address`, and
+/// `verify_{account}_address`, which checks the account and an optional bump
+/// against it. Seeds reading account data cannot be resolved and are skipped.
+pub fn generate_pda_helpers(
+    instructions: &[IdlInstruction],
+    types: &[IdlTypeDef],
+    options: &GenerateOptions,
+) -> TokenStream {
+    if !sdk::supports_pda_derivation(options) {
+        return TokenStream::new();
+    }
+
+    let pubkey = sdk::pubkey_type();
+    let result = sdk::result_type(options, quote! { u8 });
+    let aliases = alias_targets(types);
+
+    let impls = instructions
+        .iter()
+        .filter(|ix| !ix.accounts.is_empty())
+        .map(|ix| {
+            let items = InstructionItems::new(ix, types, options);
+            let accounts_struct_name = &items.accounts;
+            let account_names = unique_field_names(ix.accounts.iter().map(|acc| acc.name.as_str()));
+            let arg_names = unique_field_names(ix.args.iter().map(|arg| arg.name.as_str()));
+            let args_param = if ix.args.is_empty() {
+                quote! {}
+            } else {
+                let args_struct_name = &items.args;
+                quote! { args: &#args_struct_name, }
+            };
+            let args_call = if ix.args.is_empty() {
+                quote! {}
+            } else {
+                quote! { args }
+            };
+
+            let methods = ix.accounts.iter().zip(&account_names).filter_map(|(acc, field_name)| {
+            if acc.optional {
+                return None;
+            }
+            let pda = acc.pda.as_ref()?;
+            let seeds = pda
+                .seeds
+                .iter()
+                .map(|seed| seed_bytes(seed, ix, &account_names, &arg_names, types, &aliases))
+                .collect::<Option<Vec<_>>>()?;
+            let program = match &pda.program {
+                None => quote! { PROGRAM_ID },
+                Some(IdlSeed::Const { value }) if value.len() == 32 => {
+                    quote! { #pubkey::new_from_array([#(#value),*]) }
+                }
+                Some(IdlSeed::Account { path, .. }) if !path.contains('.') => {
+                    let account = account_field(path, ix, &account_names)?;
+                    quote! { self.#account }
+                }
+                Some(_) => return None,
+            };
+
+            let find_fn = format_ident!("find_{}_address", field_name);
+            let verify_fn = format_ident!("verify_{}_address", field_name);
+            let mismatch = format!("account `{}` does not match its PDA", acc.name);
+            let mismatch = sdk::decode_error(options, "Other", quote! { #mismatch });
+            let bump_mismatch = format!("bump of `{}` does not match its PDA", acc.name);
+            let bump_mismatch = sdk::decode_error(options, "Other", quote! { #bump_mismatch });
+
+            Some(quote! {
+                /// Derive this account's PDA from its seeds, returning the address and bump
+                pub fn #find_fn(&self, #args_param) -> (#pubkey, u8) {
+                    #pubkey::find_program_address(&[#(#seeds),*], &#program)
+                }
+
+                /// Check this account against its PDA and, if given, the expected bump
+                pub fn #verify_fn(&self, #args_param bump: ::core::option::Option<u8>) -> #result {
+                    let (address, found_bump) = self.#find_fn(#args_call);
+                    if address != self.#field_name {
+                        return Err(#mismatch.into());
+                    }
+                    if bump.is_some_and(|bump| bump != found_bump) {
+                        return Err(#bump_mismatch.into());
+                    }
+                    Ok(found_bump)
+                }
+            })
+        });
+            let methods: Vec<TokenStream> = methods.collect();
+
+            if methods.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    impl #accounts_struct_name {
+                        #(#methods)*
+                    }
+                }
+            }
+        });
+
+    quote! { #(#impls)* }
+}
+
+/// Expression for a seed's bytes, or `None` if it cannot be resolved statically
+fn seed_bytes(
+    seed: &IdlSeed,
+    ix: &IdlInstruction,
+    account_names: &[syn::Ident],
+    arg_names: &[syn::Ident],
+    types: &[IdlTypeDef],
+    aliases: &HashMap<&str, &IdlType>,
+) -> Option<TokenStream> {
+    match seed {
+        IdlSeed::Const { value } => Some(quote! { &[#(#value),*] }),
+        IdlSeed::Account { path, .. } => {
+            if path.contains('.') {
+                return None;
+            }
+            let account = account_field(path, ix, account_names)?;
+            Some(quote! { self.#account.as_ref() })
+        }
+        IdlSeed::Arg { path } => {
+            let mut segments = path.split('.');
+            let first = segments.next()?.to_case(Case::Snake);
+            let index = ix
+                .args
+                .iter()
+                .position(|arg| arg.name.to_case(Case::Snake) == first)?;
+            let arg_name = &arg_names[index];
+
+            let mut expr = quote! { args.#arg_name };
+            let mut ty = &ix.args[index].ty;
+            for segment in segments {
+                let field = struct_field(resolve_alias(ty, aliases), segment, types)?;
+                let field_name = format_ident!("{}", field.name.to_case(Case::Snake));
+                expr = quote! { #expr.#field_name };
+                ty = &field.ty;
+            }
+            value_bytes(expr, resolve_alias(ty, aliases))
+        }
+    }
+}
+
+/// Struct field named `name` of the defined type `ty`
+fn struct_field<'a>(ty: &IdlType, name: &str, types: &'a [IdlTypeDef]) -> Option<&'a IdlField> {
+    let type_name = match ty {
+        IdlType::Defined { defined } => defined.name(),
+        IdlType::Primitive(name) => name.as_str(),
+        _ => return None,
+    };
+    let typedef = types.iter().find(|typedef| typedef.name == type_name)?;
+    let IdlTypeDefFields::Named(fields) = &typedef.ty.fields else {
+        return None;
+    };
+    let name = name.to_case(Case::Snake);
+    fields
+        .iter()
+        .find(|field| field.name.to_case(Case::Snake) == name)
+}
+
+/// Seed bytes of a value, the way Anchor serializes seeds of each type
+fn value_bytes(expr: TokenStream, ty: &IdlType) -> Option<TokenStream> {
+    match ty {
+        IdlType::Primitive(name) => match name.as_str() {
+            "pubkey" | "publicKey" => Some(quote! { #expr.as_ref() }),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128" => {
+                Some(quote! { &#expr.to_le_bytes() })
+            }
+            "bool" => Some(quote! { &[#expr as u8] }),
+            "string" => Some(quote! { #expr.as_bytes() }),
+            "bytes" => Some(quote! { #expr.as_slice() }),
+            _ => None,
+        },
+        IdlType::Array { array } if matches!(&*array.0, IdlType::Primitive(ty) if ty == "u8") => {
+            Some(quote! { &#expr[..] })
+        }
+        _ => None,
+    }
+}
+
+/// Field of the instruction accounts struct for the account at `path`
+fn account_field<'a>(
+    path: &str,
+    ix: &IdlInstruction,
+    account_names: &'a [syn::Ident],
+) -> Option<&'a syn::Ident> {
+    let path = path.to_case(Case::Snake);
+    let index = ix
+        .accounts
+        .iter()
+        .position(|acc| acc.name.to_case(Case::Snake) == path)?;
+    // Optional accounts may be absent, so they cannot be seeds
+    if ix.accounts[index].optional {
+        return None;
+    }
+    Some(&account_names[index])
+}
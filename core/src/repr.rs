@@ -0,0 +1,208 @@
+use crate::generator::{idl_type_to_rust, GenerateOptions};
+use crate::parser::{IdlEnumVariant, IdlEnumVariantFields, IdlTypeDefType};
+use crate::sdk;
+use convert_case::{Case, Casing};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+/// Whether the enum's `repr` declares a tag wider than the byte borsh's derives write
+pub fn has_wide_tag(ty: &IdlTypeDefType) -> bool {
+    ty.kind == "enum" && ty.tag_repr() != "u8"
+}
+
+/// Borsh impls, and with the `schema` option a `BorshSchema` impl, for an enum with a wide tag
+///
+/// The variant index is written as the little-endian integer of the enum's
+/// `repr` instead of a byte. Panics if the enum has more variants than its
+/// tag can address, or if it or its fields take borsh attributes, which only
+/// the derives understand.
+pub fn wide_tag_impls(name: &str, ty: &IdlTypeDefType, options: &GenerateOptions) -> TokenStream {
+    if ty.variants.len() as u128 > ty.max_variants() {
+        panic!(
+            "Enum {} has {} variants, more than its {} tag can address",
+            name,
+            ty.variants.len(),
+            ty.tag_repr()
+        );
+    }
+    let has_borsh_attrs = ty.borsh.is_some()
+        || ty.variants.iter().any(|variant| match &variant.fields {
+            Some(IdlEnumVariantFields::Named(fields)) => fields
+                .iter()
+                .any(|field| field.borsh.is_some() || field.len.is_some()),
+            _ => false,
+        });
+    if has_borsh_attrs {
+        panic!(
+            "Enum {} has a {} repr and cannot take borsh attributes",
+            name,
+            ty.tag_repr()
+        );
+    }
+
+    let enum_name = format_ident!("{}", name);
+    let tag = format_ident!("{}", ty.tag_repr());
+    let io = sdk::io_module(options.no_std);
+    let alloc = sdk::alloc_crate(options.no_std);
+
+    let serialize_arms = ty.variants.iter().enumerate().map(|(index, variant)| {
+        let index = Literal::usize_unsuffixed(index);
+        let (pattern, fields) = variant_bindings(variant);
+        quote! {
+            Self::#pattern => {
+                ::borsh::BorshSerialize::serialize(&(#index as #tag), writer)?;
+                #(::borsh::BorshSerialize::serialize(#fields, writer)?;)*
+            }
+        }
+    });
+    let deserialize_arms = ty.variants.iter().enumerate().map(|(index, variant)| {
+        let index = Literal::usize_unsuffixed(index);
+        let variant_name = format_ident!("{}", variant.name);
+        let read = quote! { ::borsh::BorshDeserialize::deserialize_reader(reader)? };
+        let value = match &variant.fields {
+            Some(IdlEnumVariantFields::Named(fields)) => {
+                let names = fields
+                    .iter()
+                    .map(|f| format_ident!("{}", f.name.to_case(Case::Snake)));
+                quote! { Self::#variant_name { #(#names: #read,)* } }
+            }
+            Some(IdlEnumVariantFields::Tuple(tys)) => {
+                let reads = tys.iter().map(|_| &read);
+                quote! { Self::#variant_name(#(#reads,)*) }
+            }
+            None => quote! { Self::#variant_name },
+        };
+        quote! { #index => #value }
+    });
+    let schema = schema_impl(name, ty, options);
+
+    quote! {
+        impl ::borsh::BorshSerialize for #enum_name {
+            fn serialize<W: #io::Write>(&self, writer: &mut W) -> #io::Result<()> {
+                match self {
+                    #(#serialize_arms)*
+                }
+                Ok(())
+            }
+        }
+
+        impl ::borsh::BorshDeserialize for #enum_name {
+            fn deserialize_reader<R: #io::Read>(reader: &mut R) -> #io::Result<Self> {
+                let tag = <#tag as ::borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                Ok(match tag {
+                    #(#deserialize_arms,)*
+                    _ => {
+                        return Err(#io::Error::new(
+                            #io::ErrorKind::InvalidData,
+                            #alloc::format!("Unexpected variant tag: {:?}", tag),
+                        ))
+                    }
+                })
+            }
+        }
+
+        #schema
+    }
+}
+
+/// Pattern binding every field of `variant`, and the bindings in encoding order
+fn variant_bindings(variant: &IdlEnumVariant) -> (TokenStream, Vec<syn::Ident>) {
+    let variant_name = format_ident!("{}", variant.name);
+    match &variant.fields {
+        Some(IdlEnumVariantFields::Named(fields)) => {
+            let names: Vec<syn::Ident> = fields
+                .iter()
+                .map(|f| format_ident!("{}", f.name.to_case(Case::Snake)))
+                .collect();
+            (quote! { #variant_name { #(#names),* } }, names)
+        }
+        Some(IdlEnumVariantFields::Tuple(tys)) => {
+            let names: Vec<syn::Ident> = (0..tys.len()).map(|i| format_ident!("f{}", i)).collect();
+            (quote! { #variant_name(#(#names),*) }, names)
+        }
+        None => (quote! { #variant_name }, Vec::new()),
+    }
+}
+
+/// `BorshSchema` impl declaring the enum's tag width, like the derive does for one-byte tags
+///
+/// Each variant is described by a struct named after the enum and the variant,
+/// as the derive names them.
+fn schema_impl(name: &str, ty: &IdlTypeDefType, options: &GenerateOptions) -> TokenStream {
+    if !options.schema {
+        return quote! {};
+    }
+
+    let enum_name = format_ident!("{}", name);
+    let alloc = sdk::alloc_crate(options.no_std);
+    let tag_width = Literal::usize_unsuffixed(ty.tag_width());
+    let schema = quote! { ::borsh::schema };
+
+    let variants = ty.variants.iter().enumerate().map(|(index, variant)| {
+        let index = Literal::i64_unsuffixed(index as i64);
+        let variant_name = &variant.name;
+        let declaration = format!("{}{}", name, variant.name);
+        quote! { (#index, #alloc::string::ToString::to_string(#variant_name), #alloc::string::ToString::to_string(#declaration)) }
+    });
+    let variant_definitions = ty.variants.iter().map(|variant| {
+        let declaration = format!("{}{}", name, variant.name);
+        let (fields, field_types): (TokenStream, Vec<TokenStream>) = match &variant.fields {
+            Some(IdlEnumVariantFields::Named(fields)) => {
+                let types: Vec<TokenStream> =
+                    fields.iter().map(|f| idl_type_to_rust(&f.ty, options)).collect();
+                let names = fields.iter().map(|f| f.name.to_case(Case::Snake));
+                (
+                    quote! {
+                        #schema::Fields::NamedFields(#alloc::vec![
+                            #((#alloc::string::ToString::to_string(#names), <#types as ::borsh::BorshSchema>::declaration()),)*
+                        ])
+                    },
+                    types,
+                )
+            }
+            Some(IdlEnumVariantFields::Tuple(tys)) => {
+                let types: Vec<TokenStream> =
+                    tys.iter().map(|ty| idl_type_to_rust(ty, options)).collect();
+                (
+                    quote! {
+                        #schema::Fields::UnnamedFields(#alloc::vec![
+                            #(<#types as ::borsh::BorshSchema>::declaration(),)*
+                        ])
+                    },
+                    types,
+                )
+            }
+            None => (quote! { #schema::Fields::Empty }, Vec::new()),
+        };
+        quote! {
+            #schema::add_definition(
+                #alloc::string::ToString::to_string(#declaration),
+                #schema::Definition::Struct { fields: #fields },
+                definitions,
+            );
+            #(<#field_types as ::borsh::BorshSchema>::add_definitions_recursively(definitions);)*
+        }
+    });
+
+    quote! {
+        impl ::borsh::BorshSchema for #enum_name {
+            fn declaration() -> #schema::Declaration {
+                #alloc::string::ToString::to_string(#name)
+            }
+
+            fn add_definitions_recursively(
+                definitions: &mut #alloc::collections::BTreeMap<#schema::Declaration, #schema::Definition>,
+            ) {
+                #schema::add_definition(
+                    Self::declaration(),
+                    #schema::Definition::Enum {
+                        tag_width: #tag_width,
+                        variants: #alloc::vec![#(#variants,)*],
+                    },
+                    definitions,
+                );
+                #(#variant_definitions)*
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::generator::GenerateOptions;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Path of the `Pubkey` type used by generated code
+///
+/// The runtime crate re-exports either the Solana SDK type or its minimal
+/// stand-in, depending on its `minimal-pubkey` feature.
+pub fn pubkey_type() -> TokenStream {
+    quote! { ::solana_idl_parser::Pubkey }
+}
+
+/// Path of the `AccountMeta` type used by generated code
+pub fn account_meta_type() -> TokenStream {
+    quote! { ::solana_idl_parser::AccountMeta }
+}
+
+/// Path of the `Instruction` type used by generated code
+pub fn instruction_type() -> TokenStream {
+    quote! { ::solana_idl_parser::Instruction }
+}
+
+/// Whether the `Pubkey` type can derive PDAs, which the minimal stand-in cannot
+pub fn supports_pda_derivation(options: &GenerateOptions) -> bool {
+    !options.minimal_pubkey
+}
+
+/// Serde derives for generated types, empty unless the `serde` option is set
+pub fn serde_derive(options: &GenerateOptions) -> TokenStream {
+    if options.serde {
+        quote! {
+            #[derive(::solana_idl_parser::serde::Serialize, ::solana_idl_parser::serde::Deserialize)]
+            #[serde(crate = "::solana_idl_parser::serde")]
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// `BorshSchema` derive for generated types, empty unless the `schema` option is set
+pub fn schema_derive(options: &GenerateOptions) -> TokenStream {
+    if options.schema {
+        quote! { #[derive(::borsh::BorshSchema)] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Crate providing `Vec`, `String` and the B-tree collections, `alloc` in `no_std` mode
+pub fn alloc_crate(no_std: bool) -> TokenStream {
+    if no_std {
+        quote! { ::alloc }
+    } else {
+        quote! { ::std }
+    }
+}
+
+/// Module providing the I/O error type, borsh's own in `no_std` mode
+pub fn io_module(no_std: bool) -> TokenStream {
+    if no_std {
+        quote! { ::borsh::io }
+    } else {
+        quote! { ::std::io }
+    }
+}
+
+/// Path of the runtime error decoding failures are built as
+pub fn decode_error_type() -> TokenStream {
+    quote! { ::solana_idl_parser::DecodeError }
+}
+
+/// Expression building a `DecodeError` from an I/O error kind and message
+pub fn decode_error(options: &GenerateOptions, kind: &str, message: TokenStream) -> TokenStream {
+    let io = io_module(options.no_std);
+    let decode_error = decode_error_type();
+    let kind = quote::format_ident!("{}", kind);
+    quote! { #decode_error::from(#io::Error::new(#io::ErrorKind::#kind, #message)) }
+}
+
+/// Error type of fallible generated functions
+///
+/// The `error` option names it, otherwise it is `anyhow::Error`, or borsh's
+/// I/O error in `no_std` mode since `anyhow` needs `std`. Each must convert
+/// from `DecodeError`.
+pub fn error_type(options: &GenerateOptions) -> TokenStream {
+    match &options.error {
+        Some(path) => {
+            let path: syn::Path = syn::parse_str(path)
+                .unwrap_or_else(|e| panic!("Invalid error type {:?}: {}", path, e));
+            quote! { #path }
+        }
+        None if options.no_std => quote! { ::borsh::io::Error },
+        None => quote! { ::anyhow::Error },
+    }
+}
+
+/// Result type of fallible generated functions, with the error of `error_type`
+pub fn result_type(options: &GenerateOptions, ok: TokenStream) -> TokenStream {
+    let error = error_type(options);
+    quote! { ::core::result::Result<#ok, #error> }
+}
+
+/// Attribute keeping a field out of serde, empty unless the `serde` option is set
+pub fn serde_skip_attr(options: &GenerateOptions) -> TokenStream {
+    if options.serde {
+        quote! { #[serde(skip)] }
+    } else {
+        quote! {}
+    }
+}
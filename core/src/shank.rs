@@ -0,0 +1,49 @@
+use crate::parser::{Idl, IdlFormat};
+
+/// Detect the format of an IDL that was not given an explicit `format` option
+pub fn detect_format(idl: &Idl) -> IdlFormat {
+    match idl.metadata.origin.as_deref() {
+        Some("shank") => IdlFormat::Shank,
+        _ => IdlFormat::Anchor,
+    }
+}
+
+/// Map the Shank-specific fields of an IDL onto the Anchor layout
+///
+/// Each instruction's `discriminant` becomes its `discriminator`, encoded
+/// little-endian with the width of the declared integer type.
+///
+/// The other Shank fields need no rewriting: `isMut`/`isSigner`/`isOptional`,
+/// `publicKey` and string `defined` types deserialize through serde aliases
+/// in [`crate::parser`], and `metadata.address` is read by `load`. Shank
+/// accounts have no discriminator, so their decoders read the account data
+/// from the first byte.
+pub fn normalize(idl: &mut Idl) {
+    for ix in &mut idl.instructions {
+        let Some(discriminant) = &ix.discriminant else {
+            continue;
+        };
+        if !ix.discriminator.is_empty() {
+            continue;
+        }
+
+        let bytes = discriminant.value.to_le_bytes();
+        let width = match discriminant.ty.as_str() {
+            "u8" => 1,
+            "u16" => 2,
+            "u32" => 4,
+            "u64" => 8,
+            other => panic!(
+                "Unsupported discriminant type {:?} for instruction {}",
+                other, ix.name
+            ),
+        };
+        if bytes[width..].iter().any(|b| *b != 0) {
+            panic!(
+                "Discriminant {} of instruction {} does not fit in {}",
+                discriminant.value, ix.name, discriminant.ty
+            );
+        }
+        ix.discriminator = bytes[..width].to_vec();
+    }
+}
@@ -0,0 +1,160 @@
+use crate::parser::{
+    Idl, IdlInstructionAccount, IdlSeed, IdlTypeDef, IdlTypeDefFields, IdlTypeDefType,
+};
+use convert_case::{Case, Casing};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+/// Whether an IDL of the given `metadata.spec` carries explicit discriminators
+///
+/// Anchor writes them from spec `0.1.0` (Anchor 0.30) on. Older IDLs have no
+/// `spec` at all.
+pub fn has_explicit_discriminators(spec: Option<&str>) -> bool {
+    spec.and_then(parse_version)
+        .is_some_and(|version| version >= (0, 1, 0))
+}
+
+/// Fill in or check discriminators according to the IDL spec
+///
+/// Legacy IDLs get Anchor's sighash discriminators: the first 8 bytes of
+/// `sha256("global:<snake_case_name>")` for instructions, `"account:<Name>"`
+/// for accounts and `"event:<Name>"` for events. Newer IDLs must carry every
+/// discriminator explicitly.
+pub fn apply_spec_defaults(idl: &mut Idl) {
+    let spec = idl.metadata.spec.clone();
+
+    if has_explicit_discriminators(spec.as_deref()) {
+        let spec = spec.unwrap_or_default();
+        if let Some(ix) = idl
+            .instructions
+            .iter()
+            .find(|ix| ix.discriminator.is_empty())
+        {
+            panic!(
+                "Instruction {} has no discriminator (IDL spec {})",
+                ix.name, spec
+            );
+        }
+        if let Some(acc) = idl.accounts.iter().find(|acc| acc.discriminator.is_empty()) {
+            panic!(
+                "Account {} has no discriminator (IDL spec {})",
+                acc.name, spec
+            );
+        }
+        return;
+    }
+
+    for ix in &mut idl.instructions {
+        if ix.discriminator.is_empty() {
+            ix.discriminator = sighash("global", &ix.name.to_case(Case::Snake));
+        }
+    }
+    for acc in &mut idl.accounts {
+        if acc.discriminator.is_empty() {
+            acc.discriminator = sighash("account", &acc.name);
+        }
+    }
+    for event in &mut idl.events {
+        if event.discriminator.is_empty() {
+            event.discriminator = sighash("event", &event.name);
+        }
+    }
+}
+
+/// Declare events with inline fields in `types`, as newer IDLs do
+///
+/// Each becomes a struct type named after the event. Inline fields take
+/// precedence over a type of the same name.
+pub fn inline_event_types(idl: &mut Idl) {
+    for event in &idl.events {
+        let Some(fields) = &event.fields else {
+            continue;
+        };
+        let ty = IdlTypeDefType {
+            kind: "struct".to_string(),
+            fields: IdlTypeDefFields::Named(fields.clone()),
+            variants: Vec::new(),
+            alias: None,
+            field_order: None,
+            borsh: None,
+            repr: None,
+        };
+        match idl
+            .types
+            .iter_mut()
+            .find(|typedef| typedef.name == event.name)
+        {
+            Some(typedef) => typedef.ty = ty,
+            None => idl.types.push(IdlTypeDef {
+                name: event.name.clone(),
+                ty,
+                generics: Vec::new(),
+            }),
+        }
+    }
+}
+
+/// Replace the nested account groups of instructions with their accounts, in order
+///
+/// Anchor writes composite account structs as `{ "name": ..., "accounts": [...] }`.
+/// Their accounts are named `<group>_<account>`, and PDA seeds and relations
+/// naming a sibling in the same group are renamed along.
+pub fn flatten_account_groups(idl: &mut Idl) {
+    for ix in &mut idl.instructions {
+        if ix.accounts.iter().any(|acc| !acc.accounts.is_empty()) {
+            ix.accounts = flatten_accounts(std::mem::take(&mut ix.accounts));
+        }
+    }
+}
+
+fn flatten_accounts(accounts: Vec<IdlInstructionAccount>) -> Vec<IdlInstructionAccount> {
+    let mut flat = Vec::new();
+    for account in accounts {
+        if account.accounts.is_empty() {
+            flat.push(account);
+            continue;
+        }
+        let group = account.name;
+        let members = flatten_accounts(account.accounts);
+        let siblings: HashSet<String> = members.iter().map(|acc| acc.name.clone()).collect();
+        let prefixed = |name: &mut String| {
+            let first = name.split('.').next().unwrap_or_default();
+            if siblings.contains(first) {
+                *name = format!("{}_{}", group, name);
+            }
+        };
+
+        for mut member in members {
+            member.name = format!("{}_{}", group, member.name);
+            member.relations.iter_mut().for_each(prefixed);
+            if let Some(pda) = &mut member.pda {
+                for seed in pda.seeds.iter_mut().chain(&mut pda.program) {
+                    if let IdlSeed::Account { path, .. } = seed {
+                        prefixed(path);
+                    }
+                }
+            }
+            flat.push(member);
+        }
+    }
+    flat
+}
+
+/// First 8 bytes of `sha256("<namespace>:<name>")`
+fn sighash(namespace: &str, name: &str) -> Vec<u8> {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name));
+    hash[..8].to_vec()
+}
+
+/// Parse a `major.minor.patch` version, ignoring any pre-release suffix
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version
+        .split(['-', '+'])
+        .next()?
+        .split('.')
+        .map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, patch))
+}
@@ -0,0 +1,289 @@
+use crate::generator::{
+    args_struct_name, has_instruction_code, legacy_discriminator_bytes, program_type_name,
+    GenerateOptions,
+};
+use crate::parser::{
+    Idl, IdlEnumVariantFields, IdlRepr, IdlType, IdlTypeDefFields, PRIMITIVE_TYPES, TAG_REPRS,
+};
+use convert_case::{Case, Casing};
+use std::collections::{HashMap, HashSet};
+
+/// Anchor's layout reprs, which leave an enum's tag at a single byte
+const NON_TAG_REPRS: &[&str] = &["rust", "c", "transparent"];
+
+/// Check that the IDL is consistent with itself, returning every problem found
+///
+/// Type references must resolve to a type or an account with an inline type,
+/// names must be unique, discriminators must be distinct and 8 bytes long, or
+/// 1 byte for native programs tagging instructions with their first byte, and
+/// enums must fit their variant index in their tag, a byte unless `repr`
+/// declares a wider integer. Shank discriminators come
+/// from `discriminant` and may have any width. Items generated for
+/// instructions and the program must not take the name of a type.
+pub fn validate(idl: &Idl, options: &GenerateOptions) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let mut defined: HashSet<&str> = idl.types.iter().map(|t| t.name.as_str()).collect();
+    defined.extend(
+        idl.accounts
+            .iter()
+            .filter(|acc| acc.ty.is_some())
+            .map(|acc| acc.name.as_str()),
+    );
+
+    check_unique(
+        "instruction",
+        "",
+        idl.instructions.iter().map(|ix| ix.name.as_str()),
+        &mut problems,
+    );
+    check_unique(
+        "type",
+        "",
+        idl.types.iter().map(|t| t.name.as_str()),
+        &mut problems,
+    );
+    check_unique(
+        "account",
+        "",
+        idl.accounts.iter().map(|acc| acc.name.as_str()),
+        &mut problems,
+    );
+    check_unique(
+        "constant",
+        "",
+        idl.constants.iter().map(|c| c.name.as_str()),
+        &mut problems,
+    );
+
+    let defs = idl.types.iter().map(|t| (&t.name, &t.ty)).chain(
+        idl.accounts
+            .iter()
+            .filter_map(|acc| Some((&acc.name, acc.ty.as_ref()?))),
+    );
+    for (name, ty) in defs {
+        let place = format!("type `{}`", name);
+        for field_ty in ty.field_types() {
+            check_references(field_ty, &defined, &place, &mut problems);
+        }
+        if let IdlTypeDefFields::Named(fields) = &ty.fields {
+            let within = format!(" in {}", place);
+            check_unique(
+                "field",
+                &within,
+                fields.iter().map(|f| f.name.as_str()),
+                &mut problems,
+            );
+        }
+        if ty.kind == "enum" {
+            let within = format!(" in {}", place);
+            check_unique(
+                "variant",
+                &within,
+                ty.variants.iter().map(|v| v.name.as_str()),
+                &mut problems,
+            );
+            if let Some(repr) = ty.repr.as_ref().map(IdlRepr::kind) {
+                if !TAG_REPRS.contains(&repr) && !NON_TAG_REPRS.contains(&repr) {
+                    problems.push(format!("enum `{}` has unsupported repr `{}`", name, repr));
+                }
+            }
+            if ty.variants.len() as u128 > ty.max_variants() {
+                problems.push(format!(
+                    "enum `{}` has {} variants, more than the {} a {} variant tag can address; declare a wider `repr` such as `u16`",
+                    name,
+                    ty.variants.len(),
+                    ty.max_variants(),
+                    ty.tag_repr()
+                ));
+            }
+            for variant in &ty.variants {
+                if let Some(IdlEnumVariantFields::Named(fields)) = &variant.fields {
+                    let within = format!(" in variant `{}` of {}", variant.name, place);
+                    check_unique(
+                        "field",
+                        &within,
+                        fields.iter().map(|f| f.name.as_str()),
+                        &mut problems,
+                    );
+                }
+            }
+        }
+    }
+
+    let mut discriminators: HashMap<Vec<u8>, &str> = HashMap::new();
+    for ix in &idl.instructions {
+        let place = format!("instruction `{}`", ix.name);
+        for arg in &ix.args {
+            check_references(&arg.ty, &defined, &place, &mut problems);
+        }
+        let len = ix.discriminator.len();
+        if ix.discriminant.is_none() && len != 8 && len != 1 {
+            problems.push(format!(
+                "{} has a {}-byte discriminator, expected 8 bytes (or 1 for native programs)",
+                place, len
+            ));
+        }
+        let legacy = legacy_discriminator_bytes(ix, options);
+        for discriminator in std::iter::once(ix.discriminator.clone()).chain(legacy) {
+            if let Some(other) = discriminators.insert(discriminator.clone(), &ix.name) {
+                problems.push(format!(
+                    "instructions `{}` and `{}` have the same discriminator {:?}",
+                    other, ix.name, discriminator
+                ));
+            }
+        }
+    }
+
+    for constant in &idl.constants {
+        let place = format!("constant `{}`", constant.name);
+        check_references(&constant.ty, &defined, &place, &mut problems);
+    }
+
+    check_generated_names(idl, options, &mut problems);
+
+    problems
+}
+
+/// Record a problem for each generated type name claimed by two items
+///
+/// Types keep their IDL names, while instructions get `{Name}Accounts` and
+/// `{Name}Args` structs, or modules with `instruction_modules`, and the program
+/// an instructions enum and its companions, e.g. a type `SwapAccounts` clashes
+/// with the accounts of `swap`.
+fn check_generated_names(idl: &Idl, options: &GenerateOptions, problems: &mut Vec<String>) {
+    // Duplicate types are reported on their own
+    let mut seen_types = HashSet::new();
+    let mut items: Vec<(String, String)> = idl
+        .types
+        .iter()
+        .filter(|t| seen_types.insert(t.name.as_str()))
+        .map(|t| (t.name.clone(), format!("type `{}`", t.name)))
+        .collect();
+
+    let program = program_type_name(idl, options);
+    let has_events_enum = idl
+        .events
+        .iter()
+        .any(|event| seen_types.contains(event.name.as_str()));
+    if has_events_enum {
+        items.push((format!("{}Events", program), "the program".to_string()));
+    }
+    let instruction_code = !options.types_only && has_instruction_code(idl);
+    if instruction_code && options.instruction_modules {
+        // Modules share the namespace of types, and their items shadow the types they import
+        for ix in idl.instructions.iter().chain(&idl.skipped_instructions) {
+            items.push((
+                ix.name.to_case(Case::Snake),
+                format!("the module of instruction `{}`", ix.name),
+            ));
+        }
+        for shadowed in ["Accounts", "Args"] {
+            if seen_types.contains(shadowed) {
+                problems.push(format!(
+                    "type `{}` is shadowed inside instruction modules by their own `{}`",
+                    shadowed, shadowed
+                ));
+            }
+        }
+    } else if instruction_code {
+        for ix in &idl.instructions {
+            if !ix.accounts.is_empty() {
+                items.push((
+                    format!("{}Accounts", ix.name.to_case(Case::Pascal)),
+                    format!("the accounts of instruction `{}`", ix.name),
+                ));
+            }
+            if !ix.args.is_empty() {
+                items.push((
+                    args_struct_name(ix, &idl.types).to_string(),
+                    format!("the args of instruction `{}`", ix.name),
+                ));
+            }
+        }
+    }
+    if instruction_code {
+        for suffix in ["Instructions", "Visitor", "InstructionKind"] {
+            items.push((format!("{}{}", program, suffix), "the program".to_string()));
+        }
+    }
+
+    let mut claimed: HashMap<&str, &str> = HashMap::new();
+    for (name, owner) in &items {
+        match claimed.get(name.as_str()) {
+            Some(other) => problems.push(format!(
+                "`{}` would be generated for both {} and {}",
+                name, other, owner
+            )),
+            None => {
+                claimed.insert(name, owner);
+            }
+        }
+    }
+}
+
+/// Record a problem for each name that appears more than once
+///
+/// `within` describes where the names come from, e.g. ` in type `Pool``.
+fn check_unique<'a>(
+    what: &str,
+    within: &str,
+    names: impl Iterator<Item = &'a str>,
+    problems: &mut Vec<String>,
+) {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    for name in names {
+        if !seen.insert(name) && reported.insert(name) {
+            problems.push(format!("duplicate {} `{}`{}", what, name, within));
+        }
+    }
+}
+
+/// Record a problem for each type name in `ty` that is neither primitive nor defined
+fn check_references(
+    ty: &IdlType,
+    defined: &HashSet<&str>,
+    place: &str,
+    problems: &mut Vec<String>,
+) {
+    let mut check_name = |name: &str| {
+        if !PRIMITIVE_TYPES.contains(&name) && !defined.contains(name) {
+            problems.push(format!("{} refers to unknown type `{}`", place, name));
+        }
+    };
+
+    match ty {
+        IdlType::Primitive(name) => check_name(name),
+        IdlType::Defined { defined: name } => {
+            check_name(name.name());
+            for ty in name.generic_types() {
+                check_references(ty, defined, place, problems);
+            }
+        }
+        IdlType::Option { option: inner }
+        | IdlType::Vec { vec: inner }
+        | IdlType::HashSet { hash_set: inner }
+        | IdlType::BTreeSet { btree_set: inner } => {
+            check_references(inner, defined, place, problems)
+        }
+        IdlType::Array { array } => check_references(&array.0, defined, place, problems),
+        IdlType::Tuple { tuple } => {
+            for ty in tuple {
+                check_references(ty, defined, place, problems);
+            }
+        }
+        IdlType::HashMap {
+            hash_map: (key, value),
+        }
+        | IdlType::BTreeMap {
+            btree_map: (key, value),
+        } => {
+            check_references(key, defined, place, problems);
+            check_references(value, defined, place, problems);
+        }
+        // Type parameters are checked by the compiler; unsupported types are
+        // rejected or skipped when loading
+        IdlType::Generic { .. } | IdlType::Unsupported(_) => {}
+    }
+}
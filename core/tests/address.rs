@@ -0,0 +1,42 @@
+use solana_idl_parser_core::{parse_idl_str, LoadOptions};
+
+fn load(address: &str, metadata_address: &str) -> anyhow::Result<solana_idl_parser_core::Idl> {
+    let json = format!(
+        r#"{{
+            {}
+            "metadata": {{ "name": "counter", "version": "0.1.0", "spec": "0.1.0" {} }},
+            "instructions": []
+        }}"#,
+        address, metadata_address
+    );
+    parse_idl_str(&json, &LoadOptions::default())
+}
+
+#[test]
+fn metadata_address_is_used_without_a_top_level_address() {
+    let idl = load(
+        "",
+        r#", "address": "Stake11111111111111111111111111111111111111""#,
+    )
+    .unwrap();
+    assert_eq!(idl.address, "Stake11111111111111111111111111111111111111");
+}
+
+#[test]
+fn top_level_address_takes_precedence() {
+    let idl = load(
+        r#""address": "Vote111111111111111111111111111111111111111","#,
+        r#", "address": "Stake11111111111111111111111111111111111111""#,
+    )
+    .unwrap();
+    assert_eq!(idl.address, "Vote111111111111111111111111111111111111111");
+}
+
+#[test]
+fn missing_address_is_an_error() {
+    let err = load("", "").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot determine the program address: the IDL has neither `address` nor `metadata.address`"
+    );
+}
@@ -0,0 +1,65 @@
+use solana_idl_parser_core::parser::MAX_TYPE_DEPTH;
+use solana_idl_parser_core::{generate_idl_code, parse_idl_str, GenerateOptions, LoadOptions};
+
+/// An IDL with one instruction arg of `depth` nested vecs around a `u8`
+fn nested_vec_idl(depth: usize) -> String {
+    let ty = format!(
+        "{}\"u8\"{}",
+        r#"{ "vec": "#.repeat(depth - 1),
+        " }".repeat(depth - 1)
+    );
+    format!(
+        r#"{{
+            "address": "11111111111111111111111111111111",
+            "metadata": {{ "name": "nested", "version": "0.1.0", "spec": "0.1.0" }},
+            "instructions": [{{
+                "name": "deep",
+                "discriminator": [0, 0, 0, 0, 0, 0, 0, 0],
+                "accounts": [],
+                "args": [{{ "name": "value", "type": {} }}]
+            }}]
+        }}"#,
+        ty
+    )
+}
+
+#[test]
+fn types_at_the_depth_limit_are_generated() {
+    let idl = parse_idl_str(&nested_vec_idl(MAX_TYPE_DEPTH), &LoadOptions::default()).unwrap();
+    generate_idl_code(&idl, &GenerateOptions::default());
+}
+
+#[test]
+fn types_past_the_depth_limit_are_an_error() {
+    let err =
+        parse_idl_str(&nested_vec_idl(MAX_TYPE_DEPTH + 1), &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "type in arg `value` of instruction `deep` is nested deeper than the limit of 64 levels"
+    );
+}
+
+#[test]
+fn pathologically_deep_types_are_an_error_without_overflowing() {
+    let options = LoadOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    assert!(parse_idl_str(&nested_vec_idl(100_000), &options).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Type alias Chain refers to itself (Chain -> Ring -> Chain)")]
+fn recursive_type_aliases_are_rejected() {
+    let json = r#"{
+        "address": "11111111111111111111111111111111",
+        "metadata": { "name": "aliases", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [],
+        "types": [
+            { "name": "Ring", "type": { "kind": "type", "alias": { "vec": { "defined": { "name": "Chain" } } } } },
+            { "name": "Chain", "type": { "kind": "type", "alias": { "option": { "defined": { "name": "Ring" } } } } }
+        ]
+    }"#;
+    let idl = parse_idl_str(json, &LoadOptions::default()).unwrap();
+    generate_idl_code(&idl, &GenerateOptions::default());
+}
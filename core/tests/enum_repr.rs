@@ -0,0 +1,58 @@
+use solana_idl_parser_core::{generate_idl_code, parse_idl_str, GenerateOptions, LoadOptions};
+
+/// IDL with an enum of `count` variants and a `u16` repr, the last one carrying a `u64`
+fn wide_enum_idl(count: usize) -> String {
+    let mut variants: Vec<String> = (0..count - 1)
+        .map(|i| format!(r#"{{ "name": "Op{}" }}"#, i))
+        .collect();
+    variants.push(r#"{ "name": "Last", "fields": ["u64"] }"#.to_string());
+    format!(
+        r#"{{
+            "address": "11111111111111111111111111111111",
+            "metadata": {{ "name": "wide", "version": "0.1.0", "spec": "0.1.0" }},
+            "instructions": [],
+            "types": [{{
+                "name": "Opcode",
+                "type": {{ "kind": "enum", "repr": {{ "kind": "u16" }}, "variants": [{}] }}
+            }}]
+        }}"#,
+        variants.join(", ")
+    )
+}
+
+fn generate(json: &str) -> String {
+    let idl = parse_idl_str(json, &LoadOptions::default()).unwrap();
+    generate_idl_code(&idl, &GenerateOptions::default()).to_string()
+}
+
+#[test]
+fn enums_past_256_variants_use_two_byte_tags() {
+    let code = generate(&wide_enum_idl(300));
+
+    // Variant 299 does not fit a byte, so a one-byte tag would wrap around to 43
+    assert!(
+        code.contains("Self :: Last (f0) => { :: borsh :: BorshSerialize :: serialize (& (299 as u16) , writer) ?"),
+        "{}",
+        code
+    );
+    assert!(
+        code.contains("< u16 as :: borsh :: BorshDeserialize > :: deserialize_reader (reader) ?")
+    );
+    assert!(code.contains(
+        "299 => Self :: Last (:: borsh :: BorshDeserialize :: deserialize_reader (reader) ? ,)"
+    ));
+    assert!(code.contains("256 => Self :: Op256"));
+    // The borsh derives, which write a one-byte tag, are left out
+    assert!(!code.contains(":: borsh :: BorshDeserialize , :: borsh :: BorshSerialize"));
+}
+
+#[test]
+#[should_panic(expected = "Enum Opcode has 65537 variants, more than its u16 tag can address")]
+fn enums_past_their_tag_width_are_rejected() {
+    let idl = parse_idl_str(&wide_enum_idl(65537), &LoadOptions::default()).unwrap();
+    let options = GenerateOptions {
+        validate: false,
+        ..Default::default()
+    };
+    generate_idl_code(&idl, &options);
+}
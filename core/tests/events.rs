@@ -0,0 +1,42 @@
+use solana_idl_parser_core::parser::IdlTypeDefFields;
+use solana_idl_parser_core::{parse_idl_str, LoadOptions};
+
+fn field_names(idl: &solana_idl_parser_core::Idl, ty: &str) -> Vec<String> {
+    let typedef = idl.types.iter().find(|t| t.name == ty).unwrap();
+    let IdlTypeDefFields::Named(fields) = &typedef.ty.fields else {
+        panic!("expected named fields");
+    };
+    fields.iter().map(|f| f.name.clone()).collect()
+}
+
+#[test]
+fn inline_event_fields_become_types() {
+    let json = r#"{
+        "address": "11111111111111111111111111111111",
+        "metadata": { "name": "auction", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [],
+        "events": [
+            {
+                "name": "BidPlaced",
+                "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+                "fields": [{ "name": "amount", "type": "u64", "index": false }]
+            },
+            {
+                "name": "Refunded",
+                "discriminator": [8, 7, 6, 5, 4, 3, 2, 1],
+                "fields": [{ "name": "lamports", "type": "u64", "index": false }]
+            }
+        ],
+        "types": [
+            {
+                "name": "Refunded",
+                "type": { "kind": "struct", "fields": [{ "name": "stale", "type": "u8" }] }
+            }
+        ]
+    }"#;
+    let idl = parse_idl_str(json, &LoadOptions::default()).unwrap();
+
+    assert_eq!(field_names(&idl, "BidPlaced"), ["amount"]);
+    assert_eq!(field_names(&idl, "Refunded"), ["lamports"]);
+    assert_eq!(idl.types.len(), 2);
+}
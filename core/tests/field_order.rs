@@ -0,0 +1,52 @@
+use solana_idl_parser_core::parser::IdlTypeDefFields;
+use solana_idl_parser_core::{parse_idl_str, LoadOptions};
+
+fn load(fields: &str, order: &str) -> anyhow::Result<solana_idl_parser_core::Idl> {
+    let json = format!(
+        r#"{{
+            "address": "11111111111111111111111111111111",
+            "metadata": {{ "name": "packed", "version": "0.1.0", "spec": "0.1.0" }},
+            "instructions": [],
+            "types": [{{ "name": "Settings", "type": {{ "kind": "struct", "fields": {}, "fieldOrder": {} }} }}]
+        }}"#,
+        fields, order
+    );
+    parse_idl_str(&json, &LoadOptions::default())
+}
+
+const FIELDS: &str = r#"[{ "name": "a", "type": "u8" }, { "name": "b", "type": "u16" }]"#;
+
+#[test]
+fn fields_are_reordered_while_loading() {
+    let idl = load(FIELDS, r#"["b", "a"]"#).unwrap();
+    let IdlTypeDefFields::Named(fields) = &idl.types[0].ty.fields else {
+        panic!("expected named fields");
+    };
+    let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["b", "a"]);
+    assert!(idl.types[0].ty.field_order.is_none());
+}
+
+#[test]
+fn field_order_must_list_every_field_once() {
+    let error = |order: &str| load(FIELDS, order).unwrap_err().to_string();
+
+    assert_eq!(
+        error(r#"["b"]"#),
+        "`fieldOrder` of type `Settings` is missing field `a`"
+    );
+    assert_eq!(
+        error(r#"["b", "a", "a"]"#),
+        "`fieldOrder` of type `Settings` names unknown or repeated field `a`"
+    );
+    assert_eq!(
+        error(r#"["b", "c"]"#),
+        "`fieldOrder` of type `Settings` names unknown or repeated field `c`"
+    );
+    assert_eq!(
+        load(r#"["u8", "u16"]"#, r#"["b"]"#)
+            .unwrap_err()
+            .to_string(),
+        "`fieldOrder` of type `Settings` is only supported on structs with named fields"
+    );
+}
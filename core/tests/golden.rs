@@ -0,0 +1,107 @@
+//! Compares the code generated for the sample IDLs in `tests/idls` with the
+//! snapshots in `core/tests/golden`. Run with `UPDATE_GOLDEN=1` to rewrite
+//! the snapshots after an intended codegen change.
+
+use solana_idl_parser_core::{generate_idl_code, load_idl, GenerateOptions, LoadOptions};
+use std::path::{Path, PathBuf};
+
+/// Options the snapshots are generated with
+///
+/// Every option a Cargo feature of solana_idl_parser sets is spelled out, so
+/// the snapshots are the same whichever features the test is built with.
+fn golden_options() -> GenerateOptions {
+    GenerateOptions {
+        serde: false,
+        fuzz: false,
+        schema: false,
+        test_fixtures: false,
+        minimal_pubkey: false,
+        ..Default::default()
+    }
+}
+
+/// Generated code for the IDL at `idl_path`, formatted with prettyplease
+fn generate_formatted(idl_path: &Path) -> String {
+    let idl = load_idl(idl_path, &LoadOptions::default())
+        .unwrap_or_else(|e| panic!("failed to load {:?}: {:#}", idl_path, e));
+    let tokens = generate_idl_code(&idl, &golden_options());
+    let file = syn::parse2::<syn::File>(tokens)
+        .unwrap_or_else(|e| panic!("generated code for {:?} does not parse: {}", idl_path, e));
+    prettyplease::unparse(&file)
+}
+
+#[test]
+fn generated_code_matches_golden_files() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let idl_dir = manifest_dir.join("../tests/idls");
+    let golden_dir = manifest_dir.join("tests/golden");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut idls: Vec<PathBuf> = std::fs::read_dir(&idl_dir)
+        .expect("failed to read tests/idls")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    idls.sort();
+    assert!(!idls.is_empty(), "no sample IDLs in {:?}", idl_dir);
+
+    let mut mismatches = Vec::new();
+    for idl_path in idls {
+        let generated = generate_formatted(&idl_path);
+        let stem = idl_path.file_stem().unwrap().to_str().unwrap();
+        let golden_path = golden_dir.join(format!("{}.rs", stem));
+
+        if update {
+            std::fs::create_dir_all(&golden_dir).unwrap();
+            std::fs::write(&golden_path, &generated).unwrap();
+            continue;
+        }
+
+        let golden = std::fs::read_to_string(&golden_path).unwrap_or_default();
+        if golden != generated {
+            let line = golden
+                .lines()
+                .zip(generated.lines())
+                .position(|(a, b)| a != b)
+                .unwrap_or_else(|| golden.lines().count().min(generated.lines().count()));
+            mismatches.push(format!("{} (first difference at line {})", stem, line + 1));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "generated code differs from the golden files: {}; rerun with UPDATE_GOLDEN=1 if the change is intended",
+        mismatches.join(", ")
+    );
+}
+
+#[test]
+fn no_std_code_does_not_use_std_or_anyhow() {
+    let idl_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/idls");
+    let options = GenerateOptions {
+        no_std: true,
+        ..Default::default()
+    };
+
+    for entry in std::fs::read_dir(&idl_dir).expect("failed to read tests/idls") {
+        let idl_path = entry.unwrap().path();
+        if idl_path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        // Hash collections need std and are rejected in no_std mode
+        let json = std::fs::read_to_string(&idl_path).unwrap();
+        if json.contains("hashMap") || json.contains("hashSet") {
+            continue;
+        }
+        let idl = load_idl(&idl_path, &LoadOptions::default()).unwrap();
+        let code = generate_idl_code(&idl, &options).to_string();
+        for forbidden in [":: std ::", ":: anyhow ::"] {
+            assert!(
+                !code.contains(forbidden),
+                "no_std code for {:?} uses `{}`",
+                idl_path,
+                forbidden
+            );
+        }
+    }
+}
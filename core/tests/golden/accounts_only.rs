@@ -0,0 +1,75 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 0usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 1usize;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct PriceFeed {
+    pub price: i64,
+    pub exponent: i32,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const PRICE_FEED_LAYOUT: &[(&str, usize, usize)] = &[
+    ("price", 0usize, 8usize),
+    ("exponent", 8usize, 4usize),
+];
+pub const PRICE_FEED_ACCOUNT_DISCRIMINATOR: [u8; 8usize] = [
+    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+impl PriceFeed {
+    /// Program owning accounts of this type
+    pub const OWNER: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    /// Decode account data, ignoring bytes after the struct
+    ///
+    /// Accounts are often allocated larger than their data, so trailing
+    /// padding is accepted even with `deny_trailing_bytes`, which only
+    /// applies to instructions.
+    pub fn from_account_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let (discriminator, mut reader) = data
+            .as_ref()
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "account data is shorter than its discriminator",
+                ),
+            ))?;
+        if *discriminator != PRICE_FEED_ACCOUNT_DISCRIMINATOR {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "account discriminator mismatch",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Self::deserialize(&mut reader)
+            .map_err(|e| ::solana_idl_parser::DecodeError::from(e).into())
+    }
+    /// Account data after the discriminator, without decoding it
+    ///
+    /// `None` if the data does not start with this account's
+    /// discriminator. Useful to cast the body with e.g. `bytemuck`
+    /// or to decode it lazily.
+    pub fn body(data: &[u8]) -> Option<&[u8]> {
+        data.strip_prefix(&PRICE_FEED_ACCOUNT_DISCRIMINATOR[..])
+    }
+}
@@ -0,0 +1,539 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 4usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const PRIMITIVES_DISCRIMINATOR: [u8; 8usize] = [
+    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_primitives_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&PRIMITIVES_DISCRIMINATOR)
+}
+pub const CONTAINERS_DISCRIMINATOR: [u8; 8usize] = [
+    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_containers_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&CONTAINERS_DISCRIMINATOR)
+}
+pub const DEFINED_DISCRIMINATOR: [u8; 8usize] = [3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_defined_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&DEFINED_DISCRIMINATOR)
+}
+pub const PING_DISCRIMINATOR: [u8; 8usize] = [4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_ping_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&PING_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("primitives", &PRIMITIVES_DISCRIMINATOR),
+    ("containers", &CONTAINERS_DISCRIMINATOR),
+    ("defined", &DEFINED_DISCRIMINATOR),
+    ("ping", &PING_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "primitives" => Some(PRIMITIVES_DISCRIMINATOR),
+        "containers" => Some(CONTAINERS_DISCRIMINATOR),
+        "defined" => Some(DEFINED_DISCRIMINATOR),
+        "ping" => Some(PING_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const PRIMITIVES_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct PrimitivesAccounts {
+    pub payer: ::solana_idl_parser::Pubkey,
+}
+impl PrimitivesAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = PRIMITIVES_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != PRIMITIVES_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: PRIMITIVES_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { payer: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.payer, is_signer : true,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); PRIMITIVES_IX_ACCOUNTS_LEN] {
+        [("payer", self.payer)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct PrimitivesArgs {
+    pub flag: bool,
+    pub small: u8,
+    pub medium: u16,
+    pub word: u32,
+    pub large: u64,
+    pub huge: u128,
+    pub signed_small: i8,
+    pub signed_medium: i16,
+    pub signed_word: i32,
+    pub signed_large: i64,
+    pub signed_huge: i128,
+    pub ratio: f32,
+    pub price: f64,
+    pub label: String,
+    pub payload: Vec<u8>,
+    pub owner: ::solana_idl_parser::Pubkey,
+}
+impl PrimitivesArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = PRIMITIVES_DISCRIMINATOR;
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct ContainersArgs {
+    pub maybe_owner: Option<::solana_idl_parser::Pubkey>,
+    pub amounts: Vec<u64>,
+    pub seed: [u8; 4usize],
+    pub keys: [::solana_idl_parser::Pubkey; 2usize],
+    pub pair: (u16, String, ::solana_idl_parser::Pubkey),
+    pub balances: ::std::collections::HashMap<String, u64>,
+    pub ordered: ::std::collections::BTreeMap<::solana_idl_parser::Pubkey, Vec<i32>>,
+    pub tags: ::std::collections::HashSet<u32>,
+    pub members: ::std::collections::BTreeSet<::solana_idl_parser::Pubkey>,
+    pub nested: Vec<Option<[i8; 3usize]>>,
+}
+impl ContainersArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = CONTAINERS_DISCRIMINATOR;
+}
+pub const DEFINED_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct DefinedAccounts {
+    pub config: ::solana_idl_parser::Pubkey,
+}
+impl DefinedAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = DEFINED_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != DEFINED_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: DEFINED_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { config: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.config, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); DEFINED_IX_ACCOUNTS_LEN] {
+        [("config", self.config)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct DefinedArgs {
+    pub settings: Settings,
+    pub action: Action,
+    pub history: Vec<Action>,
+    pub weight: Weight,
+    pub signers: Signers,
+}
+impl DefinedArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = DEFINED_DISCRIMINATOR;
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct Settings {
+    pub admin: ::solana_idl_parser::Pubkey,
+    pub fee: Option<f64>,
+    pub limits: Limits,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct Limits(pub u32, pub u64);
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub enum Action {
+    Idle,
+    Move { to: ::solana_idl_parser::Pubkey, steps: u8 },
+    Configure(Settings),
+}
+pub type Weight = u16;
+pub type Signers = Vec<::solana_idl_parser::Pubkey>;
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllTypesInstructions {
+    Primitives(PrimitivesAccounts, PrimitivesArgs),
+    Containers(ContainersArgs),
+    Defined(DefinedAccounts, DefinedArgs),
+    Ping,
+}
+impl AllTypesInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            PRIMITIVES_DISCRIMINATOR => {
+                Self::Primitives(
+                    PrimitivesAccounts::from_account_metas(accounts)?,
+                    PrimitivesArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            CONTAINERS_DISCRIMINATOR => {
+                Self::Containers(
+                    ContainersArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            DEFINED_DISCRIMINATOR => {
+                Self::Defined(
+                    DefinedAccounts::from_account_metas(accounts)?,
+                    DefinedArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            PING_DISCRIMINATOR => Self::Ping,
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl AllTypesInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for AllTypesInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Primitives(..) => &PRIMITIVES_DISCRIMINATOR,
+            Self::Containers(..) => &CONTAINERS_DISCRIMINATOR,
+            Self::Defined(..) => &DEFINED_DISCRIMINATOR,
+            Self::Ping => &PING_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Primitives(..) => "primitives",
+            Self::Containers(..) => "containers",
+            Self::Defined(..) => "defined",
+            Self::Ping => "ping",
+        }
+    }
+}
+impl ::core::convert::From<(PrimitivesAccounts, PrimitivesArgs)>
+for AllTypesInstructions {
+    fn from(payload: (PrimitivesAccounts, PrimitivesArgs)) -> Self {
+        Self::Primitives(payload.0, payload.1)
+    }
+}
+impl ::core::convert::From<ContainersArgs> for AllTypesInstructions {
+    fn from(payload: ContainersArgs) -> Self {
+        Self::Containers(payload)
+    }
+}
+impl ::core::convert::From<(DefinedAccounts, DefinedArgs)> for AllTypesInstructions {
+    fn from(payload: (DefinedAccounts, DefinedArgs)) -> Self {
+        Self::Defined(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `AllTypesInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<AllTypesInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: AllTypesInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for AllTypesInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Primitives(_, args) => {
+                writer.write_all(&PRIMITIVES_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Containers(args) => {
+                writer.write_all(&CONTAINERS_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Defined(_, args) => {
+                writer.write_all(&DEFINED_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Ping => writer.write_all(&PING_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl AllTypesInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Primitives(accounts, _) => accounts.to_account_metas(),
+            Self::Containers(_) => ::std::vec::Vec::new(),
+            Self::Defined(accounts, _) => accounts.to_account_metas(),
+            Self::Ping => ::std::vec::Vec::new(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Primitives(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::Containers(_) => ::std::vec::Vec::new(),
+            Self::Defined(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::Ping => ::std::vec::Vec::new(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait AllTypesVisitor {
+    ///Called by `accept` for `primitives` instructions
+    fn visit_primitives(
+        &mut self,
+        _accounts: &PrimitivesAccounts,
+        _args: &PrimitivesArgs,
+    ) {}
+    ///Called by `accept` for `containers` instructions
+    fn visit_containers(&mut self, _args: &ContainersArgs) {}
+    ///Called by `accept` for `defined` instructions
+    fn visit_defined(&mut self, _accounts: &DefinedAccounts, _args: &DefinedArgs) {}
+    ///Called by `accept` for `ping` instructions
+    fn visit_ping(&mut self) {}
+}
+impl AllTypesInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: AllTypesVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Primitives(accounts, args) => visitor.visit_primitives(accounts, args),
+            Self::Containers(args) => visitor.visit_containers(args),
+            Self::Defined(accounts, args) => visitor.visit_defined(accounts, args),
+            Self::Ping => visitor.visit_ping(),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AllTypesInstructionKind {
+    ///`primitives`
+    Primitives,
+    ///`containers`
+    Containers,
+    ///`defined`
+    Defined,
+    ///`ping`
+    Ping,
+}
+impl AllTypesInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_primitives_discriminator(data) {
+            return Some(Self::Primitives);
+        }
+        if matches_containers_discriminator(data) {
+            return Some(Self::Containers);
+        }
+        if matches_defined_discriminator(data) {
+            return Some(Self::Defined);
+        }
+        if matches_ping_discriminator(data) {
+            return Some(Self::Ping);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Primitives => "primitives",
+            Self::Containers => "containers",
+            Self::Defined => "defined",
+            Self::Ping => "ping",
+        }
+    }
+}
+impl AllTypesInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> AllTypesInstructionKind {
+        match self {
+            Self::Primitives(..) => AllTypesInstructionKind::Primitives,
+            Self::Containers(..) => AllTypesInstructionKind::Containers,
+            Self::Defined(..) => AllTypesInstructionKind::Defined,
+            Self::Ping => AllTypesInstructionKind::Ping,
+        }
+    }
+}
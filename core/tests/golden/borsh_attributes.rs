@@ -0,0 +1,402 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 1usize;
+pub const FREEZE_DISCRIMINATOR: [u8; 8usize] = [1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_freeze_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&FREEZE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("freeze", &FREEZE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "freeze" => Some(FREEZE_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const FREEZE_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct FreezeAccounts {
+    pub vault: ::solana_idl_parser::Pubkey,
+}
+impl FreezeAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = FREEZE_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != FREEZE_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: FREEZE_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { vault: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.vault, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); FREEZE_IX_ACCOUNTS_LEN] {
+        [("vault", self.vault)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct Vault {
+    pub authority: ::solana_idl_parser::Pubkey,
+    pub state: VaultState,
+    #[borsh(skip)]
+    pub reserved: [u8; 16usize],
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const VAULT_LAYOUT: &[(&str, usize, usize)] = &[
+    ("authority", 0usize, 32usize),
+    ("state", 32usize, 1usize),
+];
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct Position {
+    pub owner: ::solana_idl_parser::Pubkey,
+    #[borsh(skip)]
+    pub cached_value: u64,
+    pub amount: u64,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const POSITION_LAYOUT: &[(&str, usize, usize)] = &[
+    ("owner", 0usize, 32usize),
+    ("amount", 32usize, 8usize),
+];
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+#[borsh(use_discriminant = false)]
+pub enum VaultState {
+    Open,
+    Frozen,
+}
+pub const VAULT_ACCOUNT_DISCRIMINATOR: [u8; 8usize] = [
+    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+impl Vault {
+    /// Program owning accounts of this type
+    pub const OWNER: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    /// Decode account data, ignoring bytes after the struct
+    ///
+    /// Accounts are often allocated larger than their data, so trailing
+    /// padding is accepted even with `deny_trailing_bytes`, which only
+    /// applies to instructions.
+    pub fn from_account_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let (discriminator, mut reader) = data
+            .as_ref()
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "account data is shorter than its discriminator",
+                ),
+            ))?;
+        if *discriminator != VAULT_ACCOUNT_DISCRIMINATOR {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "account discriminator mismatch",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Self::deserialize(&mut reader)
+            .map_err(|e| ::solana_idl_parser::DecodeError::from(e).into())
+    }
+    /// Account data after the discriminator, without decoding it
+    ///
+    /// `None` if the data does not start with this account's
+    /// discriminator. Useful to cast the body with e.g. `bytemuck`
+    /// or to decode it lazily.
+    pub fn body(data: &[u8]) -> Option<&[u8]> {
+        data.strip_prefix(&VAULT_ACCOUNT_DISCRIMINATOR[..])
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaddedInstructions {
+    Freeze(FreezeAccounts),
+}
+impl PaddedInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            FREEZE_DISCRIMINATOR => {
+                Self::Freeze(FreezeAccounts::from_account_metas(accounts)?)
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl PaddedInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for PaddedInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Freeze(..) => &FREEZE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Freeze(..) => "freeze",
+        }
+    }
+}
+impl ::core::convert::From<FreezeAccounts> for PaddedInstructions {
+    fn from(payload: FreezeAccounts) -> Self {
+        Self::Freeze(payload)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `PaddedInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<PaddedInstructions> for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: PaddedInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for PaddedInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Freeze(_) => writer.write_all(&FREEZE_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl PaddedInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Freeze(accounts) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Freeze(accounts) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait PaddedVisitor {
+    ///Called by `accept` for `freeze` instructions
+    fn visit_freeze(&mut self, _accounts: &FreezeAccounts) {}
+}
+impl PaddedInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: PaddedVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Freeze(accounts) => visitor.visit_freeze(accounts),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PaddedInstructionKind {
+    ///`freeze`
+    Freeze,
+}
+impl PaddedInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_freeze_discriminator(data) {
+            return Some(Self::Freeze);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Freeze => "freeze",
+        }
+    }
+}
+impl PaddedInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> PaddedInstructionKind {
+        match self {
+            Self::Freeze(..) => PaddedInstructionKind::Freeze,
+        }
+    }
+}
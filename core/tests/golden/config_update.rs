@@ -0,0 +1,352 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const UPDATE_CONFIG_DISCRIMINATOR: [u8; 8usize] = [
+    29u8, 158u8, 252u8, 191u8, 10u8, 83u8, 219u8, 99u8,
+];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_update_config_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&UPDATE_CONFIG_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("updateConfig", &UPDATE_CONFIG_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "updateConfig" | "update_config" => Some(UPDATE_CONFIG_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const UPDATE_CONFIG_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct UpdateConfigAccounts {
+    pub pool: ::solana_idl_parser::Pubkey,
+}
+impl UpdateConfigAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = UPDATE_CONFIG_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != UPDATE_CONFIG_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: UPDATE_CONFIG_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { pool: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.pool, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); UPDATE_CONFIG_IX_ACCOUNTS_LEN] {
+        [("pool", self.pool)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct UpdateConfigArgs {
+    pub update: ConfigUpdate,
+}
+impl UpdateConfigArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = UPDATE_CONFIG_DISCRIMINATOR;
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub enum ConfigUpdate {
+    SetConfig(PoolConfig),
+    SetFee { fee_bps: u16 },
+    Pause,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct PoolConfig {
+    pub authority: ::solana_idl_parser::Pubkey,
+    pub fee_bps: u16,
+    pub tiers: Vec<u64>,
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigUpdateInstructions {
+    UpdateConfig(UpdateConfigAccounts, UpdateConfigArgs),
+}
+impl ConfigUpdateInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            UPDATE_CONFIG_DISCRIMINATOR => {
+                Self::UpdateConfig(
+                    UpdateConfigAccounts::from_account_metas(accounts)?,
+                    UpdateConfigArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl ConfigUpdateInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for ConfigUpdateInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::UpdateConfig(..) => &UPDATE_CONFIG_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::UpdateConfig(..) => "updateConfig",
+        }
+    }
+}
+impl ::core::convert::From<(UpdateConfigAccounts, UpdateConfigArgs)>
+for ConfigUpdateInstructions {
+    fn from(payload: (UpdateConfigAccounts, UpdateConfigArgs)) -> Self {
+        Self::UpdateConfig(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `ConfigUpdateInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<ConfigUpdateInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: ConfigUpdateInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for ConfigUpdateInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::UpdateConfig(_, args) => {
+                writer.write_all(&UPDATE_CONFIG_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl ConfigUpdateInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::UpdateConfig(accounts, _) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::UpdateConfig(accounts, _) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait ConfigUpdateVisitor {
+    ///Called by `accept` for `updateConfig` instructions
+    fn visit_update_config(
+        &mut self,
+        _accounts: &UpdateConfigAccounts,
+        _args: &UpdateConfigArgs,
+    ) {}
+}
+impl ConfigUpdateInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: ConfigUpdateVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::UpdateConfig(accounts, args) => {
+                visitor.visit_update_config(accounts, args)
+            }
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConfigUpdateInstructionKind {
+    ///`updateConfig`
+    UpdateConfig,
+}
+impl ConfigUpdateInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_update_config_discriminator(data) {
+            return Some(Self::UpdateConfig);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UpdateConfig => "updateConfig",
+        }
+    }
+}
+impl ConfigUpdateInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> ConfigUpdateInstructionKind {
+        match self {
+            Self::UpdateConfig(..) => ConfigUpdateInstructionKind::UpdateConfig,
+        }
+    }
+}
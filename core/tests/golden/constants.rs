@@ -0,0 +1,304 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const MAX_SUPPLY: u64 = 1_000_000_007;
+pub const MIN_PRICE: i64 = -42;
+pub const POOL_SEED: &[u8] = &[112u8, 111u8, 111u8, 108u8];
+pub const VAULT_SEED: &[u8] = &[118u8, 97u8, 117u8, 108u8, 116u8];
+pub const VERSION: &str = "v1";
+pub const MAGIC: [u8; 4usize] = [1u8, 0u8, 0u8, 0u8];
+pub const INITIALIZE_DISCRIMINATOR: [u8; 8usize] = [
+    175u8, 175u8, 109u8, 31u8, 13u8, 152u8, 155u8, 237u8,
+];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_initialize_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&INITIALIZE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("initialize", &INITIALIZE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "initialize" => Some(INITIALIZE_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct InitializeAccounts {
+    pub authority: ::solana_idl_parser::Pubkey,
+}
+impl InitializeAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = INITIALIZE_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != INITIALIZE_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: INITIALIZE_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { authority: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.authority, is_signer : true,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); INITIALIZE_IX_ACCOUNTS_LEN] {
+        [("authority", self.authority)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantsInstructions {
+    Initialize(InitializeAccounts),
+}
+impl ConstantsInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            INITIALIZE_DISCRIMINATOR => {
+                Self::Initialize(InitializeAccounts::from_account_metas(accounts)?)
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl ConstantsInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for ConstantsInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Initialize(..) => &INITIALIZE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Initialize(..) => "initialize",
+        }
+    }
+}
+impl ::core::convert::From<InitializeAccounts> for ConstantsInstructions {
+    fn from(payload: InitializeAccounts) -> Self {
+        Self::Initialize(payload)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `ConstantsInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<ConstantsInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: ConstantsInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for ConstantsInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Initialize(_) => writer.write_all(&INITIALIZE_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl ConstantsInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Initialize(accounts) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Initialize(accounts) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait ConstantsVisitor {
+    ///Called by `accept` for `initialize` instructions
+    fn visit_initialize(&mut self, _accounts: &InitializeAccounts) {}
+}
+impl ConstantsInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: ConstantsVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Initialize(accounts) => visitor.visit_initialize(accounts),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConstantsInstructionKind {
+    ///`initialize`
+    Initialize,
+}
+impl ConstantsInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_initialize_discriminator(data) {
+            return Some(Self::Initialize);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Initialize => "initialize",
+        }
+    }
+}
+impl ConstantsInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> ConstantsInstructionKind {
+        match self {
+            Self::Initialize(..) => ConstantsInstructionKind::Initialize,
+        }
+    }
+}
@@ -0,0 +1,438 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const EXECUTE_DISCRIMINATOR: [u8; 8usize] = [1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_execute_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&EXECUTE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("execute", &EXECUTE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "execute" => Some(EXECUTE_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const EXECUTE_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct ExecuteAccounts {
+    pub machine: ::solana_idl_parser::Pubkey,
+}
+impl ExecuteAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = EXECUTE_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != EXECUTE_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: EXECUTE_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { machine: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.machine, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); EXECUTE_IX_ACCOUNTS_LEN] {
+        [("machine", self.machine)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct ExecuteArgs {
+    pub op: Opcode,
+    pub step: Step,
+}
+impl ExecuteArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = EXECUTE_DISCRIMINATOR;
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Flag {
+    Off,
+    On,
+}
+impl ::borsh::BorshSerialize for Flag {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Off => {
+                ::borsh::BorshSerialize::serialize(&(0 as u32), writer)?;
+            }
+            Self::On => {
+                ::borsh::BorshSerialize::serialize(&(1 as u32), writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl ::borsh::BorshDeserialize for Flag {
+    fn deserialize_reader<R: ::std::io::Read>(
+        reader: &mut R,
+    ) -> ::std::io::Result<Self> {
+        let tag = <u32 as ::borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Ok(
+            match tag {
+                0 => Self::Off,
+                1 => Self::On,
+                _ => {
+                    return Err(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::InvalidData,
+                            ::std::format!("Unexpected variant tag: {:?}", tag),
+                        ),
+                    );
+                }
+            },
+        )
+    }
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub enum Mode {
+    Fast,
+    Safe,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct Step {
+    pub flag: Flag,
+    pub mode: Mode,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const STEP_LAYOUT: &[(&str, usize, usize)] = &[
+    ("flag", 0usize, 4usize),
+    ("mode", 4usize, 1usize),
+];
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Opcode {
+    Halt,
+    Push(u64),
+    Jump { target: u32, if_zero: bool },
+}
+impl ::borsh::BorshSerialize for Opcode {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Halt => {
+                ::borsh::BorshSerialize::serialize(&(0 as u16), writer)?;
+            }
+            Self::Push(f0) => {
+                ::borsh::BorshSerialize::serialize(&(1 as u16), writer)?;
+                ::borsh::BorshSerialize::serialize(f0, writer)?;
+            }
+            Self::Jump { target, if_zero } => {
+                ::borsh::BorshSerialize::serialize(&(2 as u16), writer)?;
+                ::borsh::BorshSerialize::serialize(target, writer)?;
+                ::borsh::BorshSerialize::serialize(if_zero, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl ::borsh::BorshDeserialize for Opcode {
+    fn deserialize_reader<R: ::std::io::Read>(
+        reader: &mut R,
+    ) -> ::std::io::Result<Self> {
+        let tag = <u16 as ::borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Ok(
+            match tag {
+                0 => Self::Halt,
+                1 => Self::Push(::borsh::BorshDeserialize::deserialize_reader(reader)?),
+                2 => {
+                    Self::Jump {
+                        target: ::borsh::BorshDeserialize::deserialize_reader(reader)?,
+                        if_zero: ::borsh::BorshDeserialize::deserialize_reader(reader)?,
+                    }
+                }
+                _ => {
+                    return Err(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::InvalidData,
+                            ::std::format!("Unexpected variant tag: {:?}", tag),
+                        ),
+                    );
+                }
+            },
+        )
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpreterInstructions {
+    Execute(ExecuteAccounts, ExecuteArgs),
+}
+impl InterpreterInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            EXECUTE_DISCRIMINATOR => {
+                Self::Execute(
+                    ExecuteAccounts::from_account_metas(accounts)?,
+                    ExecuteArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl InterpreterInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for InterpreterInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Execute(..) => &EXECUTE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Execute(..) => "execute",
+        }
+    }
+}
+impl ::core::convert::From<(ExecuteAccounts, ExecuteArgs)> for InterpreterInstructions {
+    fn from(payload: (ExecuteAccounts, ExecuteArgs)) -> Self {
+        Self::Execute(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `InterpreterInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<InterpreterInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: InterpreterInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for InterpreterInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Execute(_, args) => {
+                writer.write_all(&EXECUTE_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl InterpreterInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Execute(accounts, _) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Execute(accounts, _) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait InterpreterVisitor {
+    ///Called by `accept` for `execute` instructions
+    fn visit_execute(&mut self, _accounts: &ExecuteAccounts, _args: &ExecuteArgs) {}
+}
+impl InterpreterInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: InterpreterVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Execute(accounts, args) => visitor.visit_execute(accounts, args),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum InterpreterInstructionKind {
+    ///`execute`
+    Execute,
+}
+impl InterpreterInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_execute_discriminator(data) {
+            return Some(Self::Execute);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Execute => "execute",
+        }
+    }
+}
+impl InterpreterInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> InterpreterInstructionKind {
+        match self {
+            Self::Execute(..) => InterpreterInstructionKind::Execute,
+        }
+    }
+}
@@ -0,0 +1,422 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const PLACE_BID_DISCRIMINATOR: [u8; 8usize] = [
+    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_place_bid_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&PLACE_BID_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("placeBid", &PLACE_BID_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "placeBid" | "place_bid" => Some(PLACE_BID_DISCRIMINATOR),
+        _ => None,
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct PlaceBidArgs {
+    pub amount: u64,
+}
+impl PlaceBidArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = PLACE_BID_DISCRIMINATOR;
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct AuctionClosed {
+    pub winning_bid: u64,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const AUCTION_CLOSED_LAYOUT: &[(&str, usize, usize)] = &[
+    ("winning_bid", 0usize, 8usize),
+];
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct BidPlaced {
+    pub bidder: ::solana_idl_parser::Pubkey,
+    pub amount: u64,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const BID_PLACED_LAYOUT: &[(&str, usize, usize)] = &[
+    ("bidder", 0usize, 32usize),
+    ("amount", 32usize, 8usize),
+];
+pub const BID_PLACED_EVENT_DISCRIMINATOR: [u8; 8usize] = [
+    135u8, 4u8, 250u8, 16u8, 56u8, 9u8, 77u8, 201u8,
+];
+impl BidPlaced {
+    /// Discriminator the event data starts with
+    pub const EVENT_DISCRIMINATOR: [u8; 8usize] = BID_PLACED_EVENT_DISCRIMINATOR;
+    /// Decode event data, as logged by `emit!` after base64 decoding
+    pub fn from_event_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let (discriminator, mut reader) = data
+            .as_ref()
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "event data is shorter than its discriminator",
+                ),
+            ))?;
+        if *discriminator != BID_PLACED_EVENT_DISCRIMINATOR {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "event discriminator mismatch",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Self::deserialize(&mut reader)
+            .map_err(|e| ::solana_idl_parser::DecodeError::from(e).into())
+    }
+}
+pub const AUCTION_CLOSED_EVENT_DISCRIMINATOR: [u8; 8usize] = [
+    12u8, 200u8, 3u8, 90u8, 41u8, 7u8, 66u8, 18u8,
+];
+impl AuctionClosed {
+    /// Discriminator the event data starts with
+    pub const EVENT_DISCRIMINATOR: [u8; 8usize] = AUCTION_CLOSED_EVENT_DISCRIMINATOR;
+    /// Decode event data, as logged by `emit!` after base64 decoding
+    pub fn from_event_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let (discriminator, mut reader) = data
+            .as_ref()
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "event data is shorter than its discriminator",
+                ),
+            ))?;
+        if *discriminator != AUCTION_CLOSED_EVENT_DISCRIMINATOR {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "event discriminator mismatch",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Self::deserialize(&mut reader)
+            .map_err(|e| ::solana_idl_parser::DecodeError::from(e).into())
+    }
+}
+/// Events of the program; decode them with `from_event_data`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuctionEvents {
+    BidPlaced(BidPlaced),
+    AuctionClosed(AuctionClosed),
+}
+impl AuctionEvents {
+    /// Decode event data starting with the discriminator of any of the program's events
+    pub fn from_event_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = data.as_ref();
+        if data.starts_with(&BidPlaced::EVENT_DISCRIMINATOR) {
+            BidPlaced::from_event_data(data).map(Self::BidPlaced)
+        } else if data.starts_with(&AuctionClosed::EVENT_DISCRIMINATOR) {
+            AuctionClosed::from_event_data(data).map(Self::AuctionClosed)
+        } else {
+            Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "unknown event discriminator",
+                        ),
+                    )
+                    .into(),
+            )
+        }
+    }
+}
+impl ::solana_idl_parser::IdlEvents for AuctionEvents {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn from_event_data(data: &[u8]) -> ::anyhow::Result<Self> {
+        Self::from_event_data(data)
+    }
+}
+/// Decode an event emitted with `emit_cpi!`, given an inner instruction's program id and data
+///
+/// Such events are self-invocations of the program whose data is
+/// `solana_idl_parser::EVENT_CPI_TAG`, then the event discriminator and
+/// fields. Returns `None` for inner instructions of other programs and
+/// ones that are not events.
+pub fn decode_event_from_inner_instruction(
+    program_id: &::solana_idl_parser::Pubkey,
+    data: impl AsRef<[u8]>,
+) -> ::core::result::Result<Option<AuctionEvents>, ::anyhow::Error> {
+    if *program_id != PROGRAM_ID {
+        return Ok(None);
+    }
+    match data.as_ref().strip_prefix(&::solana_idl_parser::EVENT_CPI_TAG) {
+        Some(event) => AuctionEvents::from_event_data(event).map(Some),
+        None => Ok(None),
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuctionInstructions {
+    PlaceBid(PlaceBidArgs),
+}
+impl AuctionInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            PLACE_BID_DISCRIMINATOR => {
+                Self::PlaceBid(
+                    PlaceBidArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl AuctionInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for AuctionInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::PlaceBid(..) => &PLACE_BID_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::PlaceBid(..) => "placeBid",
+        }
+    }
+}
+impl ::core::convert::From<PlaceBidArgs> for AuctionInstructions {
+    fn from(payload: PlaceBidArgs) -> Self {
+        Self::PlaceBid(payload)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `AuctionInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<AuctionInstructions> for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: AuctionInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for AuctionInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::PlaceBid(args) => {
+                writer.write_all(&PLACE_BID_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl AuctionInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::PlaceBid(_) => ::std::vec::Vec::new(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::PlaceBid(_) => ::std::vec::Vec::new(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait AuctionVisitor {
+    ///Called by `accept` for `placeBid` instructions
+    fn visit_place_bid(&mut self, _args: &PlaceBidArgs) {}
+}
+impl AuctionInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: AuctionVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::PlaceBid(args) => visitor.visit_place_bid(args),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AuctionInstructionKind {
+    ///`placeBid`
+    PlaceBid,
+}
+impl AuctionInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_place_bid_discriminator(data) {
+            return Some(Self::PlaceBid);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PlaceBid => "placeBid",
+        }
+    }
+}
+impl AuctionInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> AuctionInstructionKind {
+        match self {
+            Self::PlaceBid(..) => AuctionInstructionKind::PlaceBid,
+        }
+    }
+}
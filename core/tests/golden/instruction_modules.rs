@@ -0,0 +1,459 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 2usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const BUY_DISCRIMINATOR: [u8; 8usize] = [1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_buy_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&BUY_DISCRIMINATOR)
+}
+pub const CLOSE_DISCRIMINATOR: [u8; 8usize] = [2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_close_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&CLOSE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("buy", &BUY_DISCRIMINATOR),
+    ("close", &CLOSE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "buy" => Some(BUY_DISCRIMINATOR),
+        "close" => Some(CLOSE_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const RECEIPT_PDA_CONST_SEED: &[u8] = &[
+    114u8, 101u8, 99u8, 101u8, 105u8, 112u8, 116u8,
+];
+pub const BUY_IX_ACCOUNTS_LEN: usize = 2usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct BuyAccounts {
+    pub buyer: ::solana_idl_parser::Pubkey,
+    pub receipt: ::solana_idl_parser::Pubkey,
+}
+impl BuyAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = BUY_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != BUY_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: BUY_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self {
+            buyer: metas[0].pubkey,
+            receipt: metas[1].pubkey,
+        })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.buyer, is_signer : true,
+            is_writable : false, }, ::solana_idl_parser::AccountMeta { pubkey : self
+            .receipt, is_signer : false, is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); BUY_IX_ACCOUNTS_LEN] {
+        [("buyer", self.buyer), ("receipt", self.receipt)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct BuyInstructionArgs {
+    pub amount: u64,
+    pub limits: BuyArgs,
+}
+impl BuyInstructionArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = BUY_DISCRIMINATOR;
+}
+pub const CLOSE_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct CloseAccounts {
+    pub receipt: ::solana_idl_parser::Pubkey,
+}
+impl CloseAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = CLOSE_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != CLOSE_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: CLOSE_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { receipt: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.receipt, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); CLOSE_IX_ACCOUNTS_LEN] {
+        [("receipt", self.receipt)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+impl BuyAccounts {
+    /// Derive this account's PDA from its seeds, returning the address and bump
+    pub fn find_receipt_address(
+        &self,
+        args: &BuyInstructionArgs,
+    ) -> (::solana_idl_parser::Pubkey, u8) {
+        ::solana_idl_parser::Pubkey::find_program_address(
+            &[&[114u8, 101u8, 99u8, 101u8, 105u8, 112u8, 116u8], self.buyer.as_ref()],
+            &PROGRAM_ID,
+        )
+    }
+    /// Check this account against its PDA and, if given, the expected bump
+    pub fn verify_receipt_address(
+        &self,
+        args: &BuyInstructionArgs,
+        bump: ::core::option::Option<u8>,
+    ) -> ::core::result::Result<u8, ::anyhow::Error> {
+        let (address, found_bump) = self.find_receipt_address(args);
+        if address != self.receipt {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "account `receipt` does not match its PDA",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        if bump.is_some_and(|bump| bump != found_bump) {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "bump of `receipt` does not match its PDA",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Ok(found_bump)
+    }
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct BuyArgs {
+    pub max_price: u64,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const BUY_ARGS_LAYOUT: &[(&str, usize, usize)] = &[("max_price", 0usize, 8usize)];
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShopInstructions {
+    Buy(BuyAccounts, BuyInstructionArgs),
+    Close(CloseAccounts),
+}
+impl ShopInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            BUY_DISCRIMINATOR => {
+                Self::Buy(
+                    BuyAccounts::from_account_metas(accounts)?,
+                    BuyInstructionArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            CLOSE_DISCRIMINATOR => {
+                Self::Close(CloseAccounts::from_account_metas(accounts)?)
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl ShopInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for ShopInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Buy(..) => &BUY_DISCRIMINATOR,
+            Self::Close(..) => &CLOSE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Buy(..) => "buy",
+            Self::Close(..) => "close",
+        }
+    }
+}
+impl ::core::convert::From<(BuyAccounts, BuyInstructionArgs)> for ShopInstructions {
+    fn from(payload: (BuyAccounts, BuyInstructionArgs)) -> Self {
+        Self::Buy(payload.0, payload.1)
+    }
+}
+impl ::core::convert::From<CloseAccounts> for ShopInstructions {
+    fn from(payload: CloseAccounts) -> Self {
+        Self::Close(payload)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `ShopInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<ShopInstructions> for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: ShopInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for ShopInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Buy(_, args) => {
+                writer.write_all(&BUY_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Close(_) => writer.write_all(&CLOSE_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl ShopInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Buy(accounts, _) => accounts.to_account_metas(),
+            Self::Close(accounts) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Buy(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::Close(accounts) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait ShopVisitor {
+    ///Called by `accept` for `buy` instructions
+    fn visit_buy(&mut self, _accounts: &BuyAccounts, _args: &BuyInstructionArgs) {}
+    ///Called by `accept` for `close` instructions
+    fn visit_close(&mut self, _accounts: &CloseAccounts) {}
+}
+impl ShopInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: ShopVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Buy(accounts, args) => visitor.visit_buy(accounts, args),
+            Self::Close(accounts) => visitor.visit_close(accounts),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ShopInstructionKind {
+    ///`buy`
+    Buy,
+    ///`close`
+    Close,
+}
+impl ShopInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_buy_discriminator(data) {
+            return Some(Self::Buy);
+        }
+        if matches_close_discriminator(data) {
+            return Some(Self::Close);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Close => "close",
+        }
+    }
+}
+impl ShopInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> ShopInstructionKind {
+        match self {
+            Self::Buy(..) => ShopInstructionKind::Buy,
+            Self::Close(..) => ShopInstructionKind::Close,
+        }
+    }
+}
@@ -0,0 +1,438 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 3usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const SWAP_DISCRIMINATOR: [u8; 8usize] = [3u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_swap_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&SWAP_DISCRIMINATOR)
+}
+pub const LEGACY_TRANSFER_DISCRIMINATOR: [u8; 1usize] = [3u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_legacy_transfer_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&LEGACY_TRANSFER_DISCRIMINATOR)
+}
+pub const CLOSE_DISCRIMINATOR: [u8; 1usize] = [9u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_close_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&CLOSE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("swap", &SWAP_DISCRIMINATOR),
+    ("legacyTransfer", &LEGACY_TRANSFER_DISCRIMINATOR),
+    ("close", &CLOSE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<&'static [u8]> {
+    match name {
+        "swap" => Some(&SWAP_DISCRIMINATOR[..]),
+        "legacyTransfer" | "legacy_transfer" => Some(&LEGACY_TRANSFER_DISCRIMINATOR[..]),
+        "close" => Some(&CLOSE_DISCRIMINATOR[..]),
+        _ => None,
+    }
+}
+pub const SWAP_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct SwapAccounts {
+    pub pool: ::solana_idl_parser::Pubkey,
+}
+impl SwapAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = SWAP_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != SWAP_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: SWAP_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { pool: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.pool, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); SWAP_IX_ACCOUNTS_LEN] {
+        [("pool", self.pool)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct SwapArgs {
+    pub amount_in: u64,
+}
+impl SwapArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = SWAP_DISCRIMINATOR;
+}
+pub const LEGACY_TRANSFER_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct LegacyTransferAccounts {
+    pub source: ::solana_idl_parser::Pubkey,
+}
+impl LegacyTransferAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 1usize] = LEGACY_TRANSFER_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != LEGACY_TRANSFER_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: LEGACY_TRANSFER_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { source: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.source, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); LEGACY_TRANSFER_IX_ACCOUNTS_LEN] {
+        [("source", self.source)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct LegacyTransferArgs {
+    pub amount: u32,
+}
+impl LegacyTransferArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 1usize] = LEGACY_TRANSFER_DISCRIMINATOR;
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MixedInstructions {
+    Swap(SwapAccounts, SwapArgs),
+    LegacyTransfer(LegacyTransferAccounts, LegacyTransferArgs),
+    Close,
+}
+impl MixedInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let discriminator: &[u8] = [
+            &SWAP_DISCRIMINATOR[..],
+            &LEGACY_TRANSFER_DISCRIMINATOR[..],
+            &CLOSE_DISCRIMINATOR[..],
+        ]
+            .into_iter()
+            .find(|discriminator| buf.starts_with(discriminator))
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::unknown_discriminator(
+                &buf[..buf.len().min(8usize)],
+                INSTRUCTION_DISCRIMINATORS,
+            ))?;
+        let args = &buf[discriminator.len()..];
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = if *discriminator == SWAP_DISCRIMINATOR {
+            Self::Swap(
+                SwapAccounts::from_account_metas(accounts)?,
+                SwapArgs::deserialize(&mut reader)
+                    .map_err(::solana_idl_parser::DecodeError::from)?,
+            )
+        } else if *discriminator == LEGACY_TRANSFER_DISCRIMINATOR {
+            Self::LegacyTransfer(
+                LegacyTransferAccounts::from_account_metas(accounts)?,
+                LegacyTransferArgs::deserialize(&mut reader)
+                    .map_err(::solana_idl_parser::DecodeError::from)?,
+            )
+        } else if *discriminator == CLOSE_DISCRIMINATOR {
+            Self::Close
+        } else {
+            return Err(
+                ::solana_idl_parser::DecodeError::unknown_discriminator(
+                        &discriminator[..],
+                        INSTRUCTION_DISCRIMINATORS,
+                    )
+                    .into(),
+            )
+        };
+        Ok(ix)
+    }
+}
+impl MixedInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<::std::vec::Vec<u8>, &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = overrides
+            .iter()
+            .filter(|(discriminator, _)| buf.starts_with(discriminator))
+            .max_by_key(|(discriminator, _)| discriminator.len())
+            .map(|(discriminator, name)| (*name, &buf[discriminator.len()..])) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for MixedInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Swap(..) => &SWAP_DISCRIMINATOR,
+            Self::LegacyTransfer(..) => &LEGACY_TRANSFER_DISCRIMINATOR,
+            Self::Close => &CLOSE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Swap(..) => "swap",
+            Self::LegacyTransfer(..) => "legacyTransfer",
+            Self::Close => "close",
+        }
+    }
+}
+impl ::core::convert::From<(SwapAccounts, SwapArgs)> for MixedInstructions {
+    fn from(payload: (SwapAccounts, SwapArgs)) -> Self {
+        Self::Swap(payload.0, payload.1)
+    }
+}
+impl ::core::convert::From<(LegacyTransferAccounts, LegacyTransferArgs)>
+for MixedInstructions {
+    fn from(payload: (LegacyTransferAccounts, LegacyTransferArgs)) -> Self {
+        Self::LegacyTransfer(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `MixedInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<MixedInstructions> for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: MixedInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for MixedInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Swap(_, args) => {
+                writer.write_all(&SWAP_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::LegacyTransfer(_, args) => {
+                writer.write_all(&LEGACY_TRANSFER_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Close => writer.write_all(&CLOSE_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl MixedInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Swap(accounts, _) => accounts.to_account_metas(),
+            Self::LegacyTransfer(accounts, _) => accounts.to_account_metas(),
+            Self::Close => ::std::vec::Vec::new(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Swap(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::LegacyTransfer(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::Close => ::std::vec::Vec::new(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait MixedVisitor {
+    ///Called by `accept` for `swap` instructions
+    fn visit_swap(&mut self, _accounts: &SwapAccounts, _args: &SwapArgs) {}
+    ///Called by `accept` for `legacyTransfer` instructions
+    fn visit_legacy_transfer(
+        &mut self,
+        _accounts: &LegacyTransferAccounts,
+        _args: &LegacyTransferArgs,
+    ) {}
+    ///Called by `accept` for `close` instructions
+    fn visit_close(&mut self) {}
+}
+impl MixedInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: MixedVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Swap(accounts, args) => visitor.visit_swap(accounts, args),
+            Self::LegacyTransfer(accounts, args) => {
+                visitor.visit_legacy_transfer(accounts, args)
+            }
+            Self::Close => visitor.visit_close(),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MixedInstructionKind {
+    ///`swap`
+    Swap,
+    ///`legacyTransfer`
+    LegacyTransfer,
+    ///`close`
+    Close,
+}
+impl MixedInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_swap_discriminator(data) {
+            return Some(Self::Swap);
+        }
+        if matches_legacy_transfer_discriminator(data) {
+            return Some(Self::LegacyTransfer);
+        }
+        if matches_close_discriminator(data) {
+            return Some(Self::Close);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap",
+            Self::LegacyTransfer => "legacyTransfer",
+            Self::Close => "close",
+        }
+    }
+}
+impl MixedInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> MixedInstructionKind {
+        match self {
+            Self::Swap(..) => MixedInstructionKind::Swap,
+            Self::LegacyTransfer(..) => MixedInstructionKind::LegacyTransfer,
+            Self::Close => MixedInstructionKind::Close,
+        }
+    }
+}
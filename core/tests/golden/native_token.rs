@@ -0,0 +1,408 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    6u8, 221u8, 246u8, 225u8, 215u8, 101u8, 161u8, 147u8, 217u8, 203u8, 225u8, 70u8,
+    206u8, 235u8, 121u8, 172u8, 28u8, 180u8, 133u8, 237u8, 95u8, 91u8, 55u8, 145u8, 58u8,
+    140u8, 245u8, 133u8, 126u8, 255u8, 0u8, 169u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 2usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const TRANSFER_DISCRIMINATOR: [u8; 1usize] = [3u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_transfer_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&TRANSFER_DISCRIMINATOR)
+}
+pub const SYNC_NATIVE_DISCRIMINATOR: [u8; 1usize] = [17u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_sync_native_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&SYNC_NATIVE_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("transfer", &TRANSFER_DISCRIMINATOR),
+    ("syncNative", &SYNC_NATIVE_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 1usize]> {
+    match name {
+        "transfer" => Some(TRANSFER_DISCRIMINATOR),
+        "syncNative" | "sync_native" => Some(SYNC_NATIVE_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const TRANSFER_IX_ACCOUNTS_LEN: usize = 3usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct TransferAccounts {
+    pub source: ::solana_idl_parser::Pubkey,
+    pub destination: ::solana_idl_parser::Pubkey,
+    pub owner: ::solana_idl_parser::Pubkey,
+}
+impl TransferAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 1usize] = TRANSFER_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != TRANSFER_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: TRANSFER_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self {
+            source: metas[0].pubkey,
+            destination: metas[1].pubkey,
+            owner: metas[2].pubkey,
+        })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.source, is_signer : false,
+            is_writable : true, }, ::solana_idl_parser::AccountMeta { pubkey : self
+            .destination, is_signer : false, is_writable : true, },
+            ::solana_idl_parser::AccountMeta { pubkey : self.owner, is_signer : true,
+            is_writable : false, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); TRANSFER_IX_ACCOUNTS_LEN] {
+        [
+            ("source", self.source),
+            ("destination", self.destination),
+            ("owner", self.owner),
+        ]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct TransferArgs {
+    pub instruction: u8,
+    pub amount: u64,
+}
+impl TransferArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 1usize] = TRANSFER_DISCRIMINATOR;
+}
+pub const SYNC_NATIVE_IX_ACCOUNTS_LEN: usize = 1usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct SyncNativeAccounts {
+    pub account: ::solana_idl_parser::Pubkey,
+}
+impl SyncNativeAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 1usize] = SYNC_NATIVE_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != SYNC_NATIVE_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: SYNC_NATIVE_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self { account: metas[0].pubkey })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.account, is_signer : false,
+            is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); SYNC_NATIVE_IX_ACCOUNTS_LEN] {
+        [("account", self.account)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum NativeTokenInstructions {
+    Transfer(TransferAccounts, TransferArgs),
+    SyncNative(SyncNativeAccounts),
+}
+impl NativeTokenInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<1usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 1usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            TRANSFER_DISCRIMINATOR => {
+                Self::Transfer(
+                    TransferAccounts::from_account_metas(accounts)?,
+                    TransferArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            SYNC_NATIVE_DISCRIMINATOR => {
+                Self::SyncNative(SyncNativeAccounts::from_account_metas(accounts)?)
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl NativeTokenInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 1usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<1usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for NativeTokenInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Transfer(..) => &TRANSFER_DISCRIMINATOR,
+            Self::SyncNative(..) => &SYNC_NATIVE_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Transfer(..) => "transfer",
+            Self::SyncNative(..) => "syncNative",
+        }
+    }
+}
+impl ::core::convert::From<(TransferAccounts, TransferArgs)>
+for NativeTokenInstructions {
+    fn from(payload: (TransferAccounts, TransferArgs)) -> Self {
+        Self::Transfer(payload.0, payload.1)
+    }
+}
+impl ::core::convert::From<SyncNativeAccounts> for NativeTokenInstructions {
+    fn from(payload: SyncNativeAccounts) -> Self {
+        Self::SyncNative(payload)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `NativeTokenInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<NativeTokenInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: NativeTokenInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for NativeTokenInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Transfer(_, args) => {
+                writer.write_all(&TRANSFER_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::SyncNative(_) => writer.write_all(&SYNC_NATIVE_DISCRIMINATOR)?,
+        }
+        Ok(())
+    }
+}
+impl NativeTokenInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Transfer(accounts, _) => accounts.to_account_metas(),
+            Self::SyncNative(accounts) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Transfer(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::SyncNative(accounts) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait NativeTokenVisitor {
+    ///Called by `accept` for `transfer` instructions
+    fn visit_transfer(&mut self, _accounts: &TransferAccounts, _args: &TransferArgs) {}
+    ///Called by `accept` for `syncNative` instructions
+    fn visit_sync_native(&mut self, _accounts: &SyncNativeAccounts) {}
+}
+impl NativeTokenInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: NativeTokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Transfer(accounts, args) => visitor.visit_transfer(accounts, args),
+            Self::SyncNative(accounts) => visitor.visit_sync_native(accounts),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NativeTokenInstructionKind {
+    ///`transfer`
+    Transfer,
+    ///`syncNative`
+    SyncNative,
+}
+impl NativeTokenInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_transfer_discriminator(data) {
+            return Some(Self::Transfer);
+        }
+        if matches_sync_native_discriminator(data) {
+            return Some(Self::SyncNative);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Transfer => "transfer",
+            Self::SyncNative => "syncNative",
+        }
+    }
+}
+impl NativeTokenInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> NativeTokenInstructionKind {
+        match self {
+            Self::Transfer(..) => NativeTokenInstructionKind::Transfer,
+            Self::SyncNative(..) => NativeTokenInstructionKind::SyncNative,
+        }
+    }
+}
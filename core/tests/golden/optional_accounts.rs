@@ -0,0 +1,343 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    12u8, 20u8, 222u8, 252u8, 130u8, 94u8, 198u8, 118u8, 148u8, 37u8, 8u8, 24u8, 187u8,
+    101u8, 64u8, 101u8, 244u8, 41u8, 141u8, 49u8, 86u8, 213u8, 113u8, 180u8, 212u8,
+    248u8, 9u8, 12u8, 24u8, 233u8, 168u8, 99u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 1usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 0usize;
+pub const SWAP_DISCRIMINATOR: [u8; 8usize] = [1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_swap_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&SWAP_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("swap", &SWAP_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 8usize]> {
+    match name {
+        "swap" => Some(SWAP_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const SWAP_IX_ACCOUNTS_LEN: usize = 4usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct SwapAccounts {
+    pub user: ::solana_idl_parser::Pubkey,
+    pub referrer: Option<::solana_idl_parser::Pubkey>,
+    pub pool: ::solana_idl_parser::Pubkey,
+    pub fee_vault: Option<::solana_idl_parser::Pubkey>,
+}
+impl SwapAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 8usize] = SWAP_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if !(3usize..=SWAP_IX_ACCOUNTS_LEN).contains(&metas.len()) {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: if metas.len() < 3usize {
+                        3usize
+                    } else {
+                        SWAP_IX_ACCOUNTS_LEN
+                    },
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self {
+            user: metas[0].pubkey,
+            referrer: metas
+                .get(1)
+                .map(|meta| meta.pubkey)
+                .filter(|pubkey| *pubkey != PROGRAM_ID),
+            pool: metas[2].pubkey,
+            fee_vault: metas
+                .get(3)
+                .map(|meta| meta.pubkey)
+                .filter(|pubkey| *pubkey != PROGRAM_ID),
+        })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.user, is_signer : true,
+            is_writable : false, }, match self.referrer { Some(pubkey) =>
+            ::solana_idl_parser::AccountMeta { pubkey, is_signer : false, is_writable :
+            true, }, None => ::solana_idl_parser::AccountMeta { pubkey : PROGRAM_ID,
+            is_signer : false, is_writable : false, }, },
+            ::solana_idl_parser::AccountMeta { pubkey : self.pool, is_signer : false,
+            is_writable : true, }, match self.fee_vault { Some(pubkey) =>
+            ::solana_idl_parser::AccountMeta { pubkey, is_signer : false, is_writable :
+            true, }, None => ::solana_idl_parser::AccountMeta { pubkey : PROGRAM_ID,
+            is_signer : false, is_writable : false, }, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); SWAP_IX_ACCOUNTS_LEN] {
+        [
+            ("user", self.user),
+            ("referrer", self.referrer.unwrap_or(PROGRAM_ID)),
+            ("pool", self.pool),
+            ("feeVault", self.fee_vault.unwrap_or(PROGRAM_ID)),
+        ]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct SwapArgs {
+    pub amount: u64,
+}
+impl SwapArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 8usize] = SWAP_DISCRIMINATOR;
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferralsInstructions {
+    Swap(SwapAccounts, SwapArgs),
+}
+impl ReferralsInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<8usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 8usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            SWAP_DISCRIMINATOR => {
+                Self::Swap(
+                    SwapAccounts::from_account_metas(accounts)?,
+                    SwapArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl ReferralsInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 8usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<8usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for ReferralsInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::Swap(..) => &SWAP_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Swap(..) => "swap",
+        }
+    }
+}
+impl ::core::convert::From<(SwapAccounts, SwapArgs)> for ReferralsInstructions {
+    fn from(payload: (SwapAccounts, SwapArgs)) -> Self {
+        Self::Swap(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `ReferralsInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<ReferralsInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: ReferralsInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for ReferralsInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::Swap(_, args) => {
+                writer.write_all(&SWAP_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl ReferralsInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::Swap(accounts, _) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::Swap(accounts, _) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait ReferralsVisitor {
+    ///Called by `accept` for `swap` instructions
+    fn visit_swap(&mut self, _accounts: &SwapAccounts, _args: &SwapArgs) {}
+}
+impl ReferralsInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: ReferralsVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Swap(accounts, args) => visitor.visit_swap(accounts, args),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReferralsInstructionKind {
+    ///`swap`
+    Swap,
+}
+impl ReferralsInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_swap_discriminator(data) {
+            return Some(Self::Swap);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap",
+        }
+    }
+}
+impl ReferralsInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> ReferralsInstructionKind {
+        match self {
+            Self::Swap(..) => ReferralsInstructionKind::Swap,
+        }
+    }
+}
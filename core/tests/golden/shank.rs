@@ -0,0 +1,538 @@
+pub const PROGRAM_ID: ::solana_idl_parser::Pubkey = ::solana_idl_parser::Pubkey::new_from_array([
+    13u8, 186u8, 28u8, 49u8, 159u8, 58u8, 24u8, 182u8, 14u8, 209u8, 215u8, 143u8, 24u8,
+    166u8, 124u8, 70u8, 218u8, 194u8, 105u8, 75u8, 229u8, 15u8, 83u8, 13u8, 65u8, 195u8,
+    130u8, 164u8, 202u8, 150u8, 90u8, 121u8,
+]);
+/// Number of instructions, one per variant of the instructions enum
+pub const INSTRUCTION_COUNT: usize = 2usize;
+/// Number of entries in the IDL `accounts` section
+pub const ACCOUNT_COUNT: usize = 1usize;
+pub const CREATE_VAULT_DISCRIMINATOR: [u8; 1usize] = [0u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_create_vault_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&CREATE_VAULT_DISCRIMINATOR)
+}
+pub const DEPOSIT_DISCRIMINATOR: [u8; 1usize] = [1u8];
+/// Whether instruction data starts with this instruction's discriminator
+pub fn matches_deposit_discriminator(data: &[u8]) -> bool {
+    data.starts_with(&DEPOSIT_DISCRIMINATOR)
+}
+/// IDL name and discriminator of every instruction, as unknown discriminators are compared to
+pub const INSTRUCTION_DISCRIMINATORS: &[(&str, &[u8])] = &[
+    ("CreateVault", &CREATE_VAULT_DISCRIMINATOR),
+    ("Deposit", &DEPOSIT_DISCRIMINATOR),
+];
+/// Discriminator of the instruction named `name`, in its IDL or snake_case form
+pub fn discriminator_of(name: &str) -> Option<[u8; 1usize]> {
+    match name {
+        "CreateVault" | "create_vault" => Some(CREATE_VAULT_DISCRIMINATOR),
+        "Deposit" | "deposit" => Some(DEPOSIT_DISCRIMINATOR),
+        _ => None,
+    }
+}
+pub const CREATE_VAULT_IX_ACCOUNTS_LEN: usize = 3usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct CreateVaultAccounts {
+    pub vault: ::solana_idl_parser::Pubkey,
+    pub authority: ::solana_idl_parser::Pubkey,
+    pub delegate: Option<::solana_idl_parser::Pubkey>,
+}
+impl CreateVaultAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 1usize] = CREATE_VAULT_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if !(2usize..=CREATE_VAULT_IX_ACCOUNTS_LEN).contains(&metas.len()) {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: if metas.len() < 2usize {
+                        2usize
+                    } else {
+                        CREATE_VAULT_IX_ACCOUNTS_LEN
+                    },
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self {
+            vault: metas[0].pubkey,
+            authority: metas[1].pubkey,
+            delegate: metas
+                .get(2)
+                .map(|meta| meta.pubkey)
+                .filter(|pubkey| *pubkey != PROGRAM_ID),
+        })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.vault, is_signer : false,
+            is_writable : true, }, ::solana_idl_parser::AccountMeta { pubkey : self
+            .authority, is_signer : true, is_writable : false, }, match self.delegate {
+            Some(pubkey) => ::solana_idl_parser::AccountMeta { pubkey, is_signer : false,
+            is_writable : false, }, None => ::solana_idl_parser::AccountMeta { pubkey :
+            PROGRAM_ID, is_signer : false, is_writable : false, }, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); CREATE_VAULT_IX_ACCOUNTS_LEN] {
+        [
+            ("vault", self.vault),
+            ("authority", self.authority),
+            ("delegate", self.delegate.unwrap_or(PROGRAM_ID)),
+        ]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct CreateVaultInstructionArgs {
+    pub create_vault_args: CreateVaultArgs,
+}
+impl CreateVaultInstructionArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 1usize] = CREATE_VAULT_DISCRIMINATOR;
+}
+pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 2usize;
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct DepositAccounts {
+    pub vault: ::solana_idl_parser::Pubkey,
+    pub depositor: ::solana_idl_parser::Pubkey,
+}
+impl DepositAccounts {
+    /// Discriminator of the instruction taking these accounts
+    pub const DISCRIMINATOR: [u8; 1usize] = DEPOSIT_DISCRIMINATOR;
+    pub fn from_account_metas(
+        metas: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        if metas.len() != DEPOSIT_IX_ACCOUNTS_LEN {
+            return Err(
+                ::solana_idl_parser::DecodeError::InvalidAccountLen {
+                    expected: DEPOSIT_IX_ACCOUNTS_LEN,
+                    got: metas.len(),
+                }
+                    .into(),
+            );
+        }
+        Ok(Self {
+            vault: metas[0].pubkey,
+            depositor: metas[1].pubkey,
+        })
+    }
+    pub fn to_account_metas(&self) -> ::std::vec::Vec<::solana_idl_parser::AccountMeta> {
+        ::std::vec![
+            ::solana_idl_parser::AccountMeta { pubkey : self.vault, is_signer : false,
+            is_writable : true, }, ::solana_idl_parser::AccountMeta { pubkey : self
+            .depositor, is_signer : true, is_writable : true, },
+        ]
+    }
+    /// Each named account paired with its name in the IDL, in instruction order
+    pub fn named_accounts(
+        &self,
+    ) -> [(&'static str, ::solana_idl_parser::Pubkey); DEPOSIT_IX_ACCOUNTS_LEN] {
+        [("vault", self.vault), ("depositor", self.depositor)]
+    }
+    /// Iterator over `named_accounts`, for streaming them without holding the array
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, ::solana_idl_parser::Pubkey)> {
+        self.named_accounts().into_iter()
+    }
+}
+#[derive(Debug, Clone, PartialEq, ::borsh::BorshDeserialize, ::borsh::BorshSerialize)]
+pub struct DepositArgs {
+    pub amount: u64,
+}
+impl DepositArgs {
+    /// Discriminator of the instruction taking these args
+    pub const DISCRIMINATOR: [u8; 1usize] = DEPOSIT_DISCRIMINATOR;
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct CreateVaultArgs {
+    pub mode: VaultMode,
+    pub limit: Option<u64>,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub enum VaultMode {
+    Open,
+    Locked,
+}
+pub const VAULT_ACCOUNT_DISCRIMINATOR: [u8; 0usize] = [];
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::borsh::BorshDeserialize,
+    ::borsh::BorshSerialize
+)]
+pub struct Vault {
+    pub authority: ::solana_idl_parser::Pubkey,
+    pub balance: u64,
+    pub mode: VaultMode,
+}
+/// Offset and size in bytes of each field of the borsh encoding, after any account discriminator
+pub const VAULT_LAYOUT: &[(&str, usize, usize)] = &[
+    ("authority", 0usize, 32usize),
+    ("balance", 32usize, 8usize),
+    ("mode", 40usize, 1usize),
+];
+impl Vault {
+    /// Program owning accounts of this type
+    pub const OWNER: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    /// Decode account data, ignoring bytes after the struct
+    ///
+    /// Accounts are often allocated larger than their data, so trailing
+    /// padding is accepted even with `deny_trailing_bytes`, which only
+    /// applies to instructions.
+    pub fn from_account_data(
+        data: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let (discriminator, mut reader) = data
+            .as_ref()
+            .split_first_chunk::<0usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "account data is shorter than its discriminator",
+                ),
+            ))?;
+        if *discriminator != VAULT_ACCOUNT_DISCRIMINATOR {
+            return Err(
+                ::solana_idl_parser::DecodeError::from(
+                        ::std::io::Error::new(
+                            ::std::io::ErrorKind::Other,
+                            "account discriminator mismatch",
+                        ),
+                    )
+                    .into(),
+            );
+        }
+        Self::deserialize(&mut reader)
+            .map_err(|e| ::solana_idl_parser::DecodeError::from(e).into())
+    }
+    /// Account data after the discriminator, without decoding it
+    ///
+    /// `None` if the data does not start with this account's
+    /// discriminator. Useful to cast the body with e.g. `bytemuck`
+    /// or to decode it lazily.
+    pub fn body(data: &[u8]) -> Option<&[u8]> {
+        data.strip_prefix(&VAULT_ACCOUNT_DISCRIMINATOR[..])
+    }
+}
+/// Instructions of the program; decode them with `deserialize` and encode them with `to_bytes`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenVaultInstructions {
+    CreateVault(CreateVaultAccounts, CreateVaultInstructionArgs),
+    Deposit(DepositAccounts, DepositArgs),
+}
+impl TokenVaultInstructions {
+    /// Decode instruction data held in a slice, `Vec`, array or anything else viewable as bytes
+    pub fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let buf = buf.as_ref();
+        let (discriminator, args) = buf
+            .split_first_chunk::<1usize>()
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::UnexpectedEof,
+                    "instruction data is shorter than its discriminator",
+                ),
+            ))?;
+        Self::deserialize_parts(discriminator, args, accounts)
+    }
+    /// Decode instruction data with borrowed account metas, like `deserialize`
+    pub fn from_slice(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        Self::deserialize(accounts, buf)
+    }
+    /// Decode an instruction whose discriminator and args payload are already split
+    pub fn deserialize_parts(
+        discriminator: &[u8; 1usize],
+        args: &[u8],
+        accounts: &[::solana_idl_parser::AccountMeta],
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        use ::borsh::BorshDeserialize as _;
+        let mut reader = args;
+        let ix = match *discriminator {
+            CREATE_VAULT_DISCRIMINATOR => {
+                Self::CreateVault(
+                    CreateVaultAccounts::from_account_metas(accounts)?,
+                    CreateVaultInstructionArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            DEPOSIT_DISCRIMINATOR => {
+                Self::Deposit(
+                    DepositAccounts::from_account_metas(accounts)?,
+                    DepositArgs::deserialize(&mut reader)
+                        .map_err(::solana_idl_parser::DecodeError::from)?,
+                )
+            }
+            _ => {
+                return Err(
+                    ::solana_idl_parser::DecodeError::unknown_discriminator(
+                            &discriminator[..],
+                            INSTRUCTION_DISCRIMINATORS,
+                        )
+                        .into(),
+                );
+            }
+        };
+        Ok(ix)
+    }
+}
+impl TokenVaultInstructions {
+    /// Decode an instruction, first mapping deployed discriminators to instruction names
+    ///
+    /// Patches around programs whose binary uses other discriminators than
+    /// the IDL, e.g. after an upgrade. Names are matched like in
+    /// `discriminator_of`; data without an overridden discriminator is
+    /// decoded as by `deserialize`.
+    pub fn deserialize_with_overrides(
+        overrides: &::std::collections::HashMap<[u8; 1usize], &str>,
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: impl AsRef<[u8]>,
+    ) -> ::core::result::Result<Self, ::anyhow::Error> {
+        let data = buf.as_ref();
+        let buf = data;
+        let Some((name, args)) = buf
+            .split_first_chunk::<1usize>()
+            .and_then(|(discriminator, args)| {
+                Some((*overrides.get(discriminator)?, args))
+            }) else {
+            return Self::deserialize(accounts, data);
+        };
+        let discriminator = discriminator_of(name)
+            .ok_or_else(|| ::solana_idl_parser::DecodeError::from(
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "discriminator override names unknown instruction `{}`", name
+                    ),
+                ),
+            ))?;
+        Self::deserialize_parts(&discriminator, args, accounts)
+    }
+    /// Decode an instruction whose data is base64 encoded
+    pub fn from_base64(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base64(data)?)
+    }
+    /// Decode an instruction whose data is base58 encoded
+    pub fn from_base58(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        data: &str,
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, &::solana_idl_parser::decode_base58(data)?)
+    }
+}
+impl ::solana_idl_parser::IdlInstructions for TokenVaultInstructions {
+    const PROGRAM_ID: ::solana_idl_parser::Pubkey = PROGRAM_ID;
+    fn deserialize(
+        accounts: &[::solana_idl_parser::AccountMeta],
+        buf: &[u8],
+    ) -> ::anyhow::Result<Self> {
+        Self::deserialize(accounts, buf)
+    }
+    fn discriminator(&self) -> &[u8] {
+        match self {
+            Self::CreateVault(..) => &CREATE_VAULT_DISCRIMINATOR,
+            Self::Deposit(..) => &DEPOSIT_DISCRIMINATOR,
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::CreateVault(..) => "CreateVault",
+            Self::Deposit(..) => "Deposit",
+        }
+    }
+}
+impl ::core::convert::From<(CreateVaultAccounts, CreateVaultInstructionArgs)>
+for TokenVaultInstructions {
+    fn from(payload: (CreateVaultAccounts, CreateVaultInstructionArgs)) -> Self {
+        Self::CreateVault(payload.0, payload.1)
+    }
+}
+impl ::core::convert::From<(DepositAccounts, DepositArgs)> for TokenVaultInstructions {
+    fn from(payload: (DepositAccounts, DepositArgs)) -> Self {
+        Self::Deposit(payload.0, payload.1)
+    }
+}
+/// Build the instruction like `into_instruction`, e.g. `TokenVaultInstructions::from((accounts, args)).try_into()`
+impl ::core::convert::TryFrom<TokenVaultInstructions>
+for ::solana_idl_parser::Instruction {
+    type Error = ::anyhow::Error;
+    fn try_from(
+        ix: TokenVaultInstructions,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        ix.into_instruction()
+    }
+}
+/// Writes the instruction data as `discriminator ++ borsh(args)`, like `to_bytes`
+///
+/// There is no `BorshDeserialize` counterpart since decoding needs the
+/// account metas; use `deserialize` instead.
+impl ::borsh::BorshSerialize for TokenVaultInstructions {
+    fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match self {
+            Self::CreateVault(_, args) => {
+                writer.write_all(&CREATE_VAULT_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+            Self::Deposit(_, args) => {
+                writer.write_all(&DEPOSIT_DISCRIMINATOR)?;
+                ::borsh::BorshSerialize::serialize(args, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl TokenVaultInstructions {
+    /// Serialize the instruction data as `discriminator ++ borsh(args)`
+    pub fn to_bytes(
+        &self,
+    ) -> ::core::result::Result<::std::vec::Vec<u8>, ::anyhow::Error> {
+        let mut data = ::std::vec::Vec::new();
+        ::borsh::BorshSerialize::serialize(self, &mut data)
+            .map_err(::solana_idl_parser::DecodeError::from)?;
+        Ok(data)
+    }
+    /// Build a ready-to-send instruction targeting `PROGRAM_ID`
+    pub fn into_instruction(
+        self,
+    ) -> ::core::result::Result<::solana_idl_parser::Instruction, ::anyhow::Error> {
+        let data = self.to_bytes()?;
+        let accounts = match &self {
+            Self::CreateVault(accounts, _) => accounts.to_account_metas(),
+            Self::Deposit(accounts, _) => accounts.to_account_metas(),
+        };
+        Ok(::solana_idl_parser::Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data,
+        })
+    }
+    /// The instruction's named accounts paired with their IDL names
+    pub fn named_accounts(
+        &self,
+    ) -> ::std::vec::Vec<(&'static str, ::solana_idl_parser::Pubkey)> {
+        match self {
+            Self::CreateVault(accounts, _) => accounts.named_accounts().to_vec(),
+            Self::Deposit(accounts, _) => accounts.named_accounts().to_vec(),
+        }
+    }
+}
+/// Callbacks for `accept`, one per instruction, all doing nothing by default
+pub trait TokenVaultVisitor {
+    ///Called by `accept` for `CreateVault` instructions
+    fn visit_create_vault(
+        &mut self,
+        _accounts: &CreateVaultAccounts,
+        _args: &CreateVaultInstructionArgs,
+    ) {}
+    ///Called by `accept` for `Deposit` instructions
+    fn visit_deposit(&mut self, _accounts: &DepositAccounts, _args: &DepositArgs) {}
+}
+impl TokenVaultInstructions {
+    /// Call the visitor method of this instruction with its accounts and args
+    pub fn accept<V: TokenVaultVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::CreateVault(accounts, args) => {
+                visitor.visit_create_vault(accounts, args)
+            }
+            Self::Deposit(accounts, args) => visitor.visit_deposit(accounts, args),
+        }
+    }
+}
+/// Which instruction some data holds, without its accounts or args
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TokenVaultInstructionKind {
+    ///`CreateVault`
+    CreateVault,
+    ///`Deposit`
+    Deposit,
+}
+impl TokenVaultInstructionKind {
+    /// The instruction whose discriminator starts `data`, without decoding the rest
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        if matches_create_vault_discriminator(data) {
+            return Some(Self::CreateVault);
+        }
+        if matches_deposit_discriminator(data) {
+            return Some(Self::Deposit);
+        }
+        None
+    }
+    /// Instruction name as written in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateVault => "CreateVault",
+            Self::Deposit => "Deposit",
+        }
+    }
+}
+impl TokenVaultInstructions {
+    /// Which instruction this is, without its accounts or args
+    pub fn kind(&self) -> TokenVaultInstructionKind {
+        match self {
+            Self::CreateVault(..) => TokenVaultInstructionKind::CreateVault,
+            Self::Deposit(..) => TokenVaultInstructionKind::Deposit,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+use solana_idl_parser_core::{parse_onchain_idl_account, LoadOptions};
+use std::io::Write;
+
+/// Data of an Anchor IDL account holding `json`, followed by unused space
+fn idl_account(json: &str) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(json.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut data = Sha256::digest("account:IdlAccount")[..8].to_vec();
+    data.extend_from_slice(&[7; 32]);
+    data.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    data.extend_from_slice(&compressed);
+    data.extend_from_slice(&[0; 64]);
+    data
+}
+
+#[test]
+fn compressed_idl_is_inflated_and_loaded() {
+    let json = std::fs::read_to_string("../tests/idls/native_token.json").unwrap();
+    let idl = parse_onchain_idl_account(&idl_account(&json), &LoadOptions::default()).unwrap();
+
+    assert_eq!(idl.address, "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    assert_eq!(idl.instructions.len(), 2);
+}
+
+#[test]
+fn truncated_account_data_is_an_error() {
+    let data = idl_account("{}");
+
+    let err = parse_onchain_idl_account(&data[..20], &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "IDL account data is shorter than its header"
+    );
+
+    let len = u32::from_le_bytes(data[40..44].try_into().unwrap());
+    let err = parse_onchain_idl_account(&data[..45], &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("IDL account data is shorter than its length of {}", len)
+    );
+}
+
+#[test]
+fn other_accounts_are_rejected() {
+    let mut data = idl_account("{}");
+    data[0] ^= 1;
+    let err = parse_onchain_idl_account(&data, &LoadOptions::default()).unwrap_err();
+    assert_eq!(err.to_string(), "account data is not an Anchor IDL account");
+}
@@ -0,0 +1,40 @@
+use solana_idl_parser_core::{parse_idl_str, LoadOptions};
+
+const UNKNOWN_KIND_IDL: &str = r#"{
+    "address": "11111111111111111111111111111111",
+    "metadata": { "name": "kinds", "version": "0.1.0", "spec": "0.1.0" },
+    "instructions": [],
+    "types": [
+        { "name": "Fee", "type": { "kind": "struct", "fields": [{ "name": "bps", "type": "u16" }] } },
+        { "name": "Layout", "type": { "kind": "union", "fields": [] } },
+        { "name": "Holder", "type": { "kind": "struct", "fields": [{ "name": "layout", "type": { "defined": { "name": "Layout" } } }] } }
+    ]
+}"#;
+
+#[test]
+fn unknown_type_kind_is_an_error_naming_the_type() {
+    let err = parse_idl_str(UNKNOWN_KIND_IDL, &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unsupported kind `union` of type `Layout`; pass `lenient = true` to skip it"
+    );
+}
+
+#[test]
+fn unknown_type_kind_is_skipped_with_a_warning_when_lenient() {
+    let options = LoadOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let idl = parse_idl_str(UNKNOWN_KIND_IDL, &options).unwrap();
+
+    let names: Vec<&str> = idl.types.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["Fee"]);
+    assert_eq!(
+        idl.warnings,
+        [
+            "skipped type `Holder`: uses skipped type `Layout`",
+            "skipped type `Layout`: unsupported kind `union`",
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+use solana_idl_parser_core::{load_idl, LoadOptions};
+use std::path::Path;
+
+#[test]
+fn malformed_idl_files_report_the_parse_error_and_path() {
+    let path = std::env::temp_dir().join(format!("malformed_idl_{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"{ "address": "11111111111111111111111111111111", "instructions": [ }"#,
+    )
+    .unwrap();
+    let err = load_idl(&path, &LoadOptions::default()).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    let message = err.to_string();
+    assert!(
+        message.starts_with(&format!("failed to parse IDL file {:?}: ", path)),
+        "{}",
+        message
+    );
+    assert!(message.contains("line 1 column"), "{}", message);
+}
+
+#[test]
+fn missing_idl_files_are_an_error() {
+    let path = Path::new("does/not/exist.json");
+    let err = load_idl(path, &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to read IDL file at \"does/not/exist.json\""
+    );
+}
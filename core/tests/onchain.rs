@@ -0,0 +1,69 @@
+#![cfg(feature = "fetch")]
+
+use solana_idl_parser_core::{idl_address, load_onchain_idl, LoadOptions};
+use std::path::PathBuf;
+
+const PROGRAM_ID: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+
+/// An RPC URL nothing listens on
+const UNREACHABLE_RPC: &str = "http://127.0.0.1:1";
+
+fn cache_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("solana_idl_parser_{}", test));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn idl_address_matches_anchor_derivation() {
+    let program: [u8; 32] = bs58::decode(PROGRAM_ID)
+        .into_vec()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        bs58::encode(idl_address(&program)).into_string(),
+        "C88XWfp26heEmDkmfSzeXP7Fd7GQJ2j9dDTUsyiZbUTa"
+    );
+}
+
+#[test]
+fn cached_idl_is_loaded_without_fetching() {
+    let dir = cache_dir("cached");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy(
+        "../tests/idls/native_token.json",
+        dir.join(format!("{}.json", PROGRAM_ID)),
+    )
+    .unwrap();
+
+    let idl = load_onchain_idl(PROGRAM_ID, UNREACHABLE_RPC, &dir, &LoadOptions::default()).unwrap();
+    assert_eq!(idl.instructions.len(), 2);
+}
+
+#[test]
+fn failed_fetch_names_the_program() {
+    let dir = cache_dir("unreachable");
+    let err =
+        load_onchain_idl(PROGRAM_ID, UNREACHABLE_RPC, &dir, &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("failed to fetch the IDL of {}", PROGRAM_ID)
+    );
+    assert!(!dir.exists());
+}
+
+#[test]
+fn invalid_program_id_is_an_error() {
+    let err = load_onchain_idl(
+        "not-base58!",
+        UNREACHABLE_RPC,
+        &cache_dir("invalid"),
+        &LoadOptions::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "program id not-base58! is not valid base58"
+    );
+}
@@ -0,0 +1,66 @@
+use solana_idl_parser_core::{load_idl, parse_idl_str, LoadOptions};
+
+const ADDRESS: &str = "Stake11111111111111111111111111111111111111";
+
+fn name_of(json: &str) -> String {
+    parse_idl_str(json, &LoadOptions::default())
+        .unwrap()
+        .metadata
+        .name
+}
+
+#[test]
+fn metadata_name_comes_first() {
+    let json = format!(
+        r#"{{ "address": "{}", "name": "top_level", "metadata": {{ "name": "counter" }}, "instructions": [] }}"#,
+        ADDRESS
+    );
+    assert_eq!(name_of(&json), "counter");
+}
+
+#[test]
+fn top_level_name_is_used_without_metadata_name() {
+    let json = format!(
+        r#"{{ "address": "{}", "name": "top_level", "instructions": [] }}"#,
+        ADDRESS
+    );
+    assert_eq!(name_of(&json), "top_level");
+}
+
+#[test]
+fn file_stem_is_used_without_a_name() {
+    let path = std::env::temp_dir().join(format!("stem_fallback_{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        format!(r#"{{ "address": "{}", "instructions": [] }}"#, ADDRESS),
+    )
+    .unwrap();
+    let idl = load_idl(&path, &LoadOptions::default());
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        idl.unwrap().metadata.name,
+        format!("stem_fallback_{}", std::process::id())
+    );
+}
+
+#[test]
+fn address_is_used_without_a_name_or_file() {
+    let json = format!(r#"{{ "address": "{}", "instructions": [] }}"#, ADDRESS);
+    assert_eq!(name_of(&json), "program_Stake111");
+}
+
+#[test]
+fn non_ascii_addresses_do_not_panic() {
+    let json = r#"{ "address": "aéééééééé", "instructions": [] }"#;
+    assert_eq!(name_of(json), "program_aééééééé");
+}
+
+#[test]
+fn missing_name_and_address_is_an_error() {
+    let err = parse_idl_str(r#"{ "instructions": [] }"#, &LoadOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot determine the program name: the IDL has no `metadata.name`, `name` or `address`"
+    );
+}
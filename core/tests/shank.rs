@@ -0,0 +1,64 @@
+use solana_idl_parser_core::{load_idl, parse_idl_str, IdlFormat, LoadOptions};
+use std::path::Path;
+
+fn sample() -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/idls/shank.json");
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn shank_idls_are_detected_from_the_metadata_origin() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/idls/shank.json");
+    let idl = load_idl(&path, &LoadOptions::default()).unwrap();
+
+    assert_eq!(idl.metadata.name, "token_vault");
+    assert_eq!(idl.address, "vau1tRCyUo9d5NwL6D5JxXgJyFz4jvxrHUBYxbqyXcL");
+    let discriminators: Vec<&[u8]> = idl
+        .instructions
+        .iter()
+        .map(|ix| ix.discriminator.as_slice())
+        .collect();
+    assert_eq!(discriminators, [&[0][..], &[1][..]]);
+
+    let accounts = &idl.instructions[0].accounts;
+    let flags: Vec<(bool, bool, bool)> = accounts
+        .iter()
+        .map(|acc| (acc.writable, acc.signer, acc.optional))
+        .collect();
+    assert_eq!(
+        flags,
+        [
+            (true, false, false),
+            (false, true, false),
+            (false, false, true)
+        ]
+    );
+    assert!(idl.accounts[0].discriminator.is_empty());
+}
+
+#[test]
+fn the_format_option_applies_shank_mapping_without_an_origin() {
+    let json = sample().replace(r#""origin": "shank","#, "");
+    assert!(!json.contains("origin"));
+
+    let options = LoadOptions {
+        format: Some(IdlFormat::Shank),
+        ..Default::default()
+    };
+    let idl = parse_idl_str(&json, &options).unwrap();
+    assert_eq!(idl.instructions[1].discriminator, [1]);
+
+    // Read as Anchor, the discriminant is ignored and a sighash used instead
+    let idl = parse_idl_str(&json, &LoadOptions::default()).unwrap();
+    assert_eq!(idl.instructions[1].discriminator.len(), 8);
+}
+
+#[test]
+fn wide_discriminants_are_little_endian() {
+    let json = sample().replace(
+        r#"{ "type": "u8", "value": 1 }"#,
+        r#"{ "type": "u32", "value": 258 }"#,
+    );
+    let idl = parse_idl_str(&json, &LoadOptions::default()).unwrap();
+    assert_eq!(idl.instructions[1].discriminator, [2, 1, 0, 0]);
+}
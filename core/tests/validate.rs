@@ -0,0 +1,197 @@
+use solana_idl_parser_core::{generate_idl_code, parse_idl_str, GenerateOptions, LoadOptions};
+
+const INCONSISTENT_IDL: &str = r#"{
+    "address": "11111111111111111111111111111111",
+    "metadata": { "name": "broken", "version": "0.1.0", "spec": "0.1.0" },
+    "instructions": [
+        {
+            "name": "open",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [],
+            "args": [{ "name": "config", "type": { "defined": { "name": "Config" } } }]
+        },
+        {
+            "name": "open",
+            "discriminator": [1, 2, 3],
+            "accounts": [],
+            "args": []
+        },
+        {
+            "name": "close",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [],
+            "args": []
+        }
+    ],
+    "types": [
+        {
+            "name": "Pool",
+            "type": {
+                "kind": "struct",
+                "fields": [
+                    { "name": "fee", "type": "u16" },
+                    { "name": "fee", "type": { "vec": { "defined": { "name": "Tier" } } } }
+                ]
+            }
+        }
+    ]
+}"#;
+
+/// Message of the `compile_error!` generated for `json`, if any
+fn validation_error(json: &str, options: &GenerateOptions) -> Option<String> {
+    let idl = parse_idl_str(json, &LoadOptions::default()).unwrap();
+    let tokens = generate_idl_code(&idl, options);
+    let file: syn::File = syn::parse2(tokens).unwrap();
+    file.items.iter().find_map(|item| match item {
+        syn::Item::Macro(item) if item.mac.path.is_ident("compile_error") => {
+            Some(item.mac.parse_body::<syn::LitStr>().unwrap().value())
+        }
+        _ => None,
+    })
+}
+
+#[test]
+fn every_problem_is_reported_at_once() {
+    let message = validation_error(INCONSISTENT_IDL, &GenerateOptions::default()).unwrap();
+    assert_eq!(
+        message,
+        "IDL `broken` failed validation:\n\
+         \x20 - duplicate instruction `open`\n\
+         \x20 - type `Pool` refers to unknown type `Tier`\n\
+         \x20 - duplicate field `fee` in type `Pool`\n\
+         \x20 - instruction `open` refers to unknown type `Config`\n\
+         \x20 - instruction `open` has a 3-byte discriminator, expected 8 bytes (or 1 for native programs)\n\
+         \x20 - instructions `open` and `close` have the same discriminator [1, 2, 3, 4, 5, 6, 7, 8]"
+    );
+}
+
+#[test]
+fn oversized_enums_are_reported() {
+    let variants: Vec<String> = (0..257)
+        .map(|i| format!(r#"{{ "name": "V{}" }}"#, i))
+        .collect();
+    let json = format!(
+        r#"{{
+            "address": "11111111111111111111111111111111",
+            "metadata": {{ "name": "wide", "version": "0.1.0", "spec": "0.1.0" }},
+            "instructions": [],
+            "types": [{{ "name": "Wide", "type": {{ "kind": "enum", "variants": [{}] }} }}]
+        }}"#,
+        variants.join(", ")
+    );
+    let message = validation_error(&json, &GenerateOptions::default()).unwrap();
+    assert!(message.ends_with(
+        "enum `Wide` has 257 variants, more than the 256 a u8 variant tag can address; declare a wider `repr` such as `u16`"
+    ));
+
+    // A two-byte tag fits them, while reprs other than integers and Anchor's layouts are rejected
+    let wide = json.replace(r#""kind": "enum","#, r#""kind": "enum", "repr": "u16","#);
+    assert_eq!(validation_error(&wide, &GenerateOptions::default()), None);
+    let signed = json.replace(r#""kind": "enum","#, r#""kind": "enum", "repr": "i16","#);
+    assert!(validation_error(&signed, &GenerateOptions::default())
+        .unwrap()
+        .contains("enum `Wide` has unsupported repr `i16`"));
+}
+
+#[test]
+fn validation_can_be_turned_off() {
+    let options = GenerateOptions {
+        validate: false,
+        ..Default::default()
+    };
+    assert_eq!(validation_error(INCONSISTENT_IDL, &options), None);
+}
+
+#[test]
+fn generated_names_taken_by_types_are_reported() {
+    let json = r#"{
+        "address": "11111111111111111111111111111111",
+        "metadata": { "name": "market", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "swap",
+                "discriminator": [1, 0, 0, 0, 0, 0, 0, 0],
+                "accounts": [{ "name": "pool" }],
+                "args": []
+            },
+            {
+                "name": "buy",
+                "discriminator": [2, 0, 0, 0, 0, 0, 0, 0],
+                "accounts": [],
+                "args": [{ "name": "amount", "type": "u64" }]
+            }
+        ],
+        "types": [
+            { "name": "SwapAccounts", "type": { "kind": "struct", "fields": [] } },
+            { "name": "BuyArgs", "type": { "kind": "struct", "fields": [] } },
+            { "name": "BuyInstructionArgs", "type": { "kind": "struct", "fields": [] } },
+            { "name": "MarketInstructions", "type": { "kind": "struct", "fields": [] } }
+        ]
+    }"#;
+    let message = validation_error(json, &GenerateOptions::default()).unwrap();
+    assert_eq!(
+        message,
+        "IDL `market` failed validation:\n\
+         \x20 - `SwapAccounts` would be generated for both type `SwapAccounts` and the accounts of instruction `swap`\n\
+         \x20 - `BuyInstructionArgs` would be generated for both type `BuyInstructionArgs` and the args of instruction `buy`\n\
+         \x20 - `MarketInstructions` would be generated for both type `MarketInstructions` and the program"
+    );
+
+    // Instruction modules hold their own structs, so only the program-wide names clash
+    let options = GenerateOptions {
+        instruction_modules: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        validation_error(json, &options).unwrap(),
+        "IDL `market` failed validation:\n\
+         \x20 - `MarketInstructions` would be generated for both type `MarketInstructions` and the program"
+    );
+    let shadowing = json.replace(r#""name": "BuyArgs""#, r#""name": "Args""#);
+    assert!(validation_error(&shadowing, &options)
+        .unwrap()
+        .contains("type `Args` is shadowed inside instruction modules by their own `Args`"));
+
+    // Without instruction code, only the types are generated
+    let options = GenerateOptions {
+        types_only: true,
+        ..Default::default()
+    };
+    assert_eq!(validation_error(json, &options), None);
+}
+
+const COUNTER_IDL: &str = r#"{
+    "address": "11111111111111111111111111111111",
+    "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+    "instructions": [
+        { "name": "initialize", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8], "accounts": [], "args": [] },
+        { "name": "setValue", "discriminator": [9, 9, 9, 9, 9, 9, 9, 9], "accounts": [], "args": [] }
+    ]
+}"#;
+
+#[test]
+fn legacy_discriminators_clashing_with_other_instructions_are_reported() {
+    let options = GenerateOptions {
+        legacy_discriminators: [("set_value".to_string(), vec![vec![1, 2, 3, 4, 5, 6, 7, 8]])]
+            .into(),
+        ..Default::default()
+    };
+    let message = validation_error(COUNTER_IDL, &options).unwrap();
+    assert!(
+        message.contains(
+            "instructions `initialize` and `setValue` have the same discriminator [1, 2, 3, 4, 5, 6, 7, 8]"
+        ),
+        "{}",
+        message
+    );
+}
+
+#[test]
+#[should_panic(expected = "`legacy_discriminators` names unknown instruction `withdraw`")]
+fn legacy_discriminators_of_unknown_instructions_are_rejected() {
+    let options = GenerateOptions {
+        legacy_discriminators: [("withdraw".to_string(), vec![vec![0; 8]])].into(),
+        ..Default::default()
+    };
+    validation_error(COUNTER_IDL, &options);
+}
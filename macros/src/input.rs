@@ -0,0 +1,237 @@
+use solana_idl_parser_core::{GenerateOptions, IdlFormat};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitBool, LitInt, LitStr, Token};
+
+/// Parsed arguments of `parse_idl!`
+pub enum MacroInput {
+    /// `parse_idl!("path/to/idl.json", option = value, ...)`
+    Single {
+        path: LitStr,
+        options: GenerateOptions,
+    },
+    /// `parse_idl! { module => "path/to/idl.json", ..., option = value, ... }`
+    Multi {
+        entries: Vec<ModuleEntry>,
+        options: GenerateOptions,
+    },
+}
+
+/// Parsed arguments of `parse_idl_dir!`
+pub struct DirInput {
+    pub dir: LitStr,
+    pub options: GenerateOptions,
+}
+
+/// A single `module => "path"` entry of the multi-IDL form
+pub struct ModuleEntry {
+    pub module: Ident,
+    pub path: LitStr,
+}
+
+impl Parse for MacroInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let path = input.parse()?;
+            let options = parse_options(input)?;
+            return Ok(MacroInput::Single { path, options });
+        }
+
+        let mut entries = Vec::new();
+        let mut options = default_options();
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if input.peek(Token![=>]) {
+                input.parse::<Token![=>]>()?;
+                let path = input.parse()?;
+                entries.push(ModuleEntry { module: key, path });
+            } else {
+                parse_multi_option(&key, input, &mut options)?;
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        if entries.is_empty() {
+            return Err(input.error("expected an IDL path or `module => \"path\"` entries"));
+        }
+        Ok(MacroInput::Multi { entries, options })
+    }
+}
+
+impl Parse for DirInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let dir = input.parse()?;
+        let mut options = default_options();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            parse_multi_option(&key, input, &mut options)?;
+        }
+        Ok(DirInput { dir, options })
+    }
+}
+
+/// Parsed arguments of `parse_idl_onchain!`
+#[cfg(feature = "fetch")]
+pub struct OnchainInput {
+    pub program_id: LitStr,
+    pub rpc_url: LitStr,
+    pub options: GenerateOptions,
+}
+
+#[cfg(feature = "fetch")]
+impl Parse for OnchainInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let program_id = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let rpc_url = input.parse()?;
+        let options = parse_options(input)?;
+        Ok(OnchainInput {
+            program_id,
+            rpc_url,
+            options,
+        })
+    }
+}
+
+/// Options before any `key = value` is applied
+///
+/// Code generation does not look at Cargo features itself, since its crate
+/// has them unified across the build; features of this crate set the options
+/// they stand for instead.
+fn default_options() -> GenerateOptions {
+    GenerateOptions {
+        serde: cfg!(feature = "serde"),
+        fuzz: cfg!(feature = "fuzz"),
+        schema: cfg!(feature = "schema"),
+        test_fixtures: cfg!(feature = "test-fixtures"),
+        minimal_pubkey: cfg!(feature = "minimal-pubkey"),
+        ..Default::default()
+    }
+}
+
+/// Parse the trailing `, key = value` options of the single-IDL form
+fn parse_options(input: ParseStream) -> syn::Result<GenerateOptions> {
+    let mut options = default_options();
+
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+
+        let key: Ident = input.parse()?;
+        parse_option(&key, input, &mut options)?;
+    }
+
+    Ok(options)
+}
+
+/// Parse the `= value` of option `key` in a form generating several IDLs
+///
+/// Options apply to every IDL, so those naming the instructions of a single
+/// IDL are rejected.
+fn parse_multi_option(
+    key: &Ident,
+    input: ParseStream,
+    options: &mut GenerateOptions,
+) -> syn::Result<()> {
+    if key == "legacy_discriminators" {
+        return Err(syn::Error::new(
+            key.span(),
+            "`legacy_discriminators` names the instructions of a single IDL and cannot be used with several IDLs",
+        ));
+    }
+    parse_option(key, input, options)
+}
+
+/// Parse the `= value` of option `key` into `options`
+fn parse_option(key: &Ident, input: ParseStream, options: &mut GenerateOptions) -> syn::Result<()> {
+    input.parse::<Token![=]>()?;
+    match key.to_string().as_str() {
+        "deny_trailing_bytes" => options.deny_trailing_bytes = input.parse::<LitBool>()?.value,
+        "lenient" => options.lenient = input.parse::<LitBool>()?.value,
+        "discriminator_in_args" => options.discriminator_in_args = input.parse::<LitBool>()?.value,
+        "discriminator_offset" => {
+            options.discriminator_offset = input.parse::<LitInt>()?.base10_parse()?
+        }
+        "no_std" => options.no_std = input.parse::<LitBool>()?.value,
+        "remaining_accounts" => options.remaining_accounts = input.parse::<LitBool>()?.value,
+        "dispatch_table" => options.dispatch_table = input.parse::<LitBool>()?.value,
+        "types_only" => options.types_only = input.parse::<LitBool>()?.value,
+        "instruction_modules" => options.instruction_modules = input.parse::<LitBool>()?.value,
+        "validate" => options.validate = input.parse::<LitBool>()?.value,
+        "serde_idl_names" => {
+            let value: LitBool = input.parse()?;
+            if value.value && !cfg!(feature = "serde") {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "`serde_idl_names` requires the `serde` feature of solana_idl_parser",
+                ));
+            }
+            options.serde_idl_names = value.value;
+        }
+        "error" => {
+            let error: LitStr = input.parse()?;
+            error.parse::<syn::Path>()?;
+            options.error = Some(error.value());
+        }
+        "enum_name" => {
+            let name: LitStr = input.parse()?;
+            name.parse::<Ident>()?;
+            options.enum_name = Some(name.value());
+        }
+        "legacy_discriminators" => {
+            let content;
+            syn::braced!(content in input);
+            while !content.is_empty() {
+                let name: Ident = content.parse()?;
+                content.parse::<Token![:]>()?;
+                let bytes;
+                syn::bracketed!(bytes in content);
+                let bytes = Punctuated::<LitInt, Token![,]>::parse_terminated(&bytes)?
+                    .iter()
+                    .map(|byte| byte.base10_parse::<u8>())
+                    .collect::<syn::Result<Vec<u8>>>()?;
+                options
+                    .legacy_discriminators
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(bytes);
+                if content.is_empty() {
+                    break;
+                }
+                content.parse::<Token![,]>()?;
+            }
+        }
+        "format" => {
+            let format: LitStr = input.parse()?;
+            options.format = Some(match format.value().as_str() {
+                "anchor" => IdlFormat::Anchor,
+                "shank" => IdlFormat::Shank,
+                other => {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        format!(
+                            "unknown IDL format `{}`, expected `anchor` or `shank`",
+                            other
+                        ),
+                    ))
+                }
+            });
+        }
+        _ => {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("unknown parse_idl! option `{}`", key),
+            ))
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+#![cfg_attr(feature = "nightly", feature(proc_macro_tracked_path))]
+
+mod input;
+
+use convert_case::{Case, Casing};
+use input::{DirInput, MacroInput};
+use proc_macro::TokenStream;
+use solana_idl_parser_core::{parser, GenerateOptions, LoadOptions};
+use std::path::{Path, PathBuf};
+use syn::{parse_macro_input, LitStr};
+
+/// Parse an Anchor IDL JSON file and generate Rust structs.
+///
+/// This macro reads the IDL file at compile time and generates:
+/// - Discriminator constants for each instruction
+/// - Accounts structs for each instruction (with `from_account_metas` method)
+/// - Args structs for each instruction
+/// - A main enum containing all instructions
+/// - A deserialize implementation for the enum
+/// - Type definitions from the IDL types section
+///
+/// # Arguments
+///
+/// * `path` - Path to the IDL JSON file (relative to the crate root or absolute)
+///
+/// # Options
+///
+/// Options follow the path as `key = value` pairs:
+///
+/// * `deny_trailing_bytes = true` - Reject instruction data with bytes left after the args
+/// * `format = "anchor" | "shank"` - IDL format, detected from `metadata.origin` by default
+/// * `serde_idl_names = true` - With the `serde` feature, serialize under the original IDL names
+/// * `lenient = true` - Skip unsupported types with a warning instead of failing; instructions
+///   using them return an error from `deserialize`
+/// * `discriminator_in_args = true` - Instruction args start with the discriminator, as in
+///   native programs whose instruction enum tag is the first byte
+/// * `discriminator_offset = 1` - Skip this many header bytes, e.g. a version byte, before the
+///   discriminator
+/// * `no_std = true` - Generate code using only `core`, `alloc` and `borsh`, for `no_std` crates
+/// * `remaining_accounts = true` - Keep account metas past the named accounts in a
+///   `remaining_accounts` field instead of rejecting them
+/// * `dispatch_table = true` - Decode through a sorted discriminator table searched by bisection
+///   instead of a `match`
+/// * `types_only = true` - Generate only type definitions and program accounts, no instruction code
+/// * `instruction_modules = true` - Put each instruction's structs, constants and helpers in a
+///   module named after it, e.g. `buy::Accounts` and `buy::DISCRIMINATOR`
+/// * `error = "crate::MyError"` - Error type of the generated functions instead of
+///   `anyhow::Error`; it must implement `From<solana_idl_parser::DecodeError>`
+/// * `enum_name = "PumpAMM"` - Base name of `PumpAMMInstructions`, `PumpAMMEvents` and the
+///   other program-wide types instead of the program name in PascalCase
+/// * `legacy_discriminators = { swap: [1, 2, 3, 4, 5, 6, 7, 8] }` - Extra discriminators an
+///   instruction was sent with by earlier program versions; names may repeat to give several
+/// * `validate = false` - Skip the consistency check of the IDL, which reports unresolved types,
+///   duplicate names, malformed discriminators and oversized enums
+///
+/// Several IDLs can be parsed at once by mapping module names to paths. Each
+/// IDL is generated into its own module, and a top-level `AnyInstruction` enum
+/// plus a `decode_any` function dispatch on the program id. Options may follow
+/// the entries and apply to every IDL, except `legacy_discriminators`, which
+/// names the instructions of a single IDL.
+///
+/// # Example
+///
+/// ```ignore
+/// solana_idl_parser::parse_idl!("idl/pump_amm.json");
+///
+/// // Now you can use the generated types:
+/// let ix = PumpAmmInstructions::deserialize(&accounts, &data)?;
+/// ```
+///
+/// ```ignore
+/// solana_idl_parser::parse_idl! {
+///     pump_amm => "idl/pump_amm.json",
+///     pump => "idl/pump.json",
+///     deny_trailing_bytes = true,
+/// }
+///
+/// if let Some(ix) = decode_any(&program_id, &accounts, &data) {
+///     match ix? {
+///         AnyInstruction::PumpAmm(ix) => println!("{:?}", ix),
+///         AnyInstruction::Pump(ix) => println!("{:?}", ix),
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn parse_idl(input: TokenStream) -> TokenStream {
+    let mut tracked = proc_macro2::TokenStream::new();
+    let generated = match parse_macro_input!(input as MacroInput) {
+        MacroInput::Single { path, options } => load_idl(&path, &options).map(|idl| {
+            tracked.extend(track_file(&resolve_path(&path.value())));
+            solana_idl_parser_core::generate_idl_code(&idl, &options)
+        }),
+        MacroInput::Multi { entries, options } => entries
+            .into_iter()
+            .map(|entry| {
+                let idl = load_idl(&entry.path, &options)?;
+                tracked.extend(track_file(&resolve_path(&entry.path.value())));
+                Ok((entry.module, idl))
+            })
+            .collect::<syn::Result<Vec<_>>>()
+            .map(|programs| solana_idl_parser_core::generate_multi_idl_code(&programs, &options)),
+    };
+    match generated {
+        Ok(generated) => quote::quote! {
+            #tracked
+            #generated
+        }
+        .into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Parse every `*.json` IDL in a directory, like the multi-IDL form of [`parse_idl!`]
+///
+/// Each IDL is generated into a module named after its file stem in snake
+/// case, and `AnyInstruction`/`decode_any` cover all of them. A file that
+/// fails to load is reported as a compile error naming it, while the other
+/// files are still generated. Takes the same options as the multi-IDL form.
+///
+/// Editing an IDL expands the macro again, as with [`parse_idl!`]. Adding or
+/// removing a file only does with the `nightly` feature, which also tracks the
+/// directory; on stable, touch the calling source file after doing so.
+///
+/// # Example
+///
+/// ```ignore
+/// // idls/pump_amm.json and idls/pump.json become `pump_amm` and `pump`
+/// solana_idl_parser::parse_idl_dir!("idls/");
+///
+/// solana_idl_parser::parse_idl_dir!("idls/", deny_trailing_bytes = true);
+/// ```
+#[proc_macro]
+pub fn parse_idl_dir(input: TokenStream) -> TokenStream {
+    let DirInput { dir, options } = parse_macro_input!(input as DirInput);
+    let dir_path = resolve_path(&dir.value());
+
+    let entries = match std::fs::read_dir(&dir_path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            let message = format!("failed to read IDL directory {:?}: {}", dir_path, e);
+            return syn::Error::new(dir.span(), message)
+                .into_compile_error()
+                .into();
+        }
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    let mut programs = Vec::new();
+    let mut errors = proc_macro2::TokenStream::new();
+    let mut tracked = track_dir(&dir_path);
+    for file in files {
+        let loaded = module_ident(&file, dir.span())
+            .and_then(|module| load_idl_file(&file, dir.span(), &options).map(|idl| (module, idl)));
+        match loaded {
+            Ok(program) => {
+                tracked.extend(track_file(&file));
+                programs.push(program);
+            }
+            Err(e) => errors.extend(e.into_compile_error()),
+        }
+    }
+
+    let generated = solana_idl_parser_core::generate_multi_idl_code(&programs, &options);
+    quote::quote! {
+        #errors
+        #tracked
+        #generated
+    }
+    .into()
+}
+
+/// Fetch a program's Anchor IDL from chain at compile time and generate code like [`parse_idl!`]
+///
+/// Requires the `fetch` feature. The IDL account of the program is read over
+/// the RPC endpoint and its JSON cached as `idl-cache/<program id>.json` in
+/// the crate root. Later builds use the cached file without network access,
+/// so commit it for reproducible builds and delete it to fetch again.
+///
+/// Takes the same options as the single-IDL form of [`parse_idl!`].
+///
+/// # Example
+///
+/// ```ignore
+/// solana_idl_parser::parse_idl_onchain!(
+///     "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+///     "https://api.mainnet-beta.solana.com",
+/// );
+/// ```
+#[cfg(feature = "fetch")]
+#[proc_macro]
+pub fn parse_idl_onchain(input: TokenStream) -> TokenStream {
+    let input::OnchainInput {
+        program_id,
+        rpc_url,
+        options,
+    } = parse_macro_input!(input as input::OnchainInput);
+    let load_options = LoadOptions {
+        format: options.format,
+        lenient: options.lenient,
+    };
+
+    let cache_dir = resolve_path("idl-cache");
+
+    solana_idl_parser_core::load_onchain_idl(
+        &program_id.value(),
+        &rpc_url.value(),
+        &cache_dir,
+        &load_options,
+    )
+    .map(|idl| {
+        // Tracking the cache file makes deleting it fetch the IDL again
+        let tracked = track_file(&cache_dir.join(format!("{}.json", program_id.value())));
+        let generated = solana_idl_parser_core::generate_idl_code(&idl, &options);
+        quote::quote! {
+            #tracked
+            #generated
+        }
+    })
+    .unwrap_or_else(|e| syn::Error::new(program_id.span(), format!("{:#}", e)).into_compile_error())
+    .into()
+}
+
+/// Module name for an IDL file, its file stem in snake case
+fn module_ident(file: &Path, span: proc_macro2::Span) -> syn::Result<syn::Ident> {
+    let stem = file
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_case(Case::Snake);
+    syn::parse_str::<syn::Ident>(&stem)
+        .map(|ident| syn::Ident::new(&ident.to_string(), span))
+        .map_err(|_| {
+            syn::Error::new(
+                span,
+                format!("IDL file {:?} does not make a valid module name", file),
+            )
+        })
+}
+
+/// Item making the compiler track the file at `path`, so editing it expands the macro again
+///
+/// Proc macros reading files are not rerun when the files change. With the
+/// `nightly` feature the file is registered through `proc_macro::tracked`.
+/// Otherwise the `include_bytes!` of an unused constant records it as a
+/// dependency of the crate without putting its bytes in the binary; paths
+/// that are not UTF-8 cannot be named in the literal and are left untracked.
+#[cfg(feature = "nightly")]
+fn track_file(path: &Path) -> proc_macro2::TokenStream {
+    proc_macro::tracked::path(path);
+    proc_macro2::TokenStream::new()
+}
+
+#[cfg(not(feature = "nightly"))]
+fn track_file(path: &Path) -> proc_macro2::TokenStream {
+    match path.to_str() {
+        Some(path) => quote::quote! { const _: &[u8] = include_bytes!(#path); },
+        None => proc_macro2::TokenStream::new(),
+    }
+}
+
+/// Item making the compiler track the directory at `path`, so adding or removing files expands the macro again
+///
+/// Only possible with the `nightly` feature: `include_bytes!` cannot name a
+/// directory, so on stable nothing is tracked.
+#[cfg(feature = "nightly")]
+fn track_dir(path: &Path) -> proc_macro2::TokenStream {
+    proc_macro::tracked::path(path);
+    proc_macro2::TokenStream::new()
+}
+
+#[cfg(not(feature = "nightly"))]
+fn track_dir(_path: &Path) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Resolve a path relative to the crate root, unless it is absolute
+fn resolve_path(path_str: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+
+    if PathBuf::from(path_str).is_absolute() {
+        PathBuf::from(path_str)
+    } else {
+        PathBuf::from(&manifest_dir).join(path_str)
+    }
+}
+
+/// Read and parse the IDL file at `path`, resolved against the crate root
+fn load_idl(path: &LitStr, options: &GenerateOptions) -> syn::Result<parser::Idl> {
+    load_idl_file(&resolve_path(&path.value()), path.span(), options)
+}
+
+/// Read and parse the IDL file at `idl_path`, reporting errors at `span`
+///
+/// See [`solana_idl_parser_core::load_idl`] for how the IDL is interpreted.
+fn load_idl_file(
+    idl_path: &Path,
+    span: proc_macro2::Span,
+    options: &GenerateOptions,
+) -> syn::Result<parser::Idl> {
+    let load_options = LoadOptions {
+        format: options.format,
+        lenient: options.lenient,
+    };
+    solana_idl_parser_core::load_idl(idl_path, &load_options)
+        .map_err(|e| syn::Error::new(span, format!("{:#}", e)))
+}
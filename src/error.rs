@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Error of the functions generated by [`parse_idl!`](crate::parse_idl), which mostly decode data
+///
+/// Generated functions return it converted into their error type: `anyhow::Error`
+/// by default, borsh's I/O error with `no_std = true`, or the type named by the
+/// `error` option.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// Malformed data, e.g. too short or invalid borsh
+    Io(borsh::io::Error),
+    /// An instruction was given a number of account metas it does not take
+    ///
+    /// `expected` is the closest count the instruction accepts: its minimum
+    /// when too few metas were given, its maximum when too many were.
+    InvalidAccountLen { expected: usize, got: usize },
+    /// Instruction data starts with no known instruction discriminator
+    ///
+    /// `discriminator` holds the bytes read in its place, as many as the
+    /// longest known discriminator. `closest` names the instruction whose
+    /// discriminator differs from them in the fewest bits, unless the IDL
+    /// was generated with `validate = false`.
+    UnknownDiscriminator {
+        discriminator: Vec<u8>,
+        closest: Option<&'static str>,
+    },
+}
+
+impl DecodeError {
+    /// The error for `discriminator`, naming the closest of the `known` `(name, discriminator)` pairs
+    ///
+    /// Bits are compared over the bytes both have; each byte only one of them
+    /// has counts as 8 differing bits.
+    pub fn unknown_discriminator(discriminator: &[u8], known: &[(&'static str, &[u8])]) -> Self {
+        let distance = |other: &[u8]| -> u32 {
+            let common: u32 = discriminator
+                .iter()
+                .zip(other)
+                .map(|(a, b)| (a ^ b).count_ones())
+                .sum();
+            common + 8 * discriminator.len().abs_diff(other.len()) as u32
+        };
+        let closest = known
+            .iter()
+            .min_by_key(|(_, other)| distance(other))
+            .map(|(name, _)| *name);
+        DecodeError::UnknownDiscriminator {
+            discriminator: discriminator.to_vec(),
+            closest,
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Io(e) => e.fmt(f),
+            DecodeError::InvalidAccountLen { expected, got } => write!(
+                f,
+                "invalid account meta length: expected {}, got {}",
+                expected, got
+            ),
+            DecodeError::UnknownDiscriminator {
+                discriminator,
+                closest,
+            } => {
+                write!(f, "unknown discriminator {:?}", discriminator)?;
+                match closest {
+                    Some(name) => write!(f, ", closest to instruction `{}`", name),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            // borsh's own I/O error, used without `std`, is not an `Error`
+            #[cfg(feature = "std")]
+            DecodeError::Io(e) => Some(e),
+            #[cfg(not(feature = "std"))]
+            DecodeError::Io(_) => None,
+            DecodeError::InvalidAccountLen { .. } | DecodeError::UnknownDiscriminator { .. } => {
+                None
+            }
+        }
+    }
+}
+
+impl From<borsh::io::Error> for DecodeError {
+    fn from(e: borsh::io::Error) -> Self {
+        DecodeError::Io(e)
+    }
+}
+
+impl From<DecodeError> for borsh::io::Error {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::Io(e) => e,
+            #[cfg(feature = "std")]
+            e => borsh::io::Error::new(borsh::io::ErrorKind::InvalidInput, e),
+            // Without `std`, borsh's I/O error only holds a message
+            #[cfg(not(feature = "std"))]
+            e => borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidInput,
+                alloc::string::ToString::to_string(&e),
+            ),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Pubkey;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Conversion to the JSON written by `decode_to_json` and `to_anchor_json`
+///
+/// Values keep the shape their serde derive gives them, except that keys are
+/// written as base58 strings rather than arrays of 32 numbers, and integers
+/// too large for JSON numbers, e.g. `u128` token amounts past `u64::MAX`, as
+/// decimal strings. Generated types implement it with the `serde` feature.
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+impl ToJson for Pubkey {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+macro_rules! number_to_json {
+    ($($ty:ty),*) => {$(
+        impl ToJson for $ty {
+            fn to_json(&self) -> Value {
+                Value::from(*self)
+            }
+        }
+    )*};
+}
+
+number_to_json!(bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl ToJson for u128 {
+    fn to_json(&self) -> Value {
+        match u64::try_from(*self) {
+            Ok(value) => Value::from(value),
+            Err(_) => Value::String(self.to_string()),
+        }
+    }
+}
+
+impl ToJson for i128 {
+    fn to_json(&self) -> Value {
+        match i64::try_from(*self) {
+            Ok(value) => Value::from(value),
+            Err(_) => Value::String(self.to_string()),
+        }
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToJson::to_json)
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> Value {
+        self.iter().map(ToJson::to_json).collect()
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> Value {
+        self.iter().map(ToJson::to_json).collect()
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeSet<T> {
+    fn to_json(&self) -> Value {
+        self.iter().map(ToJson::to_json).collect()
+    }
+}
+
+impl<T: ToJson, S> ToJson for HashSet<T, S> {
+    fn to_json(&self) -> Value {
+        self.iter().map(ToJson::to_json).collect()
+    }
+}
+
+impl<K: ToJson, V: ToJson> ToJson for BTreeMap<K, V> {
+    fn to_json(&self) -> Value {
+        object(self.iter())
+    }
+}
+
+impl<K: ToJson, V: ToJson, S> ToJson for HashMap<K, V, S> {
+    fn to_json(&self) -> Value {
+        object(self.iter())
+    }
+}
+
+/// JSON keys are strings, so other keys are written the way serde_json writes them
+fn object<'a, K: ToJson + 'a, V: ToJson + 'a>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+) -> Value {
+    let entries = entries.map(|(key, value)| {
+        let key = match key.to_json() {
+            Value::String(key) => key,
+            key => key.to_string(),
+        };
+        (key, value.to_json())
+    });
+    Value::Object(entries.collect::<Map<String, Value>>())
+}
+
+macro_rules! tuple_to_json {
+    ($(($($name:ident),+))*) => {$(
+        impl<$($name: ToJson),+> ToJson for ($($name,)+) {
+            fn to_json(&self) -> Value {
+                #[allow(non_snake_case)]
+                let ($($name,)+) = self;
+                Value::Array(vec![$($name.to_json()),+])
+            }
+        }
+    )*};
+}
+
+tuple_to_json! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+    (A, B, C, D, E, F, G, H, I)
+    (A, B, C, D, E, F, G, H, I, J)
+    (A, B, C, D, E, F, G, H, I, J, K)
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+    (A, B, C, D, E, F, G, H, I, J, K, L, M)
+    (A, B, C, D, E, F, G, H, I, J, K, L, M, N)
+    (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O)
+    (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P)
+}
@@ -1,51 +1,134 @@
-mod generator;
-#[allow(dead_code)]
-mod parser;
+//! Parse Solana Anchor IDLs into Rust types at compile time.
+//!
+//! See [`parse_idl!`] for the generated code. Generated code refers to this
+//! crate's re-exported [`Pubkey`], [`AccountMeta`] and [`Instruction`] types,
+//! which come from the Solana SDK by default or from a minimal stand-in with
+//! the `minimal-pubkey` feature.
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs
+//! `alloc`, which together with `minimal-pubkey` serves code generated with
+//! `no_std = true`.
 
-use proc_macro::TokenStream;
-use std::path::PathBuf;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-/// Parse an Anchor IDL JSON file and generate Rust structs.
-///
-/// This macro reads the IDL file at compile time and generates:
-/// - Discriminator constants for each instruction
-/// - Accounts structs for each instruction (with `from_account_metas` method)
-/// - Args structs for each instruction
-/// - A main enum containing all instructions
-/// - A deserialize implementation for the enum
-/// - Type definitions from the IDL types section
-///
-/// # Arguments
-///
-/// * `path` - Path to the IDL JSON file (relative to the crate root or absolute)
-///
-/// # Example
+extern crate alloc;
+
+mod error;
+#[cfg(feature = "serde")]
+pub mod json;
+#[cfg(feature = "minimal-pubkey")]
+mod minimal;
+#[cfg(all(feature = "rpc", not(feature = "minimal-pubkey")))]
+pub mod rpc;
+#[cfg(all(feature = "sdk", not(feature = "minimal-pubkey")))]
+pub mod transaction;
+#[cfg(all(feature = "yellowstone", not(feature = "minimal-pubkey")))]
+pub mod yellowstone;
+
+pub use error::DecodeError;
+#[cfg(feature = "fetch")]
+pub use solana_idl_parser_macros::parse_idl_onchain;
+pub use solana_idl_parser_macros::{parse_idl, parse_idl_dir};
+
+use alloc::{format, vec::Vec};
+
+/// Used by generated code when the `serde` feature is enabled
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde_json;
+
+#[cfg(feature = "minimal-pubkey")]
+pub use minimal::{AccountMeta, Instruction, Pubkey};
+#[cfg(not(feature = "minimal-pubkey"))]
+pub use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+#[cfg(not(any(feature = "sdk", feature = "minimal-pubkey")))]
+compile_error!("either the `sdk` or the `minimal-pubkey` feature must be enabled");
+
+/// Prefix of the instruction data Anchor's `emit_cpi!` invokes the program with,
+/// followed by the event discriminator and fields
+pub const EVENT_CPI_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Implemented by every instructions enum generated by [`parse_idl!`]
+pub trait IdlInstructions: Sized {
+    /// Id of the program whose instructions this type decodes
+    const PROGRAM_ID: Pubkey;
+
+    /// Decode an instruction from its account metas and data
+    fn deserialize(accounts: &[AccountMeta], data: &[u8]) -> anyhow::Result<Self>;
+
+    /// Discriminator of this instruction
+    fn discriminator(&self) -> &[u8];
+
+    /// Instruction name as written in the IDL
+    fn name(&self) -> &str;
+}
+
+/// Implemented by every events enum generated by [`parse_idl!`]
+pub trait IdlEvents: Sized {
+    /// Id of the program emitting these events
+    const PROGRAM_ID: Pubkey;
+
+    /// Decode event data starting with the discriminator of any of the program's events
+    fn from_event_data(data: &[u8]) -> anyhow::Result<Self>;
+}
+
+/// Lazily decode a stream of instructions, each given as its account metas and data
 ///
-/// ```ignore
-/// solana_idl_parser::parse_idl!("idl/pump_amm.json");
+/// Nothing is decoded until the returned iterator is advanced, so large
+/// batches can be filtered or stopped early without collecting them first.
+/// Errors name the position of the failing instruction in the stream, and
+/// still downcast to the decoding error.
+pub fn decode_many<'a, T, I>(items: I) -> impl Iterator<Item = anyhow::Result<T>> + 'a
+where
+    T: IdlInstructions + 'a,
+    I: IntoIterator<Item = (&'a [AccountMeta], &'a [u8])>,
+    I::IntoIter: 'a,
+{
+    use anyhow::Context as _;
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, (accounts, data))| {
+            T::deserialize(accounts, data)
+                .with_context(|| format!("failed to decode instruction {}", index))
+        })
+}
+
+/// Decode a borsh `Vec<u8>`, failing unless it holds exactly `N` bytes
 ///
-/// // Now you can use the generated types:
-/// let ix = PumpAmmInstructions::deserialize(accounts, &data)?;
-/// ```
-#[proc_macro]
-pub fn parse_idl(input: TokenStream) -> TokenStream {
-    let input_str = input.to_string();
-    let path_str = input_str.trim().trim_matches('"');
-
-    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
-
-    let idl_path = if PathBuf::from(path_str).is_absolute() {
-        PathBuf::from(path_str)
-    } else {
-        PathBuf::from(&manifest_dir).join(path_str)
-    };
-
-    let idl_content = std::fs::read_to_string(&idl_path)
-        .unwrap_or_else(|e| panic!("Failed to read IDL file at {:?}: {}", idl_path, e));
-
-    let idl: parser::Idl =
-        serde_json::from_str(&idl_content).unwrap_or_else(|e| panic!("Failed to parse IDL: {}", e));
-
-    let generated = generator::generate_idl_code(&idl);
-    generated.into()
+/// Used by generated code for `bytes` fields with a `len`, for IDLs declaring
+/// fixed-size values like hashes as `bytes`.
+pub fn deserialize_bytes_of_len<R: borsh::io::Read, const N: usize>(
+    reader: &mut R,
+) -> borsh::io::Result<Vec<u8>> {
+    let bytes: Vec<u8> = borsh::BorshDeserialize::deserialize_reader(reader)?;
+    if bytes.len() != N {
+        return Err(borsh::io::Error::new(
+            borsh::io::ErrorKind::InvalidData,
+            format!("expected {} bytes, got {}", N, bytes.len()),
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Decode base64 instruction data, as found in RPC `getTransaction` responses
+pub fn decode_base64(data: &str) -> anyhow::Result<Vec<u8>> {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| anyhow::anyhow!("instruction data is not valid base64: {}", e))
+}
+
+/// Decode base58 instruction data, as found in older RPC responses
+pub fn decode_base58(data: &str) -> anyhow::Result<Vec<u8>> {
+    bs58::decode(data)
+        .into_vec()
+        .map_err(|e| anyhow::anyhow!("instruction data is not valid base58: {}", e))
 }
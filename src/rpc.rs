@@ -0,0 +1,138 @@
+//! Decoding of transactions fetched over JSON-RPC
+//!
+//! `getTransaction` returns an [`EncodedConfirmedTransactionWithStatusMeta`].
+//! [`decode_rpc_transaction`] decodes it and resolves its accounts like
+//! [`decode_transaction`], so backfills can share the decoding of a Geyser
+//! stream.
+
+use crate::transaction::{self, decode_all_events, decode_transaction};
+use crate::{IdlEvents, IdlInstructions};
+use anyhow::{anyhow, Context};
+use solana_sdk::{
+    message::{compiled_instruction::CompiledInstruction, v0::LoadedAddresses},
+    pubkey::Pubkey,
+    transaction::VersionedTransaction,
+};
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta, UiInstruction,
+    UiTransactionStatusMeta,
+};
+use std::str::FromStr;
+
+/// Decode every instruction of `T`'s program in a transaction returned by `getTransaction`
+///
+/// The transaction must be requested with the `base64` or `base58` encoding;
+/// `json` and `jsonParsed` transactions are errors. Results are those of
+/// [`decode_transaction`]. A transaction without status meta is decoded
+/// without loaded addresses or inner instructions.
+pub fn decode_rpc_transaction<T: IdlInstructions>(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> anyhow::Result<Vec<(Option<Pubkey>, anyhow::Result<T>)>> {
+    let transaction = versioned_transaction(tx)?;
+    let (loaded_addresses, inner_instructions) = match &tx.transaction.meta {
+        Some(meta) => (loaded_addresses(meta)?, inner_instructions(meta)?),
+        None => (LoadedAddresses::default(), Vec::new()),
+    };
+
+    Ok(decode_transaction(
+        &transaction.message,
+        &loaded_addresses,
+        &inner_instructions,
+    ))
+}
+
+/// Decode every event of `T`'s program emitted by a transaction returned by `getTransaction`
+///
+/// Encodings are handled as in [`decode_rpc_transaction`]; events are those of
+/// [`decode_all_events`]. A transaction without status meta has no events.
+pub fn decode_rpc_events<T: IdlEvents>(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> anyhow::Result<Vec<(usize, anyhow::Result<T>)>> {
+    let transaction = versioned_transaction(tx)?;
+    let Some(meta) = &tx.transaction.meta else {
+        return Ok(Vec::new());
+    };
+    let log_messages = match &meta.log_messages {
+        OptionSerializer::Some(log_messages) => log_messages.as_slice(),
+        _ => &[],
+    };
+
+    Ok(decode_all_events(
+        &transaction.message,
+        &loaded_addresses(meta)?,
+        &inner_instructions(meta)?,
+        log_messages,
+    ))
+}
+
+/// The binary-encoded transaction of a `getTransaction` result
+pub fn versioned_transaction(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> anyhow::Result<VersionedTransaction> {
+    tx.transaction.transaction.decode().ok_or_else(|| {
+        anyhow!("transaction must be encoded as base64 or base58, and decode as a transaction")
+    })
+}
+
+/// Addresses loaded from lookup tables, empty for legacy transactions
+pub fn loaded_addresses(meta: &UiTransactionStatusMeta) -> anyhow::Result<LoadedAddresses> {
+    match &meta.loaded_addresses {
+        OptionSerializer::Some(loaded) => Ok(LoadedAddresses {
+            writable: parse_pubkeys(&loaded.writable).context("invalid loaded address")?,
+            readonly: parse_pubkeys(&loaded.readonly).context("invalid loaded address")?,
+        }),
+        _ => Ok(LoadedAddresses::default()),
+    }
+}
+
+/// Inner instructions of `meta` in the form [`decode_transaction`] takes
+///
+/// Instruction data arrives base58 encoded. Instructions parsed by the RPC
+/// node, which only happens with the `jsonParsed` encoding, are errors.
+pub fn inner_instructions(
+    meta: &UiTransactionStatusMeta,
+) -> anyhow::Result<Vec<transaction::InnerInstructions>> {
+    let OptionSerializer::Some(inner_instructions) = &meta.inner_instructions else {
+        return Ok(Vec::new());
+    };
+
+    inner_instructions
+        .iter()
+        .map(|inner| {
+            let instructions = inner
+                .instructions
+                .iter()
+                .map(|ix| match ix {
+                    UiInstruction::Compiled(ix) => Ok(transaction::InnerInstruction {
+                        instruction: CompiledInstruction {
+                            program_id_index: ix.program_id_index,
+                            accounts: ix.accounts.clone(),
+                            data: bs58::decode(&ix.data).into_vec().with_context(|| {
+                                format!(
+                                    "inner instruction of instruction {} has invalid base58 data",
+                                    inner.index
+                                )
+                            })?,
+                        },
+                        stack_height: ix.stack_height,
+                    }),
+                    UiInstruction::Parsed(_) => Err(anyhow!(
+                        "inner instruction of instruction {} is parsed, request the transaction as base64",
+                        inner.index
+                    )),
+                })
+                .collect::<anyhow::Result<_>>()?;
+
+            Ok(transaction::InnerInstructions {
+                index: inner.index,
+                instructions,
+            })
+        })
+        .collect()
+}
+
+fn parse_pubkeys(keys: &[String]) -> anyhow::Result<Vec<Pubkey>> {
+    keys.iter()
+        .map(|key| Pubkey::from_str(key).map_err(|_| anyhow!("invalid pubkey {:?}", key)))
+        .collect()
+}
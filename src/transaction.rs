@@ -0,0 +1,282 @@
+use crate::{IdlEvents, IdlInstructions, EVENT_CPI_TAG};
+use anyhow::anyhow;
+use base64::Engine as _;
+use solana_sdk::{
+    instruction::AccountMeta,
+    message::{compiled_instruction::CompiledInstruction, v0::LoadedAddresses, VersionedMessage},
+    pubkey::Pubkey,
+};
+
+/// Inner instructions invoked by the top-level instruction at `index`
+///
+/// Mirrors `solana_transaction_status::InnerInstructions`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<InnerInstruction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerInstruction {
+    pub instruction: CompiledInstruction,
+    /// Invocation stack height, starting at 1 for top-level instructions
+    ///
+    /// Missing in transactions recorded before stack heights were tracked; such
+    /// instructions are treated as direct CPIs of their top-level instruction.
+    pub stack_height: Option<u32>,
+}
+
+/// An instruction of a transaction, in execution order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlattenedInstruction<'a> {
+    pub instruction: &'a CompiledInstruction,
+    /// Index of the top-level instruction this one belongs to
+    pub top_level_index: usize,
+    /// Invocation stack height, starting at 1 for top-level instructions
+    pub stack_height: Option<u32>,
+    /// Position in the flattened list of the instruction that invoked this one,
+    /// `None` for top-level instructions
+    pub parent: Option<usize>,
+}
+
+/// List the instructions of a transaction in execution order, with their callers
+///
+/// Each top-level instruction is followed by its inner instructions. The
+/// caller of an inner instruction is the closest preceding instruction with a
+/// lower stack height, so nested CPIs, including a program invoking itself, are
+/// attributed to the exact call that made them. Inner instructions without a
+/// stack height are attributed to their top-level instruction.
+pub fn flatten_instructions<'a>(
+    message: &'a VersionedMessage,
+    inner_instructions: &'a [InnerInstructions],
+) -> Vec<FlattenedInstruction<'a>> {
+    let mut flattened = Vec::new();
+
+    for (index, ix) in message.instructions().iter().enumerate() {
+        let top_level = flattened.len();
+        flattened.push(FlattenedInstruction {
+            instruction: ix,
+            top_level_index: index,
+            stack_height: Some(1),
+            parent: None,
+        });
+
+        // Stack heights and positions of the instructions currently executing
+        let mut callers: Vec<(u32, usize)> = vec![(1, top_level)];
+        let inner = inner_instructions
+            .iter()
+            .filter(|inner| inner.index as usize == index)
+            .flat_map(|inner| &inner.instructions);
+
+        for inner_ix in inner {
+            let position = flattened.len();
+            let parent = match inner_ix.stack_height {
+                Some(height) => {
+                    while callers.len() > 1 && callers[callers.len() - 1].0 >= height {
+                        callers.pop();
+                    }
+                    let parent = callers[callers.len() - 1].1;
+                    callers.push((height, position));
+                    parent
+                }
+                None => top_level,
+            };
+            flattened.push(FlattenedInstruction {
+                instruction: &inner_ix.instruction,
+                top_level_index: index,
+                stack_height: inner_ix.stack_height,
+                parent: Some(parent),
+            });
+        }
+    }
+
+    flattened
+}
+
+/// Decode every instruction of `T`'s program in a transaction
+///
+/// Top-level instructions are visited in order, each followed by its inner
+/// instructions. Instructions invoking `T::PROGRAM_ID` are decoded and returned
+/// along with the program id of their top-level parent (`None` for top-level
+/// instructions); all others are skipped. See [`flatten_instructions`] for the
+/// exact caller of each instruction.
+pub fn decode_transaction<T: IdlInstructions>(
+    message: &VersionedMessage,
+    loaded_addresses: &LoadedAddresses,
+    inner_instructions: &[InnerInstructions],
+) -> Vec<(Option<Pubkey>, anyhow::Result<T>)> {
+    let account_metas = transaction_account_metas(message, loaded_addresses);
+    let top_level = message.instructions();
+    let mut decoded = Vec::new();
+
+    for ix in flatten_instructions(message, inner_instructions) {
+        let parent_program_id = match ix.parent {
+            None => None,
+            Some(_) => {
+                let parent = &top_level[ix.top_level_index];
+                match account_metas.get(parent.program_id_index as usize) {
+                    Some(meta) => Some(meta.pubkey),
+                    None => continue,
+                }
+            }
+        };
+        if let Some(result) = decode_compiled_instruction(ix.instruction, &account_metas) {
+            decoded.push((parent_program_id, result));
+        }
+    }
+
+    decoded
+}
+
+/// Decode every event of `T`'s program emitted by a transaction
+///
+/// Combines events logged with `emit!`, read from `log_messages` by
+/// [`decode_log_events`], and events emitted with `emit_cpi!`, read from the
+/// inner instructions in which the program invokes itself. Each event comes
+/// with the index of the top-level instruction that emitted it. Events are
+/// ordered by that index, logged events before CPI events of the same
+/// instruction, each in the order they were emitted.
+pub fn decode_all_events<T: IdlEvents>(
+    message: &VersionedMessage,
+    loaded_addresses: &LoadedAddresses,
+    inner_instructions: &[InnerInstructions],
+    log_messages: &[String],
+) -> Vec<(usize, anyhow::Result<T>)> {
+    let account_metas = transaction_account_metas(message, loaded_addresses);
+    let mut events = decode_log_events(log_messages);
+
+    for ix in flatten_instructions(message, inner_instructions) {
+        if ix.parent.is_none() {
+            continue;
+        }
+        let invokes_program = account_metas
+            .get(ix.instruction.program_id_index as usize)
+            .is_some_and(|meta| meta.pubkey == T::PROGRAM_ID);
+        if let Some(event) = ix
+            .instruction
+            .data
+            .strip_prefix(&EVENT_CPI_TAG)
+            .filter(|_| invokes_program)
+        {
+            events.push((ix.top_level_index, T::from_event_data(event)));
+        }
+    }
+
+    // Stable, so events of one instruction keep their order
+    events.sort_by_key(|(index, _)| *index);
+    events
+}
+
+/// Decode the events of `T`'s program logged with `emit!`
+///
+/// Events are the base64 payloads of `Program data:` lines written while the
+/// program is executing, including when invoked through CPI; data logged by
+/// other programs is skipped. Each event comes with the index of its
+/// top-level instruction, counted from the `invoke [1]` lines.
+pub fn decode_log_events<T: IdlEvents>(log_messages: &[String]) -> Vec<(usize, anyhow::Result<T>)> {
+    let program_id = T::PROGRAM_ID.to_string();
+    // Programs currently executing, innermost last
+    let mut invoked: Vec<&str> = Vec::new();
+    let mut top_level_count = 0usize;
+    let mut events = Vec::new();
+
+    for log in log_messages {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if invoked.last() == Some(&program_id.as_str()) {
+                let event = base64::engine::general_purpose::STANDARD
+                    .decode(data.trim())
+                    .map_err(|e| anyhow!("event data is not valid base64: {}", e))
+                    .and_then(|data| T::from_event_data(&data));
+                events.push((top_level_count.saturating_sub(1), event));
+            }
+            continue;
+        }
+        let Some((program, status)) = log
+            .strip_prefix("Program ")
+            .and_then(|log| log.split_once(' '))
+        else {
+            continue;
+        };
+        if status == "invoke [1]" {
+            invoked.clear();
+            top_level_count += 1;
+        }
+        if status.starts_with("invoke [") {
+            invoked.push(program);
+        } else if status == "success" || status.starts_with("failed") {
+            invoked.pop();
+        }
+    }
+
+    events
+}
+
+/// Resolve the account metas of a transaction, including loaded addresses
+pub fn transaction_account_metas(
+    message: &VersionedMessage,
+    loaded_addresses: &LoadedAddresses,
+) -> Vec<AccountMeta> {
+    let header = message.header();
+    let accounts = message.static_account_keys();
+    let readonly_signed_accounts_count = header.num_readonly_signed_accounts as usize;
+    let readonly_unsigned_accounts_count = header.num_readonly_unsigned_accounts as usize;
+    let required_signatures_accounts_count = header.num_required_signatures as usize;
+    let total_accounts = accounts.len();
+
+    let mut account_metas: Vec<AccountMeta> = accounts
+        .iter()
+        .enumerate()
+        .map(|(index, pubkey)| {
+            let is_writable = index
+                < required_signatures_accounts_count.saturating_sub(readonly_signed_accounts_count)
+                || (index >= required_signatures_accounts_count
+                    && index < total_accounts.saturating_sub(readonly_unsigned_accounts_count));
+
+            AccountMeta {
+                pubkey: *pubkey,
+                is_signer: index < required_signatures_accounts_count,
+                is_writable,
+            }
+        })
+        .collect();
+
+    account_metas.extend(loaded_addresses.writable.iter().map(|pubkey| AccountMeta {
+        pubkey: *pubkey,
+        is_signer: false,
+        is_writable: true,
+    }));
+    account_metas.extend(loaded_addresses.readonly.iter().map(|pubkey| AccountMeta {
+        pubkey: *pubkey,
+        is_signer: false,
+        is_writable: false,
+    }));
+
+    account_metas
+}
+
+/// Decode `ix` if it invokes `T`'s program, `None` otherwise
+fn decode_compiled_instruction<T: IdlInstructions>(
+    ix: &CompiledInstruction,
+    account_metas: &[AccountMeta],
+) -> Option<anyhow::Result<T>> {
+    let program_id = account_metas.get(ix.program_id_index as usize)?.pubkey;
+    if program_id != T::PROGRAM_ID {
+        return None;
+    }
+
+    let accounts = ix
+        .accounts
+        .iter()
+        .map(|&index| {
+            account_metas.get(index as usize).cloned().ok_or_else(|| {
+                anyhow!(
+                    "account index {} out of range, transaction has {} accounts",
+                    index,
+                    account_metas.len()
+                )
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>();
+
+    Some(accounts.and_then(|accounts| T::deserialize(&accounts, &ix.data)))
+}
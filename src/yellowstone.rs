@@ -0,0 +1,367 @@
+//! Conversion of Yellowstone Geyser transaction updates into Solana types
+//!
+//! Geyser streams transactions as protobuf messages. [`convert_update`] maps
+//! them onto `ConfirmedTransactionWithStatusMeta`, after which
+//! [`decode_transaction`](crate::transaction::decode_transaction) can decode
+//! the instructions of a program.
+
+use crate::transaction;
+use crate::IdlEvents;
+use anyhow::{anyhow, Context};
+use solana_account_decoder_client_types::token::UiTokenAmount;
+use solana_sdk::{
+    hash::Hash,
+    message::{
+        compiled_instruction::CompiledInstruction,
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
+        Message, MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{TransactionError, VersionedTransaction},
+};
+use solana_transaction_context::TransactionReturnData;
+use solana_transaction_status::{
+    ConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions, Reward, RewardType,
+    TransactionStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta,
+    VersionedTransactionWithStatusMeta,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use yellowstone_grpc_proto::prelude as proto;
+
+/// Convert a Geyser transaction update received at `slot`
+///
+/// Geyser does not send the block time, so `block_time` is `None`.
+pub fn convert_update(
+    slot: u64,
+    info: proto::SubscribeUpdateTransactionInfo,
+) -> anyhow::Result<ConfirmedTransactionWithStatusMeta> {
+    let transaction = info
+        .transaction
+        .ok_or_else(|| anyhow!("transaction update has no transaction"))?;
+    let meta = info
+        .meta
+        .ok_or_else(|| anyhow!("transaction update has no status meta"))?;
+
+    Ok(ConfirmedTransactionWithStatusMeta {
+        slot,
+        tx_with_meta: TransactionWithStatusMeta::Complete(VersionedTransactionWithStatusMeta {
+            transaction: convert_transaction(transaction)?,
+            meta: convert_meta(meta)?,
+        }),
+        block_time: None,
+    })
+}
+
+/// Inner instructions of `meta` in the form [`decode_transaction`](crate::transaction::decode_transaction) takes
+pub fn inner_instructions(meta: &TransactionStatusMeta) -> Vec<transaction::InnerInstructions> {
+    meta.inner_instructions
+        .iter()
+        .flatten()
+        .map(|inner| transaction::InnerInstructions {
+            index: inner.index,
+            instructions: inner
+                .instructions
+                .iter()
+                .map(|ix| transaction::InnerInstruction {
+                    instruction: ix.instruction.clone(),
+                    stack_height: ix.stack_height,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Decode every event of `T`'s program emitted by a converted transaction
+///
+/// Reads the log messages and inner instructions of `tx.meta`; see
+/// [`decode_all_events`](crate::transaction::decode_all_events).
+pub fn decode_all_events<T: IdlEvents>(
+    tx: &VersionedTransactionWithStatusMeta,
+) -> Vec<(usize, anyhow::Result<T>)> {
+    transaction::decode_all_events(
+        &tx.transaction.message,
+        &tx.meta.loaded_addresses,
+        &inner_instructions(&tx.meta),
+        tx.meta.log_messages.as_deref().unwrap_or_default(),
+    )
+}
+
+fn convert_transaction(transaction: proto::Transaction) -> anyhow::Result<VersionedTransaction> {
+    let signatures = transaction
+        .signatures
+        .iter()
+        .map(|signature| {
+            Signature::try_from(signature.as_slice())
+                .map_err(|_| anyhow!("signature must be 64 bytes, got {}", signature.len()))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let message = transaction
+        .message
+        .ok_or_else(|| anyhow!("transaction has no message"))?;
+
+    Ok(VersionedTransaction {
+        signatures,
+        message: convert_message(message)?,
+    })
+}
+
+fn convert_message(message: proto::Message) -> anyhow::Result<VersionedMessage> {
+    let header = message
+        .header
+        .ok_or_else(|| anyhow!("message has no header"))?;
+    let header = MessageHeader {
+        num_required_signatures: to_u8(header.num_required_signatures, "num_required_signatures")?,
+        num_readonly_signed_accounts: to_u8(
+            header.num_readonly_signed_accounts,
+            "num_readonly_signed_accounts",
+        )?,
+        num_readonly_unsigned_accounts: to_u8(
+            header.num_readonly_unsigned_accounts,
+            "num_readonly_unsigned_accounts",
+        )?,
+    };
+    let recent_blockhash = <[u8; 32]>::try_from(message.recent_blockhash.as_slice())
+        .map(Hash::new_from_array)
+        .map_err(|_| anyhow!("recent blockhash must be 32 bytes"))?;
+    let account_keys = convert_pubkeys(&message.account_keys).context("invalid account key")?;
+    let instructions = message
+        .instructions
+        .into_iter()
+        .map(|ix| {
+            Ok(CompiledInstruction {
+                program_id_index: to_u8(ix.program_id_index, "program_id_index")?,
+                accounts: ix.accounts,
+                data: ix.data,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    if !message.versioned {
+        return Ok(VersionedMessage::Legacy(Message {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+        }));
+    }
+
+    let address_table_lookups = message
+        .address_table_lookups
+        .into_iter()
+        .map(|lookup| {
+            Ok(MessageAddressTableLookup {
+                account_key: convert_pubkey(&lookup.account_key)
+                    .context("invalid address table")?,
+                writable_indexes: lookup.writable_indexes,
+                readonly_indexes: lookup.readonly_indexes,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(VersionedMessage::V0(v0::Message {
+        header,
+        account_keys,
+        recent_blockhash,
+        instructions,
+        address_table_lookups,
+    }))
+}
+
+fn convert_meta(meta: proto::TransactionStatusMeta) -> anyhow::Result<TransactionStatusMeta> {
+    let status = match meta.err {
+        Some(err) => Err(bincode::deserialize::<TransactionError>(&err.err)
+            .context("failed to decode transaction error")?),
+        None => Ok(()),
+    };
+    let inner_instructions = meta
+        .inner_instructions
+        .into_iter()
+        .map(convert_inner_instructions)
+        .collect::<anyhow::Result<_>>()?;
+    let return_data = match meta.return_data {
+        Some(return_data) if !meta.return_data_none => Some(TransactionReturnData {
+            program_id: convert_pubkey(&return_data.program_id)
+                .context("invalid return data program id")?,
+            data: return_data.data,
+        }),
+        _ => None,
+    };
+
+    Ok(TransactionStatusMeta {
+        status,
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions: (!meta.inner_instructions_none).then_some(inner_instructions),
+        log_messages: (!meta.log_messages_none).then_some(meta.log_messages),
+        pre_token_balances: Some(convert_token_balances(meta.pre_token_balances)?),
+        post_token_balances: Some(convert_token_balances(meta.post_token_balances)?),
+        rewards: Some(meta.rewards.into_iter().map(convert_reward).collect()),
+        loaded_addresses: LoadedAddresses {
+            writable: convert_pubkeys(&meta.loaded_writable_addresses)
+                .context("invalid loaded address")?,
+            readonly: convert_pubkeys(&meta.loaded_readonly_addresses)
+                .context("invalid loaded address")?,
+        },
+        return_data,
+        compute_units_consumed: meta.compute_units_consumed,
+        cost_units: meta.cost_units,
+    })
+}
+
+fn convert_inner_instructions(
+    inner: proto::InnerInstructions,
+) -> anyhow::Result<InnerInstructions> {
+    let instructions = inner
+        .instructions
+        .into_iter()
+        .map(|ix| {
+            Ok(InnerInstruction {
+                instruction: CompiledInstruction {
+                    program_id_index: to_u8(ix.program_id_index, "program_id_index")?,
+                    accounts: ix.accounts,
+                    data: ix.data,
+                },
+                stack_height: ix.stack_height,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(InnerInstructions {
+        index: to_u8(inner.index, "inner instructions index")?,
+        instructions,
+    })
+}
+
+fn convert_token_balances(
+    balances: Vec<proto::TokenBalance>,
+) -> anyhow::Result<Vec<TransactionTokenBalance>> {
+    balances.into_iter().map(convert_token_balance).collect()
+}
+
+/// Convert a Geyser token balance
+///
+/// A balance without an amount is an error rather than a zero balance.
+pub fn convert_token_balance(
+    balance: proto::TokenBalance,
+) -> anyhow::Result<TransactionTokenBalance> {
+    let amount = balance.ui_token_amount.ok_or_else(|| {
+        anyhow!(
+            "token balance of account {} has no amount",
+            balance.account_index
+        )
+    })?;
+
+    Ok(TransactionTokenBalance {
+        account_index: to_u8(balance.account_index, "token balance account index")?,
+        mint: balance.mint,
+        ui_token_amount: convert_ui_token_amount(amount)?,
+        owner: balance.owner,
+        program_id: balance.program_id,
+    })
+}
+
+/// Convert a Geyser token amount
+///
+/// Geyser sends a missing `ui_amount` as `0.0`. It is `None` only when the raw
+/// amount is not zero, so genuinely empty balances keep `Some(0.0)`.
+pub fn convert_ui_token_amount(amount: proto::UiTokenAmount) -> anyhow::Result<UiTokenAmount> {
+    let raw_is_zero = amount.amount.trim_start_matches('0').is_empty();
+    let ui_amount = (amount.ui_amount != 0.0 || raw_is_zero).then_some(amount.ui_amount);
+
+    Ok(UiTokenAmount {
+        ui_amount,
+        decimals: to_u8(amount.decimals, "token decimals")?,
+        amount: amount.amount,
+        ui_amount_string: amount.ui_amount_string,
+    })
+}
+
+/// Net change of each owner's balance of each mint, as `post - pre` in raw units
+///
+/// Balances of the same owner and mint held in several token accounts are
+/// summed, and accounts present on only one side count as zero on the other.
+/// Every owner and mint appearing in either list gets an entry, even when its
+/// delta is zero. Owners, mints or amounts that do not parse are errors.
+pub fn token_balance_deltas(
+    pre: &[TransactionTokenBalance],
+    post: &[TransactionTokenBalance],
+) -> anyhow::Result<HashMap<(Pubkey, Pubkey), i128>> {
+    let mut deltas = HashMap::new();
+    for (balances, sign) in [(pre, -1), (post, 1)] {
+        for balance in balances {
+            let parse_key = |key: &str, field: &str| {
+                Pubkey::from_str(key).map_err(|_| {
+                    anyhow!(
+                        "token balance of account {} has an invalid {} {:?}",
+                        balance.account_index,
+                        field,
+                        key
+                    )
+                })
+            };
+            let owner = parse_key(&balance.owner, "owner")?;
+            let mint = parse_key(&balance.mint, "mint")?;
+            let amount: u64 = balance.ui_token_amount.amount.parse().with_context(|| {
+                format!(
+                    "token balance of account {} has an invalid amount {:?}",
+                    balance.account_index, balance.ui_token_amount.amount
+                )
+            })?;
+            *deltas.entry((owner, mint)).or_insert(0) += sign * i128::from(amount);
+        }
+    }
+    Ok(deltas)
+}
+
+/// Convert a Geyser reward
+///
+/// The commission arrives as a string, empty when the reward has none. A
+/// commission that is not a valid percentage is read as `0` rather than
+/// failing the whole transaction.
+pub fn convert_reward(reward: proto::Reward) -> Reward {
+    Reward {
+        pubkey: reward.pubkey,
+        lamports: reward.lamports,
+        post_balance: reward.post_balance,
+        reward_type: convert_reward_type(reward.reward_type),
+        commission: parse_commission(&reward.commission),
+    }
+}
+
+/// Map a Geyser reward type onto [`RewardType`]
+///
+/// `Unspecified` and values unknown to this version of the protocol are
+/// `None`, like a reward without a type, so newer Geyser servers do not break
+/// the conversion.
+pub fn convert_reward_type(reward_type: i32) -> Option<RewardType> {
+    match proto::RewardType::try_from(reward_type).ok()? {
+        proto::RewardType::Unspecified => None,
+        proto::RewardType::Fee => Some(RewardType::Fee),
+        proto::RewardType::Rent => Some(RewardType::Rent),
+        proto::RewardType::Staking => Some(RewardType::Staking),
+        proto::RewardType::Voting => Some(RewardType::Voting),
+    }
+}
+
+fn parse_commission(commission: &str) -> Option<u8> {
+    if commission.is_empty() {
+        None
+    } else {
+        Some(commission.trim().parse().unwrap_or(0))
+    }
+}
+
+fn convert_pubkeys(keys: &[Vec<u8>]) -> anyhow::Result<Vec<Pubkey>> {
+    keys.iter().map(|key| convert_pubkey(key)).collect()
+}
+
+fn convert_pubkey(key: &[u8]) -> anyhow::Result<Pubkey> {
+    Pubkey::try_from(key).map_err(|_| anyhow!("pubkey must be 32 bytes, got {}", key.len()))
+}
+
+fn to_u8(value: u32, field: &str) -> anyhow::Result<u8> {
+    u8::try_from(value).map_err(|_| anyhow!("{} {} does not fit in a u8", field, value))
+}
@@ -0,0 +1,83 @@
+mod padded {
+    solana_idl_parser::parse_idl!("tests/idls/borsh_attributes.json");
+}
+
+use padded::{Vault, VaultState, VAULT_ACCOUNT_DISCRIMINATOR, VAULT_LAYOUT};
+use solana_idl_parser::Pubkey;
+
+#[test]
+fn skipped_padding_is_not_read_from_account_data() {
+    let mut data = VAULT_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&[9; 32]);
+    data.push(1);
+    // Reserved bytes on chain, left undecoded
+    data.extend_from_slice(&[0xff; 16]);
+
+    let vault = Vault::from_account_data(&data).unwrap();
+    assert_eq!(
+        vault,
+        Vault {
+            authority: Pubkey::new_from_array([9; 32]),
+            state: VaultState::Frozen,
+            reserved: [0; 16],
+        }
+    );
+    assert_eq!(borsh::to_vec(&vault).unwrap(), data[8..41]);
+}
+
+#[test]
+fn skipped_fields_are_left_out_of_the_layout() {
+    assert_eq!(VAULT_LAYOUT, [("authority", 0, 32), ("state", 32, 1)]);
+}
+
+#[test]
+fn fields_after_a_skipped_one_are_laid_out_as_encoded() {
+    use padded::{Position, POSITION_LAYOUT};
+
+    assert_eq!(POSITION_LAYOUT, [("owner", 0, 32), ("amount", 32, 8)]);
+
+    let position = Position {
+        owner: Pubkey::new_from_array([3; 32]),
+        cached_value: 99,
+        amount: 500,
+    };
+    let data = borsh::to_vec(&position).unwrap();
+    assert_eq!(data.len(), 40);
+    let (_, offset, size) = POSITION_LAYOUT[1];
+    assert_eq!(data[offset..offset + size], 500u64.to_le_bytes());
+
+    let decoded: Position = borsh::from_slice(&data).unwrap();
+    assert_eq!(decoded.amount, 500);
+    assert_eq!(decoded.cached_value, 0);
+}
+
+mod merkle {
+    solana_idl_parser::parse_idl!("tests/idls/bytes_len.json");
+}
+
+#[test]
+fn bytes_with_a_len_must_have_that_length() {
+    use merkle::{MerkleInstructions, Tree, TREE_ACCOUNT_DISCRIMINATOR};
+
+    let tree = |root: Vec<u8>| Tree {
+        root,
+        label: vec![1, 2, 3],
+    };
+    let mut data = TREE_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&tree(vec![7; 32])).unwrap());
+    assert_eq!(Tree::from_account_data(&data).unwrap(), tree(vec![7; 32]));
+
+    let mut data = TREE_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&tree(vec![7; 31])).unwrap());
+    let err = Tree::from_account_data(&data).unwrap_err();
+    assert!(err.to_string().contains("expected 32 bytes, got 31"));
+
+    let accounts = [solana_idl_parser::AccountMeta {
+        pubkey: Pubkey::new_from_array([1; 32]),
+        is_signer: false,
+        is_writable: true,
+    }];
+    let mut ix = merkle::SET_ROOT_DISCRIMINATOR.to_vec();
+    ix.extend_from_slice(&borsh::to_vec(&vec![0u8; 33]).unwrap());
+    assert!(MerkleInstructions::deserialize(&accounts, &ix).is_err());
+}
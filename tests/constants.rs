@@ -0,0 +1,23 @@
+solana_idl_parser::parse_idl!("tests/idls/constants.json");
+
+#[test]
+fn integer_constants_keep_their_value() {
+    assert_eq!(MAX_SUPPLY, 1_000_000_007u64);
+    assert_eq!(MIN_PRICE, -42i64);
+}
+
+#[test]
+fn u64_constant_serializes_little_endian() {
+    assert_eq!(
+        borsh::to_vec(&MAX_SUPPLY).unwrap(),
+        [0x07, 0xca, 0x9a, 0x3b, 0x00, 0x00, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn byte_constants_are_emitted_verbatim() {
+    assert_eq!(POOL_SEED, b"pool");
+    assert_eq!(VAULT_SEED, b"vault");
+    assert_eq!(MAGIC, [1, 0, 0, 0]);
+    assert_eq!(VERSION, "v1");
+}
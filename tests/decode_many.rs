@@ -0,0 +1,66 @@
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+use counter::{CounterInstructions, INITIALIZE_DISCRIMINATOR, SET_VALUE_DISCRIMINATOR};
+use solana_idl_parser::{decode_many, AccountMeta, DecodeError, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: i == 1,
+            is_writable: i == 0,
+        })
+        .collect()
+}
+
+fn set_value_data(value: u64) -> Vec<u8> {
+    let mut data = SET_VALUE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&value.to_le_bytes());
+    data
+}
+
+#[test]
+fn batches_of_instructions_are_decoded_in_order() {
+    let (one, two) = (metas(1), metas(2));
+    let (first, second) = (set_value_data(1), set_value_data(2));
+    let items = [
+        (one.as_slice(), first.as_slice()),
+        (two.as_slice(), INITIALIZE_DISCRIMINATOR.as_slice()),
+        (one.as_slice(), second.as_slice()),
+    ];
+
+    let decoded: Vec<CounterInstructions> =
+        decode_many(items).collect::<anyhow::Result<_>>().unwrap();
+    assert_eq!(decoded.len(), 3);
+    assert!(matches!(&decoded[0], CounterInstructions::SetValue(_, args) if args.value == 1));
+    assert!(matches!(&decoded[1], CounterInstructions::Initialize(_)));
+    assert!(matches!(&decoded[2], CounterInstructions::SetValue(_, args) if args.value == 2));
+}
+
+#[test]
+fn empty_input_decodes_nothing() {
+    let mut decoded = decode_many::<CounterInstructions, _>(std::iter::empty());
+    assert!(decoded.next().is_none());
+}
+
+#[test]
+fn failures_name_the_index_of_the_instruction() {
+    let one = metas(1);
+    let (good, bad) = (set_value_data(1), vec![0; 8]);
+    let items = [
+        (one.as_slice(), good.as_slice()),
+        (one.as_slice(), good.as_slice()),
+        (one.as_slice(), bad.as_slice()),
+    ];
+
+    let results: Vec<_> = decode_many::<CounterInstructions, _>(items).collect();
+    assert!(results[0].is_ok() && results[1].is_ok());
+    let err = results[2].as_ref().unwrap_err();
+    assert_eq!(err.to_string(), "failed to decode instruction 2");
+    assert!(matches!(
+        err.downcast_ref::<DecodeError>(),
+        Some(DecodeError::UnknownDiscriminator { .. })
+    ));
+}
@@ -0,0 +1,342 @@
+mod native_token {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/native_token.json",
+        discriminator_in_args = true,
+        deny_trailing_bytes = true,
+    );
+}
+
+use native_token::{NativeTokenInstructions, SyncNativeAccounts, TransferAccounts, TransferArgs};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn single_byte_discriminator_is_not_consumed_from_args() {
+    let mut data = vec![3];
+    data.extend_from_slice(&500u64.to_le_bytes());
+
+    match NativeTokenInstructions::deserialize(&metas(3), &data).unwrap() {
+        NativeTokenInstructions::Transfer(_, args) => {
+            assert_eq!(args.instruction, 3);
+            assert_eq!(args.amount, 500);
+        }
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn args_with_discriminator_are_serialized_once() {
+    let accounts = metas(3);
+    let ix = NativeTokenInstructions::Transfer(
+        TransferAccounts::from_account_metas(&accounts).unwrap(),
+        TransferArgs {
+            instruction: 3,
+            amount: 7,
+        },
+    );
+    let data = ix.to_bytes().unwrap();
+    assert_eq!(data, [3, 7, 0, 0, 0, 0, 0, 0, 0]);
+
+    let sync = NativeTokenInstructions::SyncNative(
+        SyncNativeAccounts::from_account_metas(&metas(1)).unwrap(),
+    );
+    assert_eq!(sync.to_bytes().unwrap(), [17]);
+    assert!(NativeTokenInstructions::deserialize(&metas(1), &[17]).is_ok());
+}
+
+#[test]
+fn borsh_serialization_writes_the_instruction_data() {
+    let ix = NativeTokenInstructions::Transfer(
+        TransferAccounts::from_account_metas(&metas(3)).unwrap(),
+        TransferArgs {
+            instruction: 3,
+            amount: 7,
+        },
+    );
+    assert_eq!(borsh::to_vec(&ix).unwrap(), ix.to_bytes().unwrap());
+    assert_eq!(borsh::to_vec(&ix).unwrap(), [3, 7, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn trailing_bytes_after_args_with_discriminator_are_rejected() {
+    let mut data = vec![3];
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.push(0);
+    assert!(NativeTokenInstructions::deserialize(&metas(3), &data).is_err());
+}
+
+#[test]
+fn encoded_instruction_data_is_decoded() {
+    let data = [3, 1, 0, 0, 0, 0, 0, 0, 0];
+    let base64 = "AwEAAAAAAAAA";
+    let base58 = bs58::encode(data).into_string();
+
+    for ix in [
+        NativeTokenInstructions::from_base64(&metas(3), base64).unwrap(),
+        NativeTokenInstructions::from_base58(&metas(3), &base58).unwrap(),
+    ] {
+        assert_eq!(ix.to_bytes().unwrap(), data);
+    }
+
+    let err = NativeTokenInstructions::from_base64(&metas(3), "not base64!").unwrap_err();
+    assert!(err.to_string().contains("not valid base64"));
+}
+
+#[test]
+fn accounts_are_labeled_with_their_idl_names() {
+    let accounts = TransferAccounts::from_account_metas(&metas(3)).unwrap();
+    let names: Vec<&str> = accounts
+        .named_accounts()
+        .iter()
+        .map(|(name, _)| *name)
+        .collect();
+    assert_eq!(names, ["source", "destination", "owner"]);
+    assert_eq!(accounts.named_accounts()[1].1, metas(3)[1].pubkey);
+
+    let streamed: Vec<(&str, Pubkey)> = accounts.iter().collect();
+    assert_eq!(streamed, accounts.named_accounts());
+
+    let ix = NativeTokenInstructions::SyncNative(
+        SyncNativeAccounts::from_account_metas(&metas(1)).unwrap(),
+    );
+    assert_eq!(ix.named_accounts(), [("account", metas(1)[0].pubkey)]);
+}
+
+#[test]
+fn payload_types_carry_their_instruction_discriminator() {
+    assert_eq!(
+        TransferArgs::DISCRIMINATOR,
+        native_token::TRANSFER_DISCRIMINATOR
+    );
+    assert_eq!(TransferAccounts::DISCRIMINATOR, [3]);
+    assert_eq!(SyncNativeAccounts::DISCRIMINATOR, [17]);
+}
+
+mod mixed {
+    solana_idl_parser::parse_idl!("tests/idls/mixed_discriminators.json");
+}
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn mixed_discriminator_lengths_prefer_the_longest_match() {
+    use mixed::{LegacyTransferAccounts, LegacyTransferArgs, MixedInstructions, SwapArgs};
+
+    let mut swap = vec![3, 1, 2, 3, 4, 5, 6, 7];
+    swap.extend_from_slice(&1_000u64.to_le_bytes());
+    match MixedInstructions::deserialize(&metas(1), &swap).unwrap() {
+        MixedInstructions::Swap(_, args) => assert_eq!(args, SwapArgs { amount_in: 1_000 }),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+
+    // Starts with the 1-byte discriminator but not with the 8-byte one
+    let mut transfer = vec![3];
+    transfer.extend_from_slice(&42u32.to_le_bytes());
+    let ix = MixedInstructions::deserialize(&metas(1), &transfer).unwrap();
+    assert_eq!(
+        ix,
+        MixedInstructions::LegacyTransfer(
+            LegacyTransferAccounts::from_account_metas(&metas(1)).unwrap(),
+            LegacyTransferArgs { amount: 42 },
+        )
+    );
+    assert_eq!(ix.to_bytes().unwrap(), transfer);
+
+    assert_eq!(
+        MixedInstructions::deserialize(&[], &[9]).unwrap(),
+        MixedInstructions::Close
+    );
+    assert!(MixedInstructions::deserialize(&[], &[5, 0, 0]).is_err());
+    assert!(MixedInstructions::deserialize(&[], &[]).is_err());
+}
+
+#[test]
+fn instruction_kind_is_classified_from_the_discriminator() {
+    use mixed::{MixedInstructionKind, MixedInstructions};
+    use std::collections::HashMap;
+
+    let swap = [3, 1, 2, 3, 4, 5, 6, 7, 0];
+    assert_eq!(
+        MixedInstructionKind::from_discriminator(&swap),
+        Some(MixedInstructionKind::Swap)
+    );
+    assert_eq!(
+        MixedInstructionKind::from_discriminator(&[3, 1]),
+        Some(MixedInstructionKind::LegacyTransfer)
+    );
+    assert_eq!(MixedInstructionKind::from_discriminator(&[5]), None);
+    assert_eq!(
+        MixedInstructionKind::LegacyTransfer.name(),
+        "legacyTransfer"
+    );
+
+    let mut counts: HashMap<MixedInstructionKind, usize> = HashMap::new();
+    *counts.entry(MixedInstructions::Close.kind()).or_default() += 1;
+    assert_eq!(counts[&MixedInstructionKind::Close], 1);
+}
+
+#[test]
+fn discriminators_are_looked_up_by_idl_or_snake_case_name() {
+    assert_eq!(native_token::discriminator_of("syncNative"), Some([17]));
+    assert_eq!(native_token::discriminator_of("sync_native"), Some([17]));
+    assert_eq!(native_token::discriminator_of("transfer"), Some([3]));
+    assert_eq!(native_token::discriminator_of("SyncNative"), None);
+
+    assert_eq!(
+        mixed::discriminator_of("swap"),
+        Some(&[3, 1, 2, 3, 4, 5, 6, 7][..])
+    );
+    assert_eq!(mixed::discriminator_of("legacy_transfer"), Some(&[3][..]));
+}
+
+#[test]
+fn instruction_data_can_be_passed_in_any_byte_container() {
+    let mut data = vec![3];
+    data.extend_from_slice(&500u64.to_le_bytes());
+    let accounts = metas(3);
+
+    let from_slice = NativeTokenInstructions::deserialize(&accounts, data.as_slice()).unwrap();
+    let from_vec_ref = NativeTokenInstructions::deserialize(&accounts, &data).unwrap();
+    let array: [u8; 9] = data.clone().try_into().unwrap();
+    let from_array = NativeTokenInstructions::deserialize(&accounts, array).unwrap();
+    let from_vec = NativeTokenInstructions::deserialize(&accounts, data).unwrap();
+
+    assert_eq!(from_slice, from_vec_ref);
+    assert_eq!(from_slice, from_array);
+    assert_eq!(from_slice, from_vec);
+}
+
+#[test]
+fn overridden_discriminators_decode_as_the_named_instruction() {
+    use std::collections::HashMap;
+
+    // The deployed program tags `transfer` with 12 instead of the IDL's 3
+    let overrides = HashMap::from([([12], "transfer")]);
+    let mut data = vec![12];
+    data.extend_from_slice(&500u64.to_le_bytes());
+    match NativeTokenInstructions::deserialize_with_overrides(&overrides, &metas(3), &data).unwrap()
+    {
+        NativeTokenInstructions::Transfer(_, args) => {
+            assert_eq!(args.instruction, 3);
+            assert_eq!(args.amount, 500);
+        }
+        other => panic!("unexpected instruction {:?}", other),
+    }
+    assert!(NativeTokenInstructions::deserialize(&metas(3), &data).is_err());
+
+    // Other discriminators decode as usual
+    assert_eq!(
+        NativeTokenInstructions::deserialize_with_overrides(&overrides, &metas(1), [17]).unwrap(),
+        NativeTokenInstructions::deserialize(&metas(1), [17]).unwrap()
+    );
+
+    let unknown = HashMap::from([([12], "mint")]);
+    let error = NativeTokenInstructions::deserialize_with_overrides(&unknown, &metas(3), &data)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "discriminator override names unknown instruction `mint`"
+    );
+
+    // With mixed lengths, overrides are matched as prefixes of any length
+    let overrides = HashMap::from([(vec![200, 1], "close")]);
+    assert_eq!(
+        mixed::MixedInstructions::deserialize_with_overrides(&overrides, &[], [200, 1]).unwrap(),
+        mixed::MixedInstructions::Close
+    );
+}
+
+mod versioned {
+    solana_idl_parser::parse_idl!("tests/idls/versioned.json", discriminator_offset = 1);
+}
+
+#[test]
+fn discriminator_is_read_after_the_offset() {
+    use versioned::{CloseAccounts, VersionedVaultInstructions};
+
+    assert_eq!(versioned::DISCRIMINATOR_OFFSET, 1);
+
+    let mut data = vec![4, 1];
+    data.extend_from_slice(&250u64.to_le_bytes());
+    assert!(versioned::matches_deposit_discriminator(&data));
+    assert!(!versioned::matches_close_discriminator(&data));
+    match VersionedVaultInstructions::deserialize(&metas(2), &data).unwrap() {
+        VersionedVaultInstructions::Deposit(_, args) => assert_eq!(args.amount, 250),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+
+    let close =
+        VersionedVaultInstructions::Close(CloseAccounts::from_account_metas(&metas(1)).unwrap());
+    assert_eq!(close.to_bytes().unwrap(), [0, 2]);
+
+    let err = VersionedVaultInstructions::deserialize(&metas(1), []).unwrap_err();
+    assert!(err.to_string().contains("discriminator offset"));
+    assert!(!versioned::matches_close_discriminator(&[2]));
+}
+
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+#[test]
+fn split_discriminator_and_args_are_decoded() {
+    let args = 42u64.to_le_bytes();
+    let ix = counter::CounterInstructions::deserialize_parts(
+        &counter::SET_VALUE_DISCRIMINATOR,
+        &args,
+        &metas(1),
+    )
+    .unwrap();
+
+    let mut data = counter::SET_VALUE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&args);
+    assert_eq!(
+        ix,
+        counter::CounterInstructions::deserialize(&metas(1), &data).unwrap()
+    );
+    match ix {
+        counter::CounterInstructions::SetValue(_, args) => assert_eq!(args.value, 42),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+fn split_decoding_rejects_an_unknown_discriminator() {
+    let mut discriminator = counter::SET_VALUE_DISCRIMINATOR;
+    discriminator[0] ^= 1;
+    let err = counter::CounterInstructions::deserialize_parts(
+        &discriminator,
+        &42u64.to_le_bytes(),
+        &metas(1),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<solana_idl_parser::DecodeError>(),
+        Some(solana_idl_parser::DecodeError::UnknownDiscriminator {
+            closest: Some("set_value"),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn from_slice_decodes_like_deserialize() {
+    let mut data = counter::SET_VALUE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&42u64.to_le_bytes());
+    let accounts = metas(1);
+
+    assert_eq!(
+        counter::CounterInstructions::from_slice(&accounts, &data).unwrap(),
+        counter::CounterInstructions::deserialize(&accounts, &data).unwrap()
+    );
+    assert!(counter::CounterInstructions::from_slice(&accounts, &data[..4]).is_err());
+}
@@ -0,0 +1,92 @@
+mod matched {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json", deny_trailing_bytes = true);
+}
+
+mod table {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/spec_0_1_0.json",
+        deny_trailing_bytes = true,
+        dispatch_table = true,
+    );
+}
+
+mod native_token {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/native_token.json",
+        discriminator_in_args = true,
+        dispatch_table = true,
+    );
+}
+
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: i == 0,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn table_dispatch_decodes_like_match_dispatch() {
+    let mut set_value = table::SET_VALUE_DISCRIMINATOR.to_vec();
+    set_value.extend_from_slice(&42u64.to_le_bytes());
+
+    for (accounts, data) in [
+        (metas(2), table::INITIALIZE_DISCRIMINATOR.to_vec()),
+        (metas(1), set_value),
+    ] {
+        let matched = matched::CounterInstructions::deserialize(&accounts, &data).unwrap();
+        let table = table::CounterInstructions::deserialize(&accounts, &data).unwrap();
+        assert_eq!(format!("{:?}", table), format!("{:?}", matched));
+        assert_eq!(table.to_bytes().unwrap(), data);
+    }
+}
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn table_dispatch_reports_the_same_errors() {
+    let err = table::CounterInstructions::deserialize(&metas(2), &[0; 8]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown discriminator [0, 0, 0, 0, 0, 0, 0, 0], closest to instruction `initialize`"
+    );
+
+    let mut data = table::INITIALIZE_DISCRIMINATOR.to_vec();
+    data.push(0);
+    let err = table::CounterInstructions::deserialize(&metas(2), &data).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected trailing bytes after instruction data"
+    );
+}
+
+#[test]
+fn table_dispatch_reads_args_starting_with_the_discriminator() {
+    let mut data = vec![3];
+    data.extend_from_slice(&500u64.to_le_bytes());
+
+    match native_token::NativeTokenInstructions::deserialize(&metas(3), &data).unwrap() {
+        native_token::NativeTokenInstructions::Transfer(_, args) => {
+            assert_eq!(args.instruction, 3);
+            assert_eq!(args.amount, 500);
+        }
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+fn table_dispatch_accepts_owned_and_array_data() {
+    let mut set_value = table::SET_VALUE_DISCRIMINATOR.to_vec();
+    set_value.extend_from_slice(&7u64.to_le_bytes());
+    match table::CounterInstructions::deserialize(&metas(1), set_value).unwrap() {
+        table::CounterInstructions::SetValue(_, args) => assert_eq!(args.value, 7),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+
+    let err = table::CounterInstructions::deserialize(&metas(2), [0; 8]).unwrap_err();
+    assert!(err.to_string().starts_with("unknown discriminator"));
+}
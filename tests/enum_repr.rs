@@ -0,0 +1,59 @@
+mod interpreter {
+    solana_idl_parser::parse_idl!("tests/idls/enum_repr.json");
+}
+
+use interpreter::{Flag, Mode, Opcode, Step, STEP_LAYOUT};
+
+#[test]
+fn wide_enums_write_their_declared_tag_width() {
+    assert_eq!(borsh::to_vec(&Opcode::Halt).unwrap(), [0, 0]);
+    assert_eq!(
+        borsh::to_vec(&Opcode::Push(7)).unwrap(),
+        [1, 0, 7, 0, 0, 0, 0, 0, 0, 0]
+    );
+    let jump = Opcode::Jump {
+        target: 9,
+        if_zero: true,
+    };
+    assert_eq!(borsh::to_vec(&jump).unwrap(), [2, 0, 9, 0, 0, 0, 1]);
+    assert_eq!(borsh::to_vec(&Flag::On).unwrap(), [1, 0, 0, 0]);
+}
+
+#[test]
+fn wide_tags_round_trip() {
+    for op in [
+        Opcode::Halt,
+        Opcode::Push(u64::MAX),
+        Opcode::Jump {
+            target: 1,
+            if_zero: false,
+        },
+    ] {
+        let data = borsh::to_vec(&op).unwrap();
+        assert_eq!(borsh::from_slice::<Opcode>(&data).unwrap(), op);
+    }
+}
+
+#[test]
+fn unknown_wide_tags_are_errors() {
+    let err = borsh::from_slice::<Opcode>(&3u16.to_le_bytes()).unwrap_err();
+    assert!(
+        err.to_string().contains("Unexpected variant tag: 3"),
+        "{}",
+        err
+    );
+    // A one-byte tag is too short for a two-byte repr
+    assert!(borsh::from_slice::<Opcode>(&[0]).is_err());
+}
+
+#[test]
+fn layout_reprs_keep_one_byte_tags() {
+    // Anchor's `rust` repr describes zero-copy layout, not the tag
+    assert_eq!(borsh::to_vec(&Mode::Safe).unwrap(), [1]);
+    let step = Step {
+        flag: Flag::On,
+        mode: Mode::Fast,
+    };
+    assert_eq!(borsh::to_vec(&step).unwrap(), [1, 0, 0, 0, 0]);
+    assert_eq!(STEP_LAYOUT, &[("flag", 0, 4), ("mode", 4, 1)]);
+}
@@ -0,0 +1,79 @@
+mod config_update {
+    solana_idl_parser::parse_idl!("tests/idls/config_update.json");
+}
+
+use borsh::BorshDeserialize;
+use config_update::{ConfigUpdate, ConfigUpdateInstructions, PoolConfig};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+#[test]
+fn tuple_variant_wrapping_defined_struct_round_trips() {
+    let update = ConfigUpdate::SetConfig(PoolConfig {
+        authority: Pubkey::new_from_array([7; 32]),
+        fee_bps: 30,
+        tiers: vec![1, 2],
+    });
+    let encoded = borsh::to_vec(&update).unwrap();
+    assert_eq!(encoded[0], 0);
+    assert_eq!(ConfigUpdate::try_from_slice(&encoded).unwrap(), update);
+
+    let mut data = config_update::UPDATE_CONFIG_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&encoded);
+    let accounts = [AccountMeta {
+        pubkey: Pubkey::new_from_array([1; 32]),
+        is_signer: false,
+        is_writable: true,
+    }];
+
+    let ix = ConfigUpdateInstructions::deserialize(&accounts, &data).unwrap();
+    match &ix {
+        ConfigUpdateInstructions::UpdateConfig(_, args) => assert_eq!(args.update, update),
+    }
+    assert_eq!(ix.to_bytes().unwrap(), data);
+}
+
+mod escrow {
+    solana_idl_parser::parse_idl!("tests/idls/state_machine.json");
+}
+
+use escrow::{Escrow, EscrowState};
+
+#[test]
+fn mixed_variant_tags_follow_declaration_order() {
+    let arbiter = Pubkey::new_from_array([9; 32]);
+    let mut disputed = vec![2];
+    disputed.extend_from_slice(&arbiter.to_bytes());
+    disputed.extend_from_slice(&40u64.to_le_bytes());
+    let mut active = vec![1];
+    active.extend_from_slice(&(-5i64).to_le_bytes());
+
+    let cases = [
+        (vec![0], EscrowState::Uninitialized),
+        (active, EscrowState::Active { since: -5 }),
+        (disputed, EscrowState::Disputed(arbiter, 40)),
+        (vec![3], EscrowState::Closed),
+    ];
+    for (bytes, state) in cases {
+        assert_eq!(EscrowState::try_from_slice(&bytes).unwrap(), state);
+        assert_eq!(borsh::to_vec(&state).unwrap(), bytes);
+    }
+
+    assert!(EscrowState::try_from_slice(&[4]).is_err());
+}
+
+#[test]
+fn account_with_mixed_variant_state_decodes() {
+    let mut data = escrow::ESCROW_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.push(1);
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    data.extend_from_slice(&250u64.to_le_bytes());
+
+    let escrow = Escrow::from_account_data(&data).unwrap();
+    assert_eq!(
+        escrow.state,
+        EscrowState::Active {
+            since: 1_700_000_000
+        }
+    );
+    assert_eq!(escrow.amount, 250);
+}
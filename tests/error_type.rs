@@ -0,0 +1,142 @@
+use solana_idl_parser::{AccountMeta, DecodeError, Pubkey};
+use std::fmt;
+
+/// Application error wrapping decoding failures
+#[derive(Debug)]
+pub enum AppError {
+    Decode(DecodeError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Decode(e) => write!(f, "decoding failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<DecodeError> for AppError {
+    fn from(e: DecodeError) -> Self {
+        AppError::Decode(e)
+    }
+}
+
+mod counter {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/spec_0_1_0.json",
+        error = "crate::AppError",
+        deny_trailing_bytes = true,
+    );
+}
+
+mod default_counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+mod unvalidated {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json", validate = false);
+}
+
+use counter::{Counter, CounterInstructions, SetValueAccounts, SET_VALUE_DISCRIMINATOR};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn generated_functions_return_the_configured_error() {
+    let err: AppError = CounterInstructions::deserialize(&metas(1), [9, 9]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "decoding failed: instruction data is shorter than its discriminator"
+    );
+
+    let mut data = SET_VALUE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&[0; 9]);
+    match CounterInstructions::deserialize(&metas(1), &data).unwrap_err() {
+        AppError::Decode(DecodeError::Io(e)) => {
+            assert_eq!(e.kind(), std::io::ErrorKind::InvalidData)
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    match SetValueAccounts::from_account_metas(&metas(2)).unwrap_err() {
+        AppError::Decode(DecodeError::InvalidAccountLen { expected, got }) => {
+            assert_eq!((expected, got), (1, 2))
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    let err: AppError = Counter::from_account_data([0; 4]).unwrap_err();
+    assert!(err.to_string().contains("shorter than its discriminator"));
+}
+
+#[test]
+fn anyhow_returning_functions_wrap_the_configured_error() {
+    let err = CounterInstructions::from_base64(&metas(1), "CQk=").unwrap_err();
+    assert!(err.downcast_ref::<AppError>().is_some());
+}
+
+#[test]
+fn default_errors_downcast_to_decode_error() {
+    let err = default_counter::CounterInstructions::deserialize(&metas(1), [0; 8]).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DecodeError>(),
+        Some(DecodeError::UnknownDiscriminator { .. })
+    ));
+    assert!(err.to_string().starts_with("unknown discriminator"));
+
+    let err = default_counter::CounterInstructions::deserialize(&metas(1), [1; 5]).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DecodeError>(),
+        Some(DecodeError::Io(_))
+    ));
+}
+
+#[test]
+fn io_errors_are_the_source_of_decode_errors() {
+    use std::error::Error;
+
+    let err = DecodeError::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "bad bool",
+    ));
+    let source = err.source().expect("I/O errors have a source");
+    let io = source.downcast_ref::<std::io::Error>().unwrap();
+    assert_eq!(io.kind(), std::io::ErrorKind::InvalidData);
+
+    let err = DecodeError::InvalidAccountLen {
+        expected: 1,
+        got: 2,
+    };
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn unknown_discriminators_carry_their_bytes_and_the_closest_instruction() {
+    // One bit away from `set_value`'s discriminator
+    let mut data = default_counter::SET_VALUE_DISCRIMINATOR;
+    data[3] ^= 0b100;
+    let err = default_counter::CounterInstructions::deserialize(&metas(1), data).unwrap_err();
+    let Some(DecodeError::UnknownDiscriminator {
+        discriminator,
+        closest,
+    }) = err.downcast_ref::<DecodeError>()
+    else {
+        panic!("expected an unknown discriminator, got {:?}", err);
+    };
+    assert_eq!(discriminator, &data);
+    assert_eq!(*closest, Some("set_value"));
+
+    // Without validation the bytes are all there is
+    let err = unvalidated::CounterInstructions::deserialize(&metas(1), data).unwrap_err();
+    assert_eq!(err.to_string(), format!("unknown discriminator {:?}", data));
+}
@@ -0,0 +1,110 @@
+mod auction {
+    solana_idl_parser::parse_idl!("tests/idls/events.json");
+}
+
+use auction::{AuctionClosed, AuctionEvents, BidPlaced, AUCTION_CLOSED_EVENT_DISCRIMINATOR};
+use solana_idl_parser::{Pubkey, EVENT_CPI_TAG};
+
+#[test]
+fn events_with_inline_fields_are_decoded() {
+    let bidder = Pubkey::new_from_array([7; 32]);
+    let mut data = BidPlaced::EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&bidder.to_bytes());
+    data.extend_from_slice(&500u64.to_le_bytes());
+
+    let event = BidPlaced::from_event_data(&data).unwrap();
+    assert_eq!(event.bidder, bidder);
+    assert_eq!(event.amount, 500);
+}
+
+#[test]
+fn events_declared_in_types_are_decoded() {
+    let mut data = AUCTION_CLOSED_EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&900u64.to_le_bytes());
+
+    let event = AuctionClosed::from_event_data(data).unwrap();
+    assert_eq!(event.winning_bid, 900);
+}
+
+#[test]
+fn event_data_must_start_with_the_discriminator() {
+    let mut data = AUCTION_CLOSED_EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&900u64.to_le_bytes());
+
+    assert!(BidPlaced::from_event_data(&data).is_err());
+    assert!(BidPlaced::from_event_data([135, 4]).is_err());
+}
+
+#[test]
+fn events_enum_dispatches_on_the_discriminator() {
+    let mut data = AUCTION_CLOSED_EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&900u64.to_le_bytes());
+
+    assert_eq!(
+        AuctionEvents::from_event_data(&data).unwrap(),
+        AuctionEvents::AuctionClosed(AuctionClosed { winning_bid: 900 })
+    );
+    assert!(AuctionEvents::from_event_data([1; 16]).is_err());
+}
+
+#[test]
+fn cpi_events_are_decoded_from_inner_instructions() {
+    let mut data = EVENT_CPI_TAG.to_vec();
+    data.extend_from_slice(&AUCTION_CLOSED_EVENT_DISCRIMINATOR);
+    data.extend_from_slice(&900u64.to_le_bytes());
+
+    let event = auction::decode_event_from_inner_instruction(&auction::PROGRAM_ID, &data).unwrap();
+    assert_eq!(
+        event,
+        Some(AuctionEvents::AuctionClosed(AuctionClosed {
+            winning_bid: 900
+        }))
+    );
+
+    // Other programs' instructions and the program's own instructions are not events
+    let other_program = Pubkey::new_from_array([9; 32]);
+    assert_eq!(
+        auction::decode_event_from_inner_instruction(&other_program, &data).unwrap(),
+        None
+    );
+    assert_eq!(
+        auction::decode_event_from_inner_instruction(
+            &auction::PROGRAM_ID,
+            auction::PLACE_BID_DISCRIMINATOR
+        )
+        .unwrap(),
+        None
+    );
+
+    // A CPI event with an unknown discriminator is an error
+    let mut unknown = EVENT_CPI_TAG.to_vec();
+    unknown.extend_from_slice(&[0; 16]);
+    assert!(auction::decode_event_from_inner_instruction(&auction::PROGRAM_ID, &unknown).is_err());
+}
+
+mod auction_house {
+    solana_idl_parser::parse_idl!("tests/idls/events.json", enum_name = "AuctionHouse");
+}
+
+#[test]
+fn enum_name_option_renames_program_wide_types() {
+    let mut data = auction::PLACE_BID_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&5u64.to_le_bytes());
+
+    let ix = auction_house::AuctionHouseInstructions::deserialize(&[], &data).unwrap();
+    assert_eq!(
+        ix.kind(),
+        auction_house::AuctionHouseInstructionKind::PlaceBid
+    );
+
+    let mut data = AUCTION_CLOSED_EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&900u64.to_le_bytes());
+    assert!(matches!(
+        auction_house::AuctionHouseEvents::from_event_data(&data).unwrap(),
+        auction_house::AuctionHouseEvents::AuctionClosed(_)
+    ));
+
+    struct Noop;
+    impl auction_house::AuctionHouseVisitor for Noop {}
+    ix.accept(&mut Noop);
+}
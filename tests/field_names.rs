@@ -0,0 +1,42 @@
+mod renamed {
+    solana_idl_parser::parse_idl!("tests/idls/field_names.json");
+}
+
+use renamed::{RenamedInstructions, TransferAccounts, TransferArgs, TRANSFER_DISCRIMINATOR};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+#[test]
+fn names_colliding_after_snake_case_are_suffixed() {
+    let accounts = TransferAccounts {
+        user_account: key(0),
+        user_account_1: key(1),
+        user_account_2: key(2),
+        authority: key(3),
+        authority_2: key(4),
+        authority_1: key(5),
+    };
+    let args = TransferArgs {
+        new_value: 500,
+        new_value_1: 7,
+    };
+
+    let metas: Vec<AccountMeta> = (0..6)
+        .map(|i| AccountMeta {
+            pubkey: key(i),
+            is_signer: i == 3,
+            is_writable: i < 2,
+        })
+        .collect();
+    let mut data = TRANSFER_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.push(7);
+
+    assert_eq!(
+        RenamedInstructions::deserialize(&metas, &data).unwrap(),
+        RenamedInstructions::Transfer(accounts, args)
+    );
+}
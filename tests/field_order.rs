@@ -0,0 +1,25 @@
+mod packed {
+    solana_idl_parser::parse_idl!("tests/idls/field_order.json");
+}
+
+use packed::{ConfigureArgs, PackedInstructions, Settings, CONFIGURE_DISCRIMINATOR};
+
+#[test]
+fn fields_are_decoded_in_the_declared_wire_order() {
+    let mut data = CONFIGURE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(2);
+    data.push(1);
+
+    let ix = PackedInstructions::deserialize(&[], &data).unwrap();
+    let settings = Settings {
+        enabled: true,
+        limit: 1_000,
+        mode: 2,
+    };
+    assert_eq!(
+        ix,
+        PackedInstructions::Configure(ConfigureArgs { settings })
+    );
+    assert_eq!(ix.to_bytes().unwrap(), data);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "fuzz")]
+
+mod config_update {
+    solana_idl_parser::parse_idl!("tests/idls/config_update.json");
+}
+
+mod type_aliases {
+    solana_idl_parser::parse_idl!("tests/idls/type_aliases.json");
+}
+
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Deterministic pseudo-random input bytes
+fn input(seed: u64) -> Vec<u8> {
+    let mut state = seed;
+    (0..4096)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_instructions_round_trip() {
+    for seed in 0..64 {
+        let data = input(seed);
+        let mut u = Unstructured::new(&data);
+        let ix = config_update::ConfigUpdateInstructions::arbitrary(&mut u).unwrap();
+
+        let instruction = ix.into_instruction().unwrap();
+        let decoded = config_update::ConfigUpdateInstructions::deserialize(
+            &instruction.accounts,
+            &instruction.data,
+        )
+        .unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), instruction.data);
+    }
+}
+
+#[test]
+fn arbitrary_args_with_aliased_keys_round_trip() {
+    for seed in 0..64 {
+        let data = input(seed);
+        let mut u = Unstructured::new(&data);
+        let args = type_aliases::PayoutArgs::arbitrary(&mut u).unwrap();
+
+        let bytes = borsh::to_vec(&args).unwrap();
+        let decoded: type_aliases::PayoutArgs = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(borsh::to_vec(&decoded).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,34 @@
+mod lending {
+    solana_idl_parser::parse_idl!("tests/idls/generics.json");
+}
+
+use lending::{Bag, DepositArgs, Foo, LendingInstructions, WrappedI80F48, DEPOSIT_DISCRIMINATOR};
+
+#[test]
+fn args_with_generic_instantiations_decode() {
+    let args = DepositArgs {
+        rate: WrappedI80F48 { value: -3 },
+        bag: Bag {
+            first: Foo { weight: 0.5 },
+            rest: vec![Foo { weight: 1.5 }],
+        },
+        amounts: Some(Bag {
+            first: 1u64,
+            rest: vec![2, 3],
+        }),
+    };
+    let mut data = DEPOSIT_DISCRIMINATOR.to_vec();
+    data.extend(borsh::to_vec(&args).unwrap());
+
+    let ix = LendingInstructions::deserialize(&[], &data).unwrap();
+    assert_eq!(ix, LendingInstructions::Deposit(args));
+    assert_eq!(ix.to_bytes().unwrap(), data);
+}
+
+#[test]
+fn generic_types_derive_for_instantiations_that_allow_it() {
+    fn assert_hash<T: std::hash::Hash>() {}
+
+    // `Foo` holds a float, so `Bag<Foo>` cannot derive `Hash`, but `Bag<u64>` can
+    assert_hash::<Bag<u64>>();
+}
@@ -0,0 +1,48 @@
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+mod programs {
+    solana_idl_parser::parse_idl_dir!("tests/idl_dir");
+}
+
+#[test]
+fn each_idl_gets_a_module_named_after_its_file_stem() {
+    // `price-oracle.json`, whose program is named `oracle`
+    let feed = programs::price_oracle::PriceFeed {
+        price: 6_500_000,
+        exponent: -5,
+    };
+    let mut data = programs::price_oracle::PRICE_FEED_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&feed).unwrap());
+    assert_eq!(
+        programs::price_oracle::PriceFeed::from_account_data(&data).unwrap(),
+        feed
+    );
+
+    assert_eq!(programs::counter::INSTRUCTION_COUNT, 2);
+}
+
+#[test]
+fn decode_any_covers_the_directory() {
+    let accounts = vec![
+        AccountMeta {
+            pubkey: Pubkey::new_from_array([1; 32]),
+            is_signer: true,
+            is_writable: true,
+        };
+        programs::counter::INITIALIZE_IX_ACCOUNTS_LEN
+    ];
+    let decoded = programs::decode_any(
+        &programs::counter::PROGRAM_ID,
+        &accounts,
+        programs::counter::INITIALIZE_DISCRIMINATOR,
+    );
+    assert!(matches!(
+        decoded,
+        Some(Ok(programs::AnyInstruction::Counter(_)))
+    ));
+}
+
+#[test]
+fn malformed_idls_are_reported_without_dropping_the_others() {
+    trybuild::TestCases::new().compile_fail("tests/ui/idl_dir_malformed.rs");
+}
@@ -0,0 +1,80 @@
+mod shop {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/instruction_modules.json",
+        instruction_modules = true,
+    );
+}
+
+use shop::{buy, close, BuyArgs, ShopInstructionKind, ShopInstructions, ShopVisitor};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8 + 1; 32]),
+            is_signer: i == 0,
+            is_writable: i > 0,
+        })
+        .collect()
+}
+
+fn buy_data() -> Vec<u8> {
+    let mut data = buy::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&5u64.to_le_bytes());
+    data.extend_from_slice(&70u64.to_le_bytes());
+    data
+}
+
+#[test]
+fn instruction_items_live_in_their_modules() {
+    assert_eq!(buy::ACCOUNTS_LEN, 2);
+    assert!(buy::matches_discriminator(&buy_data()));
+    assert!(!close::matches_discriminator(&buy_data()));
+    assert_eq!(shop::discriminator_of("close"), Some(close::DISCRIMINATOR));
+
+    let ix = ShopInstructions::deserialize(&metas(2), buy_data()).unwrap();
+    let ShopInstructions::Buy(accounts, args) = &ix else {
+        panic!("expected buy, got {:?}", ix);
+    };
+    // The module's `Args` and the IDL's `BuyArgs` type no longer compete for a name
+    let expected: buy::Args = buy::Args {
+        amount: 5,
+        limits: BuyArgs { max_price: 70 },
+    };
+    assert_eq!(*args, expected);
+    assert_eq!(
+        *accounts,
+        buy::Accounts::from_account_metas(&metas(2)).unwrap()
+    );
+    assert_eq!(ix.kind(), ShopInstructionKind::Buy);
+    assert_eq!(ix.to_bytes().unwrap(), buy_data());
+}
+
+#[test]
+#[cfg(not(feature = "minimal-pubkey"))]
+fn pda_helpers_are_generated_inside_modules() {
+    let accounts = buy::Accounts::from_account_metas(&metas(2)).unwrap();
+    let args = buy::Args {
+        amount: 1,
+        limits: BuyArgs { max_price: 2 },
+    };
+    let (address, _) = accounts.find_receipt_address(&args);
+    assert_eq!(
+        address,
+        Pubkey::find_program_address(&[b"receipt", accounts.buyer.as_ref()], &shop::PROGRAM_ID).0
+    );
+}
+
+#[test]
+fn visitors_take_the_module_structs() {
+    struct Closes(usize);
+    impl ShopVisitor for Closes {
+        fn visit_close(&mut self, _accounts: &close::Accounts) {
+            self.0 += 1;
+        }
+    }
+    let ix = ShopInstructions::deserialize(&metas(1), close::DISCRIMINATOR).unwrap();
+    let mut closes = Closes(0);
+    ix.accept(&mut closes);
+    assert_eq!(closes.0, 1);
+}
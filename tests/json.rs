@@ -0,0 +1,208 @@
+#![cfg(feature = "serde")]
+
+mod all_types {
+    solana_idl_parser::parse_idl!("tests/idls/all_types.json");
+}
+
+use all_types::*;
+use serde_json::json;
+use solana_idl_parser::Pubkey;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+fn data(discriminator: [u8; 8], args: impl borsh::BorshSerialize) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend(borsh::to_vec(&args).unwrap());
+    data
+}
+
+#[test]
+fn instructions_decode_to_json_keyed_by_name_with_base58_keys() {
+    let key = Pubkey::new_from_array([1; 32]);
+    let other = Pubkey::new_from_array([2; 32]);
+    let args = ContainersArgs {
+        maybe_owner: Some(key),
+        amounts: vec![5, 6],
+        seed: [1, 2, 3, 4],
+        keys: [key, other],
+        pair: (7, "seven".to_string(), other),
+        balances: HashMap::from([("alice".to_string(), 10)]),
+        ordered: BTreeMap::from([(key, vec![-1])]),
+        tags: HashSet::from([3]),
+        members: BTreeSet::from([other]),
+        nested: vec![None, Some([1, -2, 3])],
+    };
+
+    let value = AllTypesInstructions::decode_to_json(data(CONTAINERS_DISCRIMINATOR, args)).unwrap();
+    let (key, other) = (key.to_string(), other.to_string());
+    assert_eq!(
+        value,
+        json!({
+            "containers": {
+                "maybe_owner": key,
+                "amounts": [5, 6],
+                "seed": [1, 2, 3, 4],
+                "keys": [key, other],
+                "pair": [7, "seven", other],
+                "balances": { "alice": 10 },
+                "ordered": { key.clone(): [-1] },
+                "tags": [3],
+                "members": [other],
+                "nested": [null, [1, -2, 3]],
+            }
+        })
+    );
+}
+
+#[test]
+fn integers_past_json_numbers_decode_to_strings() {
+    let args = PrimitivesArgs {
+        flag: true,
+        small: 1,
+        medium: 2,
+        word: 3,
+        large: u64::MAX,
+        huge: u128::MAX,
+        signed_small: -1,
+        signed_medium: -2,
+        signed_word: -3,
+        signed_large: i64::MIN,
+        signed_huge: i128::MIN,
+        ratio: 0.5,
+        price: 1.25,
+        label: "label".to_string(),
+        payload: vec![9],
+        owner: Pubkey::new_from_array([3; 32]),
+    };
+
+    let value = AllTypesInstructions::decode_to_json(data(PRIMITIVES_DISCRIMINATOR, args)).unwrap();
+    let args = &value["primitives"];
+    assert_eq!(args["large"], json!(u64::MAX));
+    assert_eq!(args["huge"], json!(u128::MAX.to_string()));
+    assert_eq!(args["signed_huge"], json!(i128::MIN.to_string()));
+    assert_eq!(
+        args["owner"],
+        json!(Pubkey::new_from_array([3; 32]).to_string())
+    );
+}
+
+#[test]
+fn instructions_without_args_decode_to_an_empty_object() {
+    let value = AllTypesInstructions::decode_to_json(PING_DISCRIMINATOR).unwrap();
+    assert_eq!(value, json!({ "ping": {} }));
+}
+
+#[test]
+fn unknown_discriminators_are_an_error() {
+    let error = AllTypesInstructions::decode_to_json([9; 8]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown discriminator [9, 9, 9, 9, 9, 9, 9, 9], closest to instruction `primitives`"
+    );
+}
+
+mod referrals {
+    solana_idl_parser::parse_idl!("tests/idls/optional_accounts.json");
+}
+
+#[test]
+fn instructions_serialize_in_anchor_coder_shape() {
+    use referrals::{ReferralsInstructions, SwapAccounts, SwapArgs};
+
+    let user = Pubkey::new_from_array([1; 32]);
+    let pool = Pubkey::new_from_array([2; 32]);
+    let ix = ReferralsInstructions::Swap(
+        SwapAccounts {
+            user,
+            referrer: None,
+            pool,
+            fee_vault: Some(pool),
+        },
+        SwapArgs { amount: 50 },
+    );
+    assert_eq!(
+        ix.to_anchor_json().unwrap(),
+        json!({
+            "name": "swap",
+            "data": { "amount": 50 },
+            "accounts": {
+                "user": user.to_string(),
+                "referrer": null,
+                "pool": pool.to_string(),
+                "feeVault": pool.to_string(),
+            }
+        })
+    );
+
+    let ping = AllTypesInstructions::Ping.to_anchor_json().unwrap();
+    assert_eq!(ping, json!({ "name": "ping", "data": {}, "accounts": {} }));
+}
+
+mod idl_names {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/mixed_discriminators.json",
+        serde_idl_names = true
+    );
+}
+
+mod referrals_idl_names {
+    solana_idl_parser::parse_idl!("tests/idls/optional_accounts.json", serde_idl_names = true);
+}
+
+#[test]
+fn serde_idl_names_keep_the_camel_case_idl_keys() {
+    use idl_names::{MixedInstructions, SwapAccounts, SwapArgs};
+
+    let pool = Pubkey::new_from_array([2; 32]);
+    let ix = MixedInstructions::Swap(SwapAccounts { pool }, SwapArgs { amount_in: 5 });
+    let value = serde_json::to_value(&ix).unwrap();
+    assert_eq!(value["swap"][1], json!({ "amountIn": 5 }));
+
+    let ix = MixedInstructions::LegacyTransfer(
+        idl_names::LegacyTransferAccounts { source: pool },
+        idl_names::LegacyTransferArgs { amount: 1 },
+    );
+    let value = serde_json::to_value(&ix).unwrap();
+    assert!(value.get("legacyTransfer").is_some(), "{}", value);
+    assert!(value.get("LegacyTransfer").is_none(), "{}", value);
+    assert_eq!(
+        serde_json::from_value::<MixedInstructions>(value).unwrap(),
+        ix
+    );
+
+    let accounts = referrals_idl_names::SwapAccounts {
+        user: pool,
+        referrer: None,
+        pool,
+        fee_vault: None,
+    };
+    let value = serde_json::to_value(accounts).unwrap();
+    assert!(value.get("feeVault").is_some(), "{}", value);
+    assert!(value.get("fee_vault").is_none(), "{}", value);
+}
+
+mod key_like_types {
+    solana_idl_parser::parse_idl!("tests/idls/key_like_types.json");
+}
+
+#[test]
+fn only_key_fields_are_written_as_base58() {
+    let owner = Pubkey::new_from_array([4; 32]);
+    let args = key_like_types::RegisterArgs {
+        owner,
+        address: key_like_types::Address([5; 32]),
+        key: key_like_types::Pubkey { bytes: [6; 32] },
+    };
+
+    let data = data(key_like_types::REGISTER_DISCRIMINATOR, args);
+    let value = key_like_types::KeyLikeTypesInstructions::decode_to_json(data).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "register": {
+                "owner": owner.to_string(),
+                "address": vec![5; 32],
+                "key": { "bytes": vec![6; 32] },
+            }
+        })
+    );
+}
@@ -0,0 +1,93 @@
+mod pools {
+    solana_idl_parser::parse_idl!("tests/idls/layout.json", deny_trailing_bytes = true);
+}
+
+use pools::*;
+use solana_idl_parser::Pubkey;
+
+fn pool() -> PoolState {
+    PoolState {
+        authority: Pubkey::new_from_array([7; 32]),
+        status: Status::Paused,
+        fees: Fees {
+            trade_fee_bps: 30,
+            protocol_share: (2, -5),
+        },
+        reserves: [1_000, 2_000],
+        bump: 254,
+    }
+}
+
+#[test]
+fn fixed_structs_list_field_offsets() {
+    assert_eq!(
+        POOL_STATE_LAYOUT,
+        [
+            ("authority", 0, 32),
+            ("status", 32, 1),
+            ("fees", 33, 14),
+            ("reserves", 47, 16),
+            ("bump", 63, 1),
+        ]
+    );
+    assert_eq!(
+        FEES_LAYOUT,
+        [("trade_fee_bps", 0, 2), ("protocol_share", 2, 12)]
+    );
+}
+
+#[test]
+fn fields_can_be_sliced_from_account_data() {
+    let mut data = POOL_STATE_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&pool()).unwrap());
+    let body = PoolState::body(&data).unwrap();
+
+    let field = |name: &str| {
+        let (_, offset, size) = POOL_STATE_LAYOUT
+            .iter()
+            .find(|(field, _, _)| *field == name)
+            .unwrap();
+        &body[*offset..offset + size]
+    };
+    assert_eq!(field("authority"), [7; 32]);
+    assert_eq!(field("status"), [1]);
+    assert_eq!(
+        u64::from_le_bytes(field("reserves")[8..].try_into().unwrap()),
+        2_000
+    );
+    assert_eq!(field("bump"), [254]);
+
+    let (_, last_offset, last_size) = POOL_STATE_LAYOUT.last().unwrap();
+    assert_eq!(last_offset + last_size, body.len());
+}
+
+#[test]
+fn body_is_none_without_the_account_discriminator() {
+    let mut data = POOL_STATE_ACCOUNT_DISCRIMINATOR.to_vec();
+    assert_eq!(PoolState::body(&data), Some(&[][..]));
+    data[0] ^= 1;
+    assert_eq!(PoolState::body(&data), None);
+    assert_eq!(
+        PoolState::body(&POOL_STATE_ACCOUNT_DISCRIMINATOR[..7]),
+        None
+    );
+}
+
+#[test]
+fn account_padding_is_ignored_while_instruction_trailing_bytes_are_not() {
+    // Allocated with 128 reserved bytes the IDL does not describe
+    let mut data = POOL_STATE_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&pool()).unwrap());
+    data.extend_from_slice(&[0; 128]);
+    assert_eq!(PoolState::from_account_data(&data).unwrap(), pool());
+
+    let accounts = [solana_idl_parser::AccountMeta {
+        pubkey: Pubkey::new_from_array([1; 32]),
+        is_signer: false,
+        is_writable: true,
+    }];
+    let mut ix = INITIALIZE_DISCRIMINATOR.to_vec();
+    assert!(PoolsInstructions::deserialize(&accounts, &ix).is_ok());
+    ix.push(0);
+    assert!(PoolsInstructions::deserialize(&accounts, &ix).is_err());
+}
@@ -0,0 +1,66 @@
+mod counter {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/spec_0_1_0.json",
+        legacy_discriminators = {
+            set_value: [7, 7, 7, 7, 7, 7, 7, 7],
+            set_value: [8, 8, 8, 8, 8, 8, 8, 8],
+        },
+    );
+}
+
+mod table {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/spec_0_1_0.json",
+        dispatch_table = true,
+        legacy_discriminators = { set_value: [7, 7, 7, 7, 7, 7, 7, 7] },
+    );
+}
+
+use counter::CounterInstructions;
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+fn set_value(discriminator: [u8; 8], value: u64) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&value.to_le_bytes());
+    data
+}
+
+#[test]
+fn legacy_discriminators_decode_to_the_instruction() {
+    for discriminator in [counter::SET_VALUE_DISCRIMINATOR, [7; 8], [8; 8]] {
+        let data = set_value(discriminator, 42);
+        match CounterInstructions::deserialize(&metas(1), &data).unwrap() {
+            CounterInstructions::SetValue(_, args) => assert_eq!(args.value, 42),
+            other => panic!("expected set_value, got {:?}", other),
+        }
+        assert!(counter::matches_set_value_discriminator(&data));
+    }
+}
+
+#[test]
+fn legacy_discriminators_are_not_written() {
+    let data = set_value([7; 8], 42);
+    let ix = CounterInstructions::deserialize(&metas(1), &data).unwrap();
+    assert_eq!(
+        ix.to_bytes().unwrap(),
+        set_value(counter::SET_VALUE_DISCRIMINATOR, 42)
+    );
+}
+
+#[test]
+fn dispatch_tables_include_legacy_discriminators() {
+    let data = set_value([7; 8], 42);
+    let ix = table::CounterInstructions::deserialize(&metas(1), &data).unwrap();
+    assert!(matches!(ix, table::CounterInstructions::SetValue(_, _)));
+    assert!(table::CounterInstructions::deserialize(&metas(1), set_value([8; 8], 42)).is_err());
+}
@@ -0,0 +1,44 @@
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+mod strict {
+    solana_idl_parser::parse_idl! {
+        counter => "tests/idls/spec_0_1_0.json",
+        oracle => "tests/idls/accounts_only.json",
+        deny_trailing_bytes = true,
+    }
+}
+
+mod relaxed {
+    solana_idl_parser::parse_idl! {
+        counter => "tests/idls/spec_0_1_0.json",
+    }
+}
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn options_apply_to_every_idl() {
+    let mut data = strict::counter::SET_VALUE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data.push(0);
+    let accounts = metas(strict::counter::SET_VALUE_IX_ACCOUNTS_LEN);
+
+    let strict = strict::decode_any(&strict::counter::PROGRAM_ID, &accounts, &data);
+    assert!(matches!(strict, Some(Err(_))));
+
+    let relaxed = relaxed::decode_any(&relaxed::counter::PROGRAM_ID, &accounts, &data);
+    assert!(matches!(
+        relaxed,
+        Some(Ok(relaxed::AnyInstruction::Counter(
+            relaxed::counter::CounterInstructions::SetValue { .. }
+        )))
+    ));
+}
@@ -0,0 +1,114 @@
+mod pools {
+    solana_idl_parser::parse_idl!("tests/idls/nested_accounts.json");
+}
+
+use pools::{SwapAccounts, SWAP_IX_ACCOUNTS_LEN};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: i == 0,
+            is_writable: i == 1 || i == 2,
+        })
+        .collect()
+}
+
+#[test]
+fn nested_groups_are_flattened_in_order() {
+    assert_eq!(SWAP_IX_ACCOUNTS_LEN, 4);
+
+    let metas = metas(4);
+    let accounts = SwapAccounts::from_account_metas(&metas).unwrap();
+    assert_eq!(accounts.user, metas[0].pubkey);
+    assert_eq!(accounts.pool_state, metas[1].pubkey);
+    assert_eq!(accounts.pool_vault, metas[2].pubkey);
+    assert_eq!(accounts.token_program, metas[3].pubkey);
+    assert_eq!(accounts.to_account_metas(), metas);
+
+    let names: Vec<&str> = accounts
+        .named_accounts()
+        .iter()
+        .map(|(name, _)| *name)
+        .collect();
+    assert_eq!(names, ["user", "pool_state", "pool_vault", "tokenProgram"]);
+}
+
+#[cfg(not(feature = "minimal-pubkey"))]
+#[test]
+fn seeds_of_grouped_accounts_refer_to_their_siblings() {
+    let args = pools::SwapArgs { amount: 5 };
+    let mut accounts = SwapAccounts::from_account_metas(&metas(4)).unwrap();
+    let (vault, _) = Pubkey::find_program_address(
+        &[b"vault", accounts.pool_state.as_ref()],
+        &pools::PROGRAM_ID,
+    );
+    assert_eq!(accounts.find_pool_vault_address(&args).0, vault);
+
+    accounts.pool_vault = vault;
+    assert!(accounts.verify_pool_vault_address(&args, None).is_ok());
+}
+
+#[cfg(not(feature = "minimal-pubkey"))]
+#[test]
+fn const_seeds_hold_the_idl_bytes_and_derive_the_pda() {
+    // The `value` of the vault's const seed in the IDL
+    assert_eq!(pools::POOL_VAULT_PDA_CONST_SEED, [118, 97, 117, 108, 116]);
+
+    let args = pools::SwapArgs { amount: 5 };
+    let accounts = SwapAccounts::from_account_metas(&metas(4)).unwrap();
+    let derived = Pubkey::find_program_address(
+        &[
+            pools::POOL_VAULT_PDA_CONST_SEED,
+            accounts.pool_state.as_ref(),
+        ],
+        &pools::PROGRAM_ID,
+    );
+    assert_eq!(derived, accounts.find_pool_vault_address(&args));
+}
+
+#[cfg(not(feature = "minimal-pubkey"))]
+#[test]
+fn verify_returns_the_canonical_bump() {
+    let args = pools::SwapArgs { amount: 5 };
+    let mut accounts = SwapAccounts::from_account_metas(&metas(4)).unwrap();
+    let (vault, bump) = Pubkey::find_program_address(
+        &[b"vault", accounts.pool_state.as_ref()],
+        &pools::PROGRAM_ID,
+    );
+    accounts.pool_vault = vault;
+
+    assert_eq!(
+        accounts.verify_pool_vault_address(&args, None).unwrap(),
+        bump
+    );
+    assert_eq!(
+        accounts
+            .verify_pool_vault_address(&args, Some(bump))
+            .unwrap(),
+        bump
+    );
+}
+
+#[cfg(not(feature = "minimal-pubkey"))]
+#[test]
+fn verify_rejects_a_wrong_address_or_bump() {
+    let args = pools::SwapArgs { amount: 5 };
+    let mut accounts = SwapAccounts::from_account_metas(&metas(4)).unwrap();
+    let err = accounts.verify_pool_vault_address(&args, None).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "account `pool_vault` does not match its PDA"
+    );
+
+    let (vault, bump) = accounts.find_pool_vault_address(&args);
+    accounts.pool_vault = vault;
+    let err = accounts
+        .verify_pool_vault_address(&args, Some(bump.wrapping_sub(1)))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "bump of `pool_vault` does not match its PDA"
+    );
+}
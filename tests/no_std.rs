@@ -0,0 +1,67 @@
+//! Behaviour of code generated with `no_std = true`; `tests/no_std_crate`
+//! checks that it builds in a `no_std` crate
+
+extern crate alloc;
+
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json", no_std = true);
+}
+
+use counter::{Counter, CounterInstructions, SetValueAccounts, SetValueArgs};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn no_std_instructions_round_trip() {
+    let ix = CounterInstructions::SetValue(
+        SetValueAccounts::from_account_metas(&metas(1)).unwrap(),
+        SetValueArgs { value: 42 },
+    );
+    let data = ix.to_bytes().unwrap();
+
+    match CounterInstructions::deserialize(&metas(1), &data).unwrap() {
+        CounterInstructions::SetValue(_, args) => assert_eq!(args.value, 42),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn no_std_errors_are_borsh_io_errors() {
+    let err: borsh::io::Error = CounterInstructions::deserialize(&metas(1), &[0; 8]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown discriminator [0, 0, 0, 0, 0, 0, 0, 0], closest to instruction `initialize`"
+    );
+
+    let err: borsh::io::Error = Counter::from_account_data(&[0; 8]).unwrap_err();
+    assert_eq!(err.to_string(), "account discriminator mismatch");
+}
+
+#[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
+fn short_data_is_rejected_without_panicking() {
+    let err = CounterInstructions::deserialize(&metas(1), &[1, 2, 3]).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::UnexpectedEof);
+
+    let err = Counter::from_account_data(&[]).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn no_std_decoding_accepts_arrays_and_vecs() {
+    let err = CounterInstructions::deserialize(&metas(1), [1, 2, 3]).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::UnexpectedEof);
+
+    let err = Counter::from_account_data(alloc::vec![0; 8]).unwrap_err();
+    assert_eq!(err.to_string(), "account discriminator mismatch");
+}
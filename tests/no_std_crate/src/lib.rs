@@ -0,0 +1,99 @@
+//! A `no_std` crate holding code generated with `no_std = true` for the
+//! sample IDLs. CI builds it for a target without `std`, which fails if the
+//! generated code or the runtime crate it uses needs `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod accounts_only {
+    solana_idl_parser::parse_idl!("../idls/accounts_only.json", no_std = true);
+}
+
+pub mod borsh_attributes {
+    solana_idl_parser::parse_idl!("../idls/borsh_attributes.json", no_std = true);
+}
+
+pub mod bytes_len {
+    solana_idl_parser::parse_idl!("../idls/bytes_len.json", no_std = true);
+}
+
+pub mod config_update {
+    solana_idl_parser::parse_idl!("../idls/config_update.json", no_std = true);
+}
+
+pub mod constants {
+    solana_idl_parser::parse_idl!("../idls/constants.json", no_std = true);
+}
+
+pub mod enum_repr {
+    solana_idl_parser::parse_idl!("../idls/enum_repr.json", no_std = true);
+}
+
+pub mod events {
+    solana_idl_parser::parse_idl!("../idls/events.json", no_std = true);
+}
+
+pub mod field_names {
+    solana_idl_parser::parse_idl!("../idls/field_names.json", no_std = true);
+}
+
+pub mod field_order {
+    solana_idl_parser::parse_idl!("../idls/field_order.json", no_std = true);
+}
+
+pub mod generics {
+    solana_idl_parser::parse_idl!("../idls/generics.json", no_std = true);
+}
+
+pub mod instruction_modules {
+    solana_idl_parser::parse_idl!("../idls/instruction_modules.json", no_std = true);
+}
+
+pub mod key_like_types {
+    solana_idl_parser::parse_idl!("../idls/key_like_types.json", no_std = true);
+}
+
+pub mod layout {
+    solana_idl_parser::parse_idl!("../idls/layout.json", no_std = true);
+}
+
+pub mod legacy {
+    solana_idl_parser::parse_idl!("../idls/legacy.json", no_std = true);
+}
+
+pub mod mixed_discriminators {
+    solana_idl_parser::parse_idl!("../idls/mixed_discriminators.json", no_std = true);
+}
+
+pub mod native_token {
+    solana_idl_parser::parse_idl!("../idls/native_token.json", no_std = true);
+}
+
+pub mod nested_accounts {
+    solana_idl_parser::parse_idl!("../idls/nested_accounts.json", no_std = true);
+}
+
+pub mod optional_accounts {
+    solana_idl_parser::parse_idl!("../idls/optional_accounts.json", no_std = true);
+}
+
+pub mod shank {
+    solana_idl_parser::parse_idl!("../idls/shank.json", no_std = true);
+}
+
+pub mod spec_0_1_0 {
+    solana_idl_parser::parse_idl!("../idls/spec_0_1_0.json", no_std = true);
+}
+
+pub mod state_machine {
+    solana_idl_parser::parse_idl!("../idls/state_machine.json", no_std = true);
+}
+
+pub mod type_aliases {
+    solana_idl_parser::parse_idl!("../idls/type_aliases.json", no_std = true);
+}
+
+pub mod versioned {
+    solana_idl_parser::parse_idl!("../idls/versioned.json", no_std = true);
+}
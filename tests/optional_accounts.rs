@@ -0,0 +1,74 @@
+mod referrals {
+    solana_idl_parser::parse_idl!("tests/idls/optional_accounts.json");
+}
+
+use referrals::{SwapAccounts, PROGRAM_ID};
+use solana_idl_parser::{AccountMeta, DecodeError, Pubkey};
+
+fn meta(pubkey: Pubkey, is_signer: bool, is_writable: bool) -> AccountMeta {
+    AccountMeta {
+        pubkey,
+        is_signer,
+        is_writable,
+    }
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+#[test]
+fn omitted_middle_optional_account_decodes_as_none() {
+    let metas = [
+        meta(key(1), true, false),
+        meta(PROGRAM_ID, false, false),
+        meta(key(3), false, true),
+        meta(key(4), false, true),
+    ];
+
+    let accounts = SwapAccounts::from_account_metas(&metas).unwrap();
+    assert_eq!(
+        accounts,
+        SwapAccounts {
+            user: key(1),
+            referrer: None,
+            pool: key(3),
+            fee_vault: Some(key(4)),
+        }
+    );
+    assert_eq!(accounts.to_account_metas(), metas);
+}
+
+#[test]
+fn trailing_optional_accounts_may_be_left_out() {
+    let metas = [
+        meta(key(1), true, false),
+        meta(key(2), false, true),
+        meta(key(3), false, true),
+    ];
+
+    let accounts = SwapAccounts::from_account_metas(&metas).unwrap();
+    assert_eq!(accounts.referrer, Some(key(2)));
+    assert_eq!(accounts.fee_vault, None);
+    assert_eq!(accounts.named_accounts()[3], ("feeVault", PROGRAM_ID));
+
+    // Required accounts must still be present
+    let err = SwapAccounts::from_account_metas(&metas[..2]).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DecodeError>(),
+        Some(DecodeError::InvalidAccountLen {
+            expected: 3,
+            got: 2
+        })
+    ));
+}
+
+#[test]
+fn extra_accounts_report_the_maximum_length() {
+    let metas = vec![meta(key(1), false, false); 5];
+    let err = SwapAccounts::from_account_metas(&metas).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid account meta length: expected 4, got 5"
+    );
+}
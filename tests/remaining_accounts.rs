@@ -0,0 +1,61 @@
+mod native_token {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/native_token.json",
+        discriminator_in_args = true,
+        remaining_accounts = true,
+    );
+}
+
+use native_token::{NativeTokenInstructions, TransferAccounts, TRANSFER_IX_ACCOUNTS_LEN};
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: i == 2,
+            is_writable: false,
+        })
+        .collect()
+}
+
+#[test]
+fn extra_account_metas_are_kept() {
+    let metas = metas(TRANSFER_IX_ACCOUNTS_LEN + 2);
+    let accounts = TransferAccounts::from_account_metas(&metas).unwrap();
+    assert_eq!(
+        accounts.remaining_accounts,
+        metas[TRANSFER_IX_ACCOUNTS_LEN..]
+    );
+
+    let roundtrip = accounts.to_account_metas();
+    assert_eq!(roundtrip.len(), metas.len());
+    assert_eq!(
+        roundtrip[TRANSFER_IX_ACCOUNTS_LEN..],
+        metas[TRANSFER_IX_ACCOUNTS_LEN..]
+    );
+}
+
+#[test]
+fn exact_account_metas_leave_remaining_empty() {
+    let accounts = TransferAccounts::from_account_metas(&metas(TRANSFER_IX_ACCOUNTS_LEN)).unwrap();
+    assert!(accounts.remaining_accounts.is_empty());
+}
+
+#[test]
+fn missing_named_accounts_are_rejected() {
+    assert!(TransferAccounts::from_account_metas(&metas(TRANSFER_IX_ACCOUNTS_LEN - 1)).is_err());
+}
+
+#[test]
+fn instructions_decode_with_extra_accounts() {
+    let mut data = vec![3];
+    data.extend_from_slice(&9u64.to_le_bytes());
+
+    match NativeTokenInstructions::deserialize(&metas(5), &data).unwrap() {
+        NativeTokenInstructions::Transfer(accounts, _) => {
+            assert_eq!(accounts.remaining_accounts.len(), 2)
+        }
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
@@ -0,0 +1,136 @@
+#![cfg(all(feature = "rpc", not(feature = "minimal-pubkey")))]
+
+mod versioned {
+    solana_idl_parser::parse_idl!("tests/idls/versioned.json");
+}
+
+use solana_idl_parser::rpc::decode_rpc_transaction;
+use solana_sdk::{
+    hash::Hash,
+    message::{
+        compiled_instruction::CompiledInstruction,
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
+        MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+use solana_transaction_status::{
+    ConfirmedTransactionWithStatusMeta, EncodedConfirmedTransactionWithStatusMeta,
+    InnerInstruction, InnerInstructions, TransactionStatusMeta, TransactionWithStatusMeta,
+    UiTransactionEncoding, VersionedTransactionWithStatusMeta,
+};
+use versioned::VersionedVaultInstructions;
+
+/// A v0 transaction depositing into a vault loaded from a lookup table, then
+/// closing it through a CPI from another program
+fn rpc_transaction(
+    owner: Pubkey,
+    vault: Pubkey,
+    encoding: UiTransactionEncoding,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    let caller = Pubkey::new_unique();
+    let mut deposit = vec![1];
+    deposit.extend_from_slice(&750u64.to_le_bytes());
+
+    let message = VersionedMessage::V0(v0::Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![owner, caller, versioned::PROGRAM_ID],
+        recent_blockhash: Hash::default(),
+        instructions: vec![
+            CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![3, 0],
+                data: deposit,
+            },
+            CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![3],
+                data: vec![],
+            },
+        ],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: Pubkey::new_unique(),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![],
+        }],
+    });
+    let meta = TransactionStatusMeta {
+        inner_instructions: Some(vec![InnerInstructions {
+            index: 1,
+            instructions: vec![InnerInstruction {
+                instruction: CompiledInstruction {
+                    program_id_index: 2,
+                    accounts: vec![3],
+                    data: vec![2],
+                },
+                stack_height: Some(2),
+            }],
+        }]),
+        loaded_addresses: LoadedAddresses {
+            writable: vec![vault],
+            readonly: vec![],
+        },
+        ..TransactionStatusMeta::default()
+    };
+
+    ConfirmedTransactionWithStatusMeta {
+        slot: 1,
+        tx_with_meta: TransactionWithStatusMeta::Complete(VersionedTransactionWithStatusMeta {
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message,
+            },
+            meta,
+        }),
+        block_time: None,
+    }
+    .encode(encoding, Some(0))
+    .unwrap()
+}
+
+#[test]
+fn rpc_transactions_decode_with_loaded_addresses_and_inner_instructions() {
+    let owner = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    for encoding in [UiTransactionEncoding::Base64, UiTransactionEncoding::Base58] {
+        let tx = rpc_transaction(owner, vault, encoding);
+        let decoded = decode_rpc_transaction::<VersionedVaultInstructions>(&tx).unwrap();
+        assert_eq!(decoded.len(), 2);
+
+        let (parent, deposit) = &decoded[0];
+        assert_eq!(*parent, None);
+        match deposit.as_ref().unwrap() {
+            VersionedVaultInstructions::Deposit(accounts, args) => {
+                assert_eq!(accounts.vault, vault);
+                assert_eq!(accounts.owner, owner);
+                assert_eq!(args.amount, 750);
+            }
+            other => panic!("expected deposit, got {:?}", other),
+        }
+
+        let (parent, close) = &decoded[1];
+        assert!(parent.is_some_and(|parent| parent != versioned::PROGRAM_ID));
+        match close.as_ref().unwrap() {
+            VersionedVaultInstructions::Close(accounts) => assert_eq!(accounts.vault, vault),
+            other => panic!("expected close, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn json_encoded_rpc_transactions_are_errors() {
+    let tx = rpc_transaction(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        UiTransactionEncoding::Json,
+    );
+    let err = decode_rpc_transaction::<VersionedVaultInstructions>(&tx).unwrap_err();
+    assert!(err.to_string().contains("base64"), "{}", err);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "test-fixtures")]
+
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+mod native_token {
+    solana_idl_parser::parse_idl!(
+        "tests/idls/native_token.json",
+        discriminator_in_args = true,
+        deny_trailing_bytes = true,
+    );
+}
+
+use solana_idl_parser::{AccountMeta, Pubkey};
+
+fn metas(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|i| AccountMeta {
+            pubkey: Pubkey::new_from_array([i as u8; 32]),
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+#[test]
+fn sample_data_is_the_discriminator_and_zeroed_args() {
+    use counter::*;
+
+    assert_eq!(INITIALIZE_SAMPLE_DATA, INITIALIZE_DISCRIMINATOR);
+    assert_eq!(
+        SET_VALUE_SAMPLE_DATA,
+        [&SET_VALUE_DISCRIMINATOR[..], &[0; 8]].concat()
+    );
+
+    match CounterInstructions::deserialize(&metas(1), SET_VALUE_SAMPLE_DATA).unwrap() {
+        CounterInstructions::SetValue(_, args) => assert_eq!(args, SetValueArgs { value: 0 }),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+fn samples_of_args_holding_the_discriminator_count_it_once() {
+    use native_token::*;
+
+    assert_eq!(TRANSFER_SAMPLE_DATA, [3, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let ix = NativeTokenInstructions::deserialize(&metas(3), TRANSFER_SAMPLE_DATA).unwrap();
+    assert_eq!(ix.to_bytes().unwrap(), TRANSFER_SAMPLE_DATA);
+
+    assert_eq!(SYNC_NATIVE_SAMPLE_DATA, [17]);
+    NativeTokenInstructions::deserialize(&metas(1), SYNC_NATIVE_SAMPLE_DATA).unwrap();
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "schema")]
+
+mod all_types {
+    solana_idl_parser::parse_idl!("tests/idls/all_types.json");
+}
+
+mod interpreter {
+    solana_idl_parser::parse_idl!("tests/idls/enum_repr.json");
+}
+
+use all_types::*;
+use borsh::schema::{Definition, Fields};
+use borsh::schema_container_of;
+
+#[test]
+fn structs_describe_their_fields() {
+    let container = schema_container_of::<Settings>();
+    let Some(Definition::Struct {
+        fields: Fields::NamedFields(fields),
+    }) = container.get_definition("Settings")
+    else {
+        panic!("Settings has no named-field struct schema");
+    };
+    let names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["admin", "fee", "limits"]);
+    assert!(container.get_definition("Limits").is_some());
+}
+
+#[test]
+fn enums_describe_their_variants() {
+    let container = schema_container_of::<Action>();
+    let Some(Definition::Enum { variants, .. }) = container.get_definition("Action") else {
+        panic!("Action has no enum schema");
+    };
+    let names: Vec<&str> = variants.iter().map(|(_, name, _)| name.as_str()).collect();
+    assert_eq!(names, ["Idle", "Move", "Configure"]);
+}
+
+#[test]
+fn wide_enums_declare_their_tag_width() {
+    let container = schema_container_of::<interpreter::Opcode>();
+    let Some(Definition::Enum {
+        tag_width,
+        variants,
+    }) = container.get_definition("Opcode")
+    else {
+        panic!("Opcode has no enum schema");
+    };
+    assert_eq!(*tag_width, 2);
+    assert_eq!(variants.len(), 3);
+    assert_eq!(variants[2].1, "Jump");
+    let Some(Definition::Struct {
+        fields: Fields::NamedFields(fields),
+    }) = container.get_definition("OpcodeJump")
+    else {
+        panic!("Jump has no named-field struct schema");
+    };
+    assert_eq!(fields[1].0, "if_zero");
+    assert!(container.validate().is_ok());
+}
+
+#[test]
+fn instruction_structs_have_schemas() {
+    let container = schema_container_of::<ContainersArgs>();
+    assert_eq!(container.declaration(), "ContainersArgs");
+    assert!(container.get_definition("ContainersArgs").is_some());
+
+    let container = schema_container_of::<DefinedAccounts>();
+    let Some(Definition::Struct {
+        fields: Fields::NamedFields(fields),
+    }) = container.get_definition("DefinedAccounts")
+    else {
+        panic!("DefinedAccounts has no named-field struct schema");
+    };
+    assert_eq!(fields[0].0, "config");
+}
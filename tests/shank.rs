@@ -0,0 +1,95 @@
+mod vault {
+    solana_idl_parser::parse_idl!("tests/idls/shank.json");
+}
+
+use solana_idl_parser::{AccountMeta, Pubkey};
+use vault::{
+    CreateVaultAccounts, CreateVaultArgs, CreateVaultInstructionArgs, DepositAccounts, DepositArgs,
+    TokenVaultInstructions, Vault, VaultMode, PROGRAM_ID,
+};
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+fn meta(pubkey: Pubkey, is_signer: bool, is_writable: bool) -> AccountMeta {
+    AccountMeta {
+        pubkey,
+        is_signer,
+        is_writable,
+    }
+}
+
+#[test]
+fn shank_fields_map_onto_the_anchor_layout() {
+    assert_eq!(
+        PROGRAM_ID.to_string(),
+        "vau1tRCyUo9d5NwL6D5JxXgJyFz4jvxrHUBYxbqyXcL"
+    );
+    // `discriminant: { type: "u8", value: 1 }` is a one-byte discriminator
+    assert_eq!(vault::DEPOSIT_DISCRIMINATOR, [1]);
+
+    let accounts = DepositAccounts {
+        vault: key(1),
+        depositor: key(2),
+    };
+    // `isMut` and `isSigner` carry over to the account metas
+    assert_eq!(
+        accounts.to_account_metas(),
+        [meta(key(1), false, true), meta(key(2), true, true)]
+    );
+}
+
+#[test]
+fn shank_instructions_round_trip() {
+    let ix = TokenVaultInstructions::CreateVault(
+        CreateVaultAccounts {
+            vault: key(1),
+            authority: key(2),
+            delegate: None,
+        },
+        CreateVaultInstructionArgs {
+            create_vault_args: CreateVaultArgs {
+                mode: VaultMode::Locked,
+                limit: Some(500),
+            },
+        },
+    );
+    let data = ix.to_bytes().unwrap();
+    let mut expected = vec![0, 1, 1];
+    expected.extend_from_slice(&500u64.to_le_bytes());
+    assert_eq!(data, expected);
+
+    let metas = [meta(key(1), false, true), meta(key(2), true, false)];
+    assert_eq!(
+        TokenVaultInstructions::deserialize(&metas, &data).unwrap(),
+        ix
+    );
+
+    let deposit = TokenVaultInstructions::Deposit(
+        DepositAccounts {
+            vault: key(1),
+            depositor: key(2),
+        },
+        DepositArgs { amount: 7 },
+    );
+    let data = deposit.to_bytes().unwrap();
+    assert_eq!(data[0], 1);
+    let metas = [meta(key(1), false, true), meta(key(2), true, true)];
+    assert_eq!(
+        TokenVaultInstructions::deserialize(&metas, &data).unwrap(),
+        deposit
+    );
+}
+
+#[test]
+fn shank_accounts_decode_from_the_first_byte() {
+    let vault = Vault {
+        authority: key(3),
+        balance: 42,
+        mode: VaultMode::Open,
+    };
+    let data = borsh::to_vec(&vault).unwrap();
+    assert_eq!(data.len(), 32 + 8 + 1);
+    assert_eq!(Vault::from_account_data(&data).unwrap(), vault);
+}
@@ -0,0 +1,127 @@
+mod legacy {
+    solana_idl_parser::parse_idl!("tests/idls/legacy.json");
+}
+
+mod spec_0_1_0 {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+#[test]
+fn legacy_idl_uses_sighash_discriminators() {
+    assert_eq!(
+        legacy::INITIALIZE_DISCRIMINATOR,
+        [175, 175, 109, 31, 13, 152, 155, 237]
+    );
+    assert_eq!(
+        legacy::SET_VALUE_DISCRIMINATOR,
+        [253, 214, 48, 201, 100, 201, 227, 219]
+    );
+    assert_eq!(
+        legacy::COUNTER_ACCOUNT_DISCRIMINATOR,
+        [255, 176, 4, 245, 188, 253, 124, 25]
+    );
+}
+
+#[test]
+fn legacy_idl_decodes_instructions() {
+    let accounts = legacy::SetValueAccounts {
+        counter: legacy::PROGRAM_ID,
+    };
+    let args = legacy::SetValueArgs { value: 42 };
+    let ix = legacy::CounterInstructions::SetValue(accounts, args)
+        .into_instruction()
+        .unwrap();
+    assert_eq!(ix.data[..8], legacy::SET_VALUE_DISCRIMINATOR);
+
+    match legacy::CounterInstructions::deserialize(&ix.accounts, &ix.data).unwrap() {
+        legacy::CounterInstructions::SetValue(_, args) => assert_eq!(args.value, 42),
+        other => panic!("unexpected instruction {:?}", other),
+    }
+}
+
+#[test]
+fn instructions_are_built_from_their_payloads() {
+    let accounts = legacy::SetValueAccounts {
+        counter: legacy::PROGRAM_ID,
+    };
+    let args = legacy::SetValueArgs { value: 42 };
+    let ix: solana_idl_parser::Instruction = legacy::CounterInstructions::from((accounts, args))
+        .try_into()
+        .unwrap();
+    assert_eq!(ix.program_id, legacy::PROGRAM_ID);
+    assert_eq!(ix.data[..8], legacy::SET_VALUE_DISCRIMINATOR);
+    assert_eq!(ix.data[8..], 42u64.to_le_bytes());
+    assert_eq!(ix.accounts, accounts.to_account_metas());
+
+    let accounts = legacy::InitializeAccounts {
+        counter: legacy::PROGRAM_ID,
+        authority: legacy::PROGRAM_ID,
+    };
+    let ix = solana_idl_parser::Instruction::try_from(legacy::CounterInstructions::from(accounts))
+        .unwrap();
+    assert_eq!(ix.data, legacy::INITIALIZE_DISCRIMINATOR);
+}
+
+#[test]
+fn spec_0_1_0_idl_uses_explicit_discriminators() {
+    assert_eq!(
+        spec_0_1_0::INITIALIZE_DISCRIMINATOR,
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!(spec_0_1_0::SET_VALUE_DISCRIMINATOR, [9; 8]);
+    assert_eq!(
+        spec_0_1_0::COUNTER_ACCOUNT_DISCRIMINATOR,
+        [10, 11, 12, 13, 14, 15, 16, 17]
+    );
+}
+
+#[test]
+fn spec_0_1_0_idl_decodes_accounts() {
+    let counter = spec_0_1_0::Counter {
+        authority: spec_0_1_0::PROGRAM_ID,
+        value: 7,
+    };
+    let mut data = spec_0_1_0::COUNTER_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend(borsh::to_vec(&counter).unwrap());
+
+    let decoded = spec_0_1_0::Counter::from_account_data(&data).unwrap();
+    assert_eq!(decoded.value, 7);
+}
+
+#[test]
+fn decoded_accounts_can_be_deduplicated_in_hash_sets() {
+    use solana_idl_parser::{AccountMeta, Pubkey};
+    use std::collections::HashSet;
+
+    let meta = |byte: u8| AccountMeta {
+        pubkey: Pubkey::new_from_array([byte; 32]),
+        is_signer: false,
+        is_writable: true,
+    };
+    let accounts: HashSet<spec_0_1_0::InitializeAccounts> =
+        [[meta(1), meta(2)], [meta(3), meta(4)], [meta(1), meta(2)]]
+            .iter()
+            .map(|metas| spec_0_1_0::InitializeAccounts::from_account_metas(metas).unwrap())
+            .collect();
+    assert_eq!(accounts.len(), 2);
+
+    // Program accounts holding only keys and integers are `Hash` as well
+    let counter = |value| {
+        let mut data = spec_0_1_0::COUNTER_ACCOUNT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&u64::to_le_bytes(value));
+        spec_0_1_0::Counter::from_account_data(data).unwrap()
+    };
+    let counters: HashSet<_> = [counter(1), counter(1), counter(2)].into_iter().collect();
+    assert_eq!(counters.len(), 2);
+}
+
+#[test]
+fn program_reports_its_instruction_and_account_counts() {
+    assert_eq!(spec_0_1_0::INSTRUCTION_COUNT, 2);
+    assert_eq!(spec_0_1_0::ACCOUNT_COUNT, 1);
+
+    // Sized for lookups indexed by instruction, e.g. coverage counters
+    let handled = [false; spec_0_1_0::INSTRUCTION_COUNT];
+    assert_eq!(handled.len(), 2);
+}
@@ -0,0 +1,116 @@
+//! Cargo only reruns `parse_idl!` when a dependency of the crate changes, so
+//! the IDL files it reads must be listed in the dep-info of the test crate,
+//! whether through `include_bytes!` or, with the `nightly` feature, through
+//! `proc_macro::tracked`. A scratch crate built with the same feature checks
+//! that editing its IDL actually changes the generated code.
+
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+mod programs {
+    solana_idl_parser::parse_idl! {
+        versioned => "tests/idls/versioned.json",
+        native_token => "tests/idls/native_token.json",
+    }
+}
+
+/// Dep-info Cargo wrote for this test binary, next to it in `target/*/deps`
+fn dep_info() -> String {
+    let exe = std::env::current_exe().unwrap();
+    let path = exe.with_extension("d");
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read dep-info {:?}: {}", path, e))
+}
+
+#[test]
+fn idl_files_are_dependencies_of_the_crate() {
+    let dep_info = dep_info();
+    for idl in ["spec_0_1_0.json", "versioned.json", "native_token.json"] {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/idls")
+            .join(idl);
+        assert!(
+            dep_info.contains(path.to_str().unwrap()),
+            "{:?} missing from dep-info:\n{}",
+            path,
+            dep_info
+        );
+    }
+    assert_eq!(counter::SET_VALUE_DISCRIMINATOR, [9; 8]);
+    assert_eq!(
+        programs::native_token::PROGRAM_ID.to_string(),
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+    );
+}
+
+/// Build and run the scratch crate in `dir`, returning what it prints
+fn run_scratch_crate(dir: &std::path::Path) -> String {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = std::process::Command::new(cargo)
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(dir.join("Cargo.toml"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "scratch crate failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn editing_an_idl_expands_the_macro_again() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = root.join("target/tracked_idl_scratch");
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+
+    // The same tracking as this test crate: `include_bytes!`, or `proc_macro::tracked`
+    let features = if cfg!(feature = "nightly") {
+        r#"["std", "minimal-pubkey", "nightly"]"#
+    } else {
+        r#"["std", "minimal-pubkey"]"#
+    };
+    let manifest = format!(
+        r#"[package]
+name = "tracked_idl_scratch"
+version = "0.0.0"
+edition = "2021"
+
+[workspace]
+
+[dependencies]
+solana_idl_parser = {{ path = {:?}, default-features = false, features = {} }}
+anyhow = "1.0"
+borsh = "1.5"
+"#,
+        root, features
+    );
+    std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    // Resolve the same versions as this crate, without fetching anything new
+    if let Ok(lock) = std::fs::read(root.join("Cargo.lock")) {
+        std::fs::write(dir.join("Cargo.lock"), lock).unwrap();
+    }
+    std::fs::write(
+        dir.join("src/main.rs"),
+        r#"solana_idl_parser::parse_idl!("idl.json");
+
+fn main() {
+    println!("{:?}", SET_VALUE_DISCRIMINATOR);
+}
+"#,
+    )
+    .unwrap();
+
+    let idl = std::fs::read_to_string(root.join("tests/idls/spec_0_1_0.json")).unwrap();
+    std::fs::write(dir.join("idl.json"), &idl).unwrap();
+    assert_eq!(run_scratch_crate(&dir).trim(), "[9, 9, 9, 9, 9, 9, 9, 9]");
+
+    // Cargo compares modification times, which may be coarse
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let edited = idl.replace("[9, 9, 9, 9, 9, 9, 9, 9]", "[7, 7, 7, 7, 7, 7, 7, 7]");
+    assert_ne!(edited, idl);
+    std::fs::write(dir.join("idl.json"), edited).unwrap();
+    assert_eq!(run_scratch_crate(&dir).trim(), "[7, 7, 7, 7, 7, 7, 7, 7]");
+}
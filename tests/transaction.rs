@@ -0,0 +1,293 @@
+#![cfg(all(feature = "sdk", not(feature = "minimal-pubkey")))]
+
+mod auction {
+    solana_idl_parser::parse_idl!("tests/idls/events.json");
+}
+
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+use auction::{AuctionClosed, AuctionEvents, BidPlaced};
+use base64::Engine as _;
+use counter::{CounterInstructions, InitializeAccounts, SetValueAccounts, SetValueArgs};
+use solana_idl_parser::transaction::{
+    decode_all_events, decode_log_events, decode_transaction, flatten_instructions,
+    InnerInstruction, InnerInstructions,
+};
+use solana_idl_parser::EVENT_CPI_TAG;
+use solana_sdk::{
+    hash::Hash,
+    message::{
+        compiled_instruction::CompiledInstruction,
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
+        Message, MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
+};
+
+fn compiled(program_id_index: u8) -> CompiledInstruction {
+    CompiledInstruction {
+        program_id_index,
+        accounts: vec![],
+        data: vec![],
+    }
+}
+
+fn inner(program_id_index: u8, stack_height: Option<u32>) -> InnerInstruction {
+    InnerInstruction {
+        instruction: compiled(program_id_index),
+        stack_height,
+    }
+}
+
+fn message(top_level: usize) -> VersionedMessage {
+    VersionedMessage::Legacy(Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        recent_blockhash: Hash::default(),
+        instructions: (0..top_level).map(|_| compiled(1)).collect(),
+    })
+}
+
+#[test]
+fn inner_instructions_are_attributed_by_stack_height() {
+    let message = message(2);
+    let inner_instructions = vec![
+        InnerInstructions {
+            index: 0,
+            instructions: vec![
+                inner(1, Some(2)),
+                inner(1, Some(3)),
+                inner(2, Some(3)),
+                inner(2, Some(2)),
+                inner(1, Some(3)),
+            ],
+        },
+        InnerInstructions {
+            index: 1,
+            instructions: vec![inner(2, Some(2)), inner(2, None)],
+        },
+    ];
+
+    let flattened = flatten_instructions(&message, &inner_instructions);
+    let parents: Vec<Option<usize>> = flattened.iter().map(|ix| ix.parent).collect();
+    assert_eq!(
+        parents,
+        [
+            None,
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(0),
+            Some(4),
+            None,
+            Some(6),
+            Some(6),
+        ]
+    );
+    let top_level: Vec<usize> = flattened.iter().map(|ix| ix.top_level_index).collect();
+    assert_eq!(top_level, [0, 0, 0, 0, 0, 0, 1, 1, 1]);
+}
+
+fn bid_placed_log(bidder: Pubkey, amount: u64) -> String {
+    let mut data = BidPlaced::EVENT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&bidder.to_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    format!(
+        "Program data: {}",
+        base64::engine::general_purpose::STANDARD.encode(data)
+    )
+}
+
+#[test]
+fn logged_events_are_attributed_to_the_program_and_instruction() {
+    let auction_id = auction::PROGRAM_ID.to_string();
+    let other_id = Pubkey::new_from_array([9; 32]).to_string();
+    let bidder = Pubkey::new_from_array([7; 32]);
+    let logs: Vec<String> = vec![
+        format!("Program {} invoke [1]", other_id),
+        bid_placed_log(bidder, 1),
+        format!("Program {} invoke [2]", auction_id),
+        bid_placed_log(bidder, 2),
+        format!("Program {} success", auction_id),
+        bid_placed_log(bidder, 3),
+        format!("Program {} success", other_id),
+        format!("Program {} invoke [1]", auction_id),
+        "Program log: Instruction: PlaceBid".to_string(),
+        bid_placed_log(bidder, 4),
+        "Program data: not base64!".to_string(),
+        format!(
+            "Program {} consumed 1200 of 200000 compute units",
+            auction_id
+        ),
+        format!("Program {} success", auction_id),
+    ];
+
+    let events = decode_log_events::<AuctionEvents>(&logs);
+    let decoded: Vec<(usize, AuctionEvents)> = events
+        .iter()
+        .take(2)
+        .map(|(index, event)| (*index, event.as_ref().unwrap().clone()))
+        .collect();
+    let bid = |amount| AuctionEvents::BidPlaced(BidPlaced { bidder, amount });
+    assert_eq!(decoded, [(0, bid(2)), (1, bid(4))]);
+    assert_eq!(events.len(), 3);
+    assert!(events[2].1.is_err());
+}
+
+#[test]
+fn all_events_combine_logs_and_cpi_events() {
+    let bidder = Pubkey::new_from_array([7; 32]);
+    let caller = Pubkey::new_unique();
+    let message = VersionedMessage::Legacy(Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![bidder, caller, auction::PROGRAM_ID],
+        recent_blockhash: Hash::default(),
+        instructions: vec![compiled(1), compiled(2)],
+    });
+
+    let mut cpi_event = EVENT_CPI_TAG.to_vec();
+    cpi_event.extend_from_slice(&AuctionClosed::EVENT_DISCRIMINATOR);
+    cpi_event.extend_from_slice(&900u64.to_le_bytes());
+    let cpi = |program_id_index, data: &[u8], stack_height| InnerInstruction {
+        instruction: CompiledInstruction {
+            program_id_index,
+            accounts: vec![],
+            data: data.to_vec(),
+        },
+        stack_height: Some(stack_height),
+    };
+    let inner_instructions = vec![InnerInstructions {
+        index: 0,
+        instructions: vec![
+            cpi(2, &auction::PLACE_BID_DISCRIMINATOR, 2),
+            cpi(2, &cpi_event, 3),
+            // Only the program's own instructions carry its events
+            cpi(1, &cpi_event, 2),
+        ],
+    }];
+    let auction_id = auction::PROGRAM_ID.to_string();
+    let logs = vec![
+        format!("Program {} invoke [1]", caller),
+        format!("Program {} success", caller),
+        format!("Program {} invoke [1]", auction_id),
+        bid_placed_log(bidder, 4),
+        format!("Program {} success", auction_id),
+    ];
+
+    let events: Vec<(usize, AuctionEvents)> = decode_all_events::<AuctionEvents>(
+        &message,
+        &Default::default(),
+        &inner_instructions,
+        &logs,
+    )
+    .into_iter()
+    .map(|(index, event)| (index, event.unwrap()))
+    .collect();
+    assert_eq!(
+        events,
+        [
+            (
+                0,
+                AuctionEvents::AuctionClosed(AuctionClosed { winning_bid: 900 })
+            ),
+            (1, AuctionEvents::BidPlaced(BidPlaced { bidder, amount: 4 })),
+        ]
+    );
+}
+
+#[test]
+fn transactions_are_decoded_with_loaded_addresses() {
+    let authority = Pubkey::new_unique();
+    let router = Pubkey::new_unique();
+    let counter_account = Pubkey::new_unique();
+    let mut set_value = counter::SET_VALUE_DISCRIMINATOR.to_vec();
+    set_value.extend_from_slice(&42u64.to_le_bytes());
+
+    // Accounts 0-2 are static, 3 and 4 come from the lookup table
+    let message = VersionedMessage::V0(v0::Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![authority, router, counter::PROGRAM_ID],
+        recent_blockhash: Hash::default(),
+        instructions: vec![
+            CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![3],
+                data: set_value,
+            },
+            CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![3, 0, 4],
+                data: vec![],
+            },
+        ],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: Pubkey::new_unique(),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![0],
+        }],
+    });
+    let loaded_addresses = LoadedAddresses {
+        writable: vec![counter_account],
+        readonly: vec![Pubkey::new_unique()],
+    };
+    let cpi = |accounts: Vec<u8>| InnerInstruction {
+        instruction: CompiledInstruction {
+            program_id_index: 2,
+            accounts,
+            data: counter::INITIALIZE_DISCRIMINATOR.to_vec(),
+        },
+        stack_height: Some(2),
+    };
+    let inner_instructions = vec![InnerInstructions {
+        index: 1,
+        instructions: vec![cpi(vec![3, 0]), cpi(vec![3, 9])],
+    }];
+
+    let decoded =
+        decode_transaction::<CounterInstructions>(&message, &loaded_addresses, &inner_instructions);
+    assert_eq!(decoded.len(), 3);
+    let (parent, ix) = &decoded[0];
+    assert_eq!(*parent, None);
+    assert_eq!(
+        *ix.as_ref().unwrap(),
+        CounterInstructions::SetValue(
+            SetValueAccounts {
+                counter: counter_account
+            },
+            SetValueArgs { value: 42 },
+        )
+    );
+    let (parent, ix) = &decoded[1];
+    assert_eq!(*parent, Some(router));
+    assert_eq!(
+        *ix.as_ref().unwrap(),
+        CounterInstructions::Initialize(InitializeAccounts {
+            counter: counter_account,
+            authority,
+        })
+    );
+    let (parent, ix) = &decoded[2];
+    assert_eq!(*parent, Some(router));
+    assert_eq!(
+        ix.as_ref().unwrap_err().to_string(),
+        "account index 9 out of range, transaction has 5 accounts"
+    );
+}
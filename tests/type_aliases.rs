@@ -0,0 +1,56 @@
+mod type_aliases {
+    solana_idl_parser::parse_idl!("tests/idls/type_aliases.json");
+}
+
+use solana_idl_parser::Pubkey;
+use type_aliases::{Amount, Fee, PayoutArgs, Recipients, Split};
+
+#[test]
+fn aliases_resolve_to_their_target_types() {
+    let amount: Amount = 7u64;
+    let recipients: Recipients = vec![Pubkey::new_from_array([1; 32])];
+    let split = Split {
+        recipients,
+        shares: vec![amount],
+        fee: 2,
+    };
+    assert_eq!(split.clone(), split);
+
+    let args = PayoutArgs { amount, split };
+    let data = borsh::to_vec(&args).unwrap();
+    let decoded: PayoutArgs = borsh::from_slice(&data).unwrap();
+    assert_eq!(decoded.amount, 7);
+    assert_eq!(decoded.split.shares, [7]);
+}
+
+#[test]
+fn alias_chains_resolve_to_the_final_target() {
+    // `Fee` is an alias of the alias `Amount`
+    let fee: Fee = u64::MAX;
+    let amount: Amount = fee;
+    assert_eq!(amount, u64::MAX);
+}
+
+#[test]
+#[cfg(not(feature = "minimal-pubkey"))]
+fn aliased_args_are_pda_seeds() {
+    use type_aliases::PayoutAccounts;
+
+    let args = PayoutArgs {
+        amount: 7,
+        split: Split {
+            recipients: vec![],
+            shares: vec![],
+            fee: 2,
+        },
+    };
+    let accounts = PayoutAccounts {
+        vault: Pubkey::new_unique(),
+    };
+    let (address, _) = accounts.find_vault_address(&args);
+    let expected = Pubkey::find_program_address(
+        &[b"vault", &7u64.to_le_bytes(), &2u64.to_le_bytes()],
+        &type_aliases::PROGRAM_ID,
+    );
+    assert_eq!(address, expected.0);
+}
@@ -0,0 +1,79 @@
+mod counter {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json", types_only = true);
+
+    // Would clash with the instruction code if it were generated
+    pub struct CounterInstructions;
+    pub struct SetValueArgs;
+    pub const INITIALIZE_DISCRIMINATOR: () = ();
+}
+
+use counter::{Counter, COUNTER_ACCOUNT_DISCRIMINATOR, PROGRAM_ID};
+use solana_idl_parser::Pubkey;
+
+#[test]
+fn accounts_are_generated_without_instruction_code() {
+    let counter = Counter {
+        authority: Pubkey::new_from_array([1; 32]),
+        value: 7,
+    };
+    let mut data = COUNTER_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&counter).unwrap());
+
+    assert_eq!(Counter::from_account_data(&data).unwrap(), counter);
+    assert_eq!(Counter::OWNER, PROGRAM_ID);
+}
+
+#[test]
+fn instruction_names_are_left_free() {
+    let _ = (
+        counter::CounterInstructions,
+        counter::SetValueArgs,
+        counter::INITIALIZE_DISCRIMINATOR,
+    );
+}
+
+mod oracle {
+    solana_idl_parser::parse_idl!("tests/idls/accounts_only.json");
+
+    // Would clash with an instructions enum if one were generated
+    pub struct OracleInstructions;
+}
+
+mod programs {
+    solana_idl_parser::parse_idl! {
+        oracle => "tests/idls/accounts_only.json",
+        counter => "tests/idls/spec_0_1_0.json",
+    }
+}
+
+#[test]
+fn idls_without_instructions_generate_only_their_accounts() {
+    let feed = oracle::PriceFeed {
+        price: 6_500_000,
+        exponent: -5,
+    };
+    let mut data = oracle::PRICE_FEED_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&borsh::to_vec(&feed).unwrap());
+
+    assert_eq!(oracle::PriceFeed::from_account_data(&data).unwrap(), feed);
+    assert_eq!(oracle::INSTRUCTION_COUNT, 0);
+    let _ = oracle::OracleInstructions;
+}
+
+#[test]
+fn decode_any_skips_programs_without_instructions() {
+    let data = programs::counter::INITIALIZE_DISCRIMINATOR;
+    let accounts = vec![
+        solana_idl_parser::AccountMeta {
+            pubkey: Pubkey::new_from_array([1; 32]),
+            is_signer: true,
+            is_writable: true,
+        };
+        programs::counter::INITIALIZE_IX_ACCOUNTS_LEN
+    ];
+    let decoded = programs::decode_any(&programs::counter::PROGRAM_ID, &accounts, data);
+    assert!(matches!(
+        decoded,
+        Some(Ok(programs::AnyInstruction::Counter(_)))
+    ));
+}
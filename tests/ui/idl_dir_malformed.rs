@@ -0,0 +1,9 @@
+mod programs {
+    // trybuild builds this file from `target/tests/trybuild/<crate>`
+    solana_idl_parser::parse_idl_dir!("../../../../tests/ui/malformed_idls");
+}
+
+fn main() {
+    // The IDLs that load are still generated
+    let _ = programs::counter::PROGRAM_ID;
+}
@@ -0,0 +1,47 @@
+mod spec_0_1_0 {
+    solana_idl_parser::parse_idl!("tests/idls/spec_0_1_0.json");
+}
+
+use solana_idl_parser::{AccountMeta, Pubkey};
+use spec_0_1_0::{
+    CounterInstructions, CounterVisitor, InitializeAccounts, SetValueAccounts, SetValueArgs,
+};
+
+fn meta(byte: u8) -> AccountMeta {
+    AccountMeta {
+        pubkey: Pubkey::new_from_array([byte; 32]),
+        is_signer: false,
+        is_writable: true,
+    }
+}
+
+/// Only handles `set_value`, relying on the default for `initialize`
+#[derive(Default)]
+struct ValueRecorder {
+    values: Vec<(Pubkey, u64)>,
+}
+
+impl CounterVisitor for ValueRecorder {
+    fn visit_set_value(&mut self, accounts: &SetValueAccounts, args: &SetValueArgs) {
+        self.values.push((accounts.counter, args.value));
+    }
+}
+
+#[test]
+fn accept_calls_the_visitor_method_of_the_instruction() {
+    let instructions = [
+        CounterInstructions::Initialize(
+            InitializeAccounts::from_account_metas(&[meta(1), meta(2)]).unwrap(),
+        ),
+        CounterInstructions::SetValue(
+            SetValueAccounts::from_account_metas(&[meta(3)]).unwrap(),
+            SetValueArgs { value: 7 },
+        ),
+    ];
+
+    let mut recorder = ValueRecorder::default();
+    for ix in &instructions {
+        ix.accept(&mut recorder);
+    }
+    assert_eq!(recorder.values, [(Pubkey::new_from_array([3; 32]), 7)]);
+}
@@ -0,0 +1,163 @@
+#![cfg(all(feature = "yellowstone", not(feature = "minimal-pubkey")))]
+
+use solana_account_decoder_client_types::token::UiTokenAmount;
+use solana_idl_parser::yellowstone::{
+    convert_reward, convert_reward_type, convert_token_balance, convert_ui_token_amount,
+    token_balance_deltas,
+};
+use solana_idl_parser::Pubkey;
+use solana_transaction_status::{RewardType, TransactionTokenBalance};
+use yellowstone_grpc_proto::prelude as proto;
+
+fn reward(commission: &str) -> proto::Reward {
+    proto::Reward {
+        pubkey: "Vote111111111111111111111111111111111111111".to_string(),
+        lamports: 5000,
+        post_balance: 10_000,
+        reward_type: proto::RewardType::Voting as i32,
+        commission: commission.to_string(),
+    }
+}
+
+#[test]
+fn reward_commission_is_parsed() {
+    assert_eq!(convert_reward(reward("10")).commission, Some(10));
+    assert_eq!(convert_reward(reward("")).commission, None);
+}
+
+#[test]
+fn non_numeric_reward_commission_defaults_to_zero() {
+    assert_eq!(convert_reward(reward("ten")).commission, Some(0));
+    assert_eq!(convert_reward(reward("300")).commission, Some(0));
+}
+
+#[test]
+fn reward_types_follow_the_geyser_protocol() {
+    assert_eq!(
+        convert_reward_type(proto::RewardType::Unspecified as i32),
+        None
+    );
+    assert_eq!(convert_reward_type(1), Some(RewardType::Fee));
+    assert_eq!(convert_reward_type(2), Some(RewardType::Rent));
+    assert_eq!(convert_reward_type(3), Some(RewardType::Staking));
+    assert_eq!(convert_reward_type(4), Some(RewardType::Voting));
+}
+
+#[test]
+fn unknown_reward_types_are_none() {
+    assert_eq!(convert_reward_type(5), None);
+    assert_eq!(convert_reward_type(-1), None);
+    assert_eq!(
+        convert_reward(proto::Reward {
+            reward_type: 42,
+            ..reward("")
+        })
+        .reward_type,
+        None
+    );
+}
+
+fn ui_token_amount(ui_amount: f64, amount: &str) -> proto::UiTokenAmount {
+    proto::UiTokenAmount {
+        ui_amount,
+        decimals: 6,
+        amount: amount.to_string(),
+        ui_amount_string: ui_amount.to_string(),
+    }
+}
+
+#[test]
+fn zero_token_amounts_are_kept() {
+    let amount = convert_ui_token_amount(ui_token_amount(0.0, "0")).unwrap();
+    assert_eq!(amount.ui_amount, Some(0.0));
+    assert_eq!(amount.amount, "0");
+
+    let amount = convert_ui_token_amount(ui_token_amount(1.5, "1500000")).unwrap();
+    assert_eq!(amount.ui_amount, Some(1.5));
+    assert_eq!(amount.decimals, 6);
+}
+
+#[test]
+fn unrepresentable_ui_amounts_are_none() {
+    let amount = convert_ui_token_amount(ui_token_amount(0.0, "1500000")).unwrap();
+    assert_eq!(amount.ui_amount, None);
+    assert_eq!(amount.amount, "1500000");
+}
+
+#[test]
+fn token_balances_without_an_amount_are_errors() {
+    let balance = proto::TokenBalance {
+        account_index: 3,
+        mint: "So11111111111111111111111111111111111111112".to_string(),
+        ui_token_amount: None,
+        owner: String::new(),
+        program_id: String::new(),
+    };
+    assert!(convert_token_balance(balance.clone()).is_err());
+
+    let balance = proto::TokenBalance {
+        ui_token_amount: Some(ui_token_amount(0.0, "0")),
+        ..balance
+    };
+    let converted = convert_token_balance(balance).unwrap();
+    assert_eq!(converted.account_index, 3);
+    assert_eq!(converted.ui_token_amount.ui_amount, Some(0.0));
+}
+
+fn token_balance(
+    account_index: u8,
+    owner: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+) -> TransactionTokenBalance {
+    TransactionTokenBalance {
+        account_index,
+        mint: mint.to_string(),
+        ui_token_amount: UiTokenAmount {
+            ui_amount: None,
+            decimals: 6,
+            amount: amount.to_string(),
+            ui_amount_string: String::new(),
+        },
+        owner: owner.to_string(),
+        program_id: String::new(),
+    }
+}
+
+#[test]
+fn token_balance_deltas_are_summed_per_owner_and_mint() {
+    let (trader, pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (usdc, sol) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let pre = [
+        token_balance(1, trader, usdc, 1_000),
+        token_balance(2, pool, usdc, 50_000),
+        token_balance(3, pool, sol, 900),
+        token_balance(4, trader, usdc, 10),
+    ];
+    let post = [
+        token_balance(1, trader, usdc, 400),
+        token_balance(2, pool, usdc, 50_600),
+        token_balance(3, pool, sol, 880),
+        token_balance(4, trader, usdc, 10),
+        // Opened by the transaction
+        token_balance(5, trader, sol, 20),
+    ];
+
+    let deltas = token_balance_deltas(&pre, &post).unwrap();
+    assert_eq!(deltas.len(), 4);
+    assert_eq!(deltas[&(trader, usdc)], -600);
+    assert_eq!(deltas[&(pool, usdc)], 600);
+    assert_eq!(deltas[&(pool, sol)], -20);
+    assert_eq!(deltas[&(trader, sol)], 20);
+}
+
+#[test]
+fn token_balances_with_invalid_owners_are_errors() {
+    let mut balance = token_balance(7, Pubkey::new_unique(), Pubkey::new_unique(), 1);
+    balance.owner = String::new();
+    let err = token_balance_deltas(&[balance], &[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "token balance of account 7 has an invalid owner \"\""
+    );
+}